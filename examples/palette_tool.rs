@@ -616,8 +616,7 @@ fn sync_controls_from_state_system(
         return;
     }
 
-    field.value = seed_hex_value(*state);
-    field.has_content = !field.value.is_empty();
+    field.set_value(seed_hex_value(*state));
     field.error = false;
     field.error_text = None;
 }
@@ -655,8 +654,7 @@ fn handle_seed_hex_submit_system(
                 state.r = r;
                 state.g = g;
                 state.b = b;
-                field.value = seed_hex_value(*state);
-                field.has_content = !field.value.is_empty();
+                field.set_value(seed_hex_value(*state));
                 field.error = false;
                 field.error_text = None;
                 draft.dirty = false;
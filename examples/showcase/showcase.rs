@@ -412,8 +412,7 @@ fn restore_showcase_text_field_values_system(
         };
 
         // Rehydrate the field’s value after an adaptive-layout rebuild.
-        field.value = value.clone();
-        field.has_content = !field.value.is_empty();
+        field.set_value(value.clone());
 
         // Keep the demo’s email validation behavior consistent after restore.
         if field.input_type == InputType::Email {
@@ -1767,10 +1766,21 @@ fn datetime_picker_demo_system(
 
     // Update result text on submit.
     for ev in submit.read() {
-        let label = format!(
-            "Result: {:04}-{:02}-{:02} {:02}:{:02}",
-            ev.date.year, ev.date.month, ev.date.day, ev.hour, ev.minute
-        );
+        let label = match ev.range {
+            Some(range) => format!(
+                "Result: {:04}-{:02}-{:02} to {:04}-{:02}-{:02}",
+                range.start.year,
+                range.start.month,
+                range.start.day,
+                range.end.year,
+                range.end.month,
+                range.end.day
+            ),
+            None => format!(
+                "Result: {:04}-{:02}-{:02} {:02}:{:02}",
+                ev.date.year, ev.date.month, ev.date.day, ev.hour, ev.minute
+            ),
+        };
 
         for (display, mut text) in result_texts.iter_mut() {
             if display.0 == ev.entity {
@@ -34,6 +34,17 @@ pub fn spawn_datetime_picker_section(parent: &mut ChildSpawnerCommands, theme: &
                     .width(Val::Px(360.0)),
             );
 
+            // Range picker in the full-screen layout used on compact windows
+            let range_picker_entity = section.spawn_datetime_picker_entity_with(
+                theme,
+                DateTimePickerBuilder::new()
+                    .title("Select dates")
+                    .range(Date::new(2025, 1, 15), Date::new(2025, 1, 19))
+                    .min_date(Date::new(2025, 1, 1))
+                    .max_date(Date::new(2025, 6, 30))
+                    .full_screen(),
+            );
+
             section
                 .spawn(Node {
                     flex_direction: FlexDirection::Row,
@@ -74,6 +85,46 @@ pub fn spawn_datetime_picker_section(parent: &mut ChildSpawnerCommands, theme: &
                     ));
                 });
 
+            section
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(16.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|row| {
+                    let label = "Open Range Picker";
+                    let btn = MaterialButton::new(label).with_variant(ButtonVariant::Filled);
+                    let text_color = btn.text_color(theme);
+
+                    row.spawn((
+                        DateTimePickerOpenButton(range_picker_entity),
+                        Interaction::None,
+                        MaterialButtonBuilder::new(label).filled().build(theme),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            ButtonLabel,
+                            Text::new(label),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(text_color),
+                        ));
+                    });
+
+                    row.spawn((
+                        DateTimePickerResultDisplay(range_picker_entity),
+                        Text::new("Result: None"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(theme.on_surface_variant),
+                    ));
+                });
+
             spawn_code_block(
                 section,
                 theme,
@@ -87,13 +138,25 @@ section.spawn_datetime_picker_with(
         .max_date(Date::new(2025, 12, 31))
 );
 
+// Or a full-screen date range picker
+section.spawn_datetime_picker_with(
+    theme,
+    DateTimePickerBuilder::new()
+        .title(\"Select dates\")
+        .range_selection()
+        .full_screen()
+);
+
 // Listen for submit/cancel messages
 fn handle_picker_events(
     mut submit: MessageReader<DateTimePickerSubmitEvent>,
     mut cancel: MessageReader<DateTimePickerCancelEvent>,
 ) {
     for ev in submit.read() {
-        info!(\"Picked: {:?} {:02}:{:02}\", ev.date, ev.hour, ev.minute);
+        match ev.range {
+            Some(range) => info!(\"Picked: {:?} to {:?}\", range.start, range.end),
+            None => info!(\"Picked: {:?} {:02}:{:02}\", ev.date, ev.hour, ev.minute),
+        }
     }
     for _ in cancel.read() {
         info!(\"Picker canceled\");
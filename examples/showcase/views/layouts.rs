@@ -429,7 +429,11 @@ fn spawn_nav_rail_card(
 }
 
 fn spawn_modal_drawer_example(parent: &mut ChildSpawnerCommands, theme: MaterialTheme) {
-    let config = layout::ModalDrawerScaffold::default();
+    // The preview is embedded in the page, so it must not capture the showcase's focus.
+    let config = layout::ModalDrawerScaffold {
+        trap_focus: false,
+        ..default()
+    };
     parent
         .spawn(Node {
             flex_direction: FlexDirection::Column,
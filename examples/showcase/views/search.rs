@@ -60,7 +60,8 @@ pub fn spawn_search_section(parent: &mut ChildSpawnerCommands, theme: &MaterialT
                     col.spawn_search_bar_with(
                         theme,
                         SearchBarBuilder::new("Search...")
-                            .with_navigation(MaterialIcon::new(ICON_MENU)),
+                            .with_navigation(MaterialIcon::new(ICON_MENU))
+                            .with_history(vec!["bevy ui".to_string(), "material you".to_string()]),
                     );
 
                     // Search bar with text
@@ -80,7 +81,12 @@ pub fn spawn_search_section(parent: &mut ChildSpawnerCommands, theme: &MaterialT
                         theme,
                         SearchBarBuilder::new("Search...")
                             .with_navigation(MaterialIcon::new(ICON_ARROW_BACK))
-                            .with_text("material design"),
+                            .with_text("material design")
+                            .with_suggestions(vec![
+                                "material design 3".to_string(),
+                                "material design icons".to_string(),
+                                "material design color system".to_string(),
+                            ]),
                     );
                 });
 
@@ -90,19 +96,24 @@ pub fn spawn_search_section(parent: &mut ChildSpawnerCommands, theme: &MaterialT
                 r#"// Default search bar
 ui.spawn_search_bar(&theme, "Search...");
 
-// With navigation icon
+// With navigation icon and recent queries
 ui.spawn_search_bar_with(
     &theme,
     SearchBarBuilder::new("Search...")
-        .with_navigation(MaterialIcon::new(ICON_MENU)),
+        .with_navigation(MaterialIcon::new(ICON_MENU))
+        .with_history(vec!["bevy ui".to_string()]),
 );
 
-// With search text
+// With search text and suggestions
 ui.spawn_search_bar_with(
     &theme,
     SearchBarBuilder::new("Search...")
-        .with_text("material design"),
-);"#,
+        .with_text("material design")
+        .with_suggestions(vec!["material design 3".to_string()]),
+);
+
+// Update suggestions as the (debounced) query changes
+fn on_query(mut queries: MessageReader<SearchQueryEvent>) { /* ... */ }"#,
             );
         });
 }
@@ -74,6 +74,39 @@ pub fn spawn_select_section(
                                 .selected(1),
                         );
                     });
+
+                    row.spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|col| {
+                        col.spawn_select_with(
+                            theme,
+                            SelectBuilder::new(options.clone())
+                                .label("Multiple")
+                                .multiple()
+                                .selected(0)
+                                .selected(2)
+                                .width(Val::Px(280.0)),
+                        );
+                    });
+
+                    row.spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|col| {
+                        col.spawn_select_with(
+                            theme,
+                            SelectBuilder::new(options.clone())
+                                .outlined()
+                                .label("Type or pick")
+                                .editable()
+                                .allow_custom(),
+                        );
+                    });
                 });
 
             spawn_code_block(
@@ -93,7 +126,13 @@ commands.spawn(
         .label("Choose")
         .selected(0)
         .build(&theme),
-);"#,
+);
+
+// Several values, shown as input chips
+ui.spawn_select_with(&theme, SelectBuilder::new(options.clone()).multiple());
+
+// Typeable combobox accepting values outside the list
+ui.spawn_select_with(&theme, SelectBuilder::new(options).editable().allow_custom());"#,
             );
         });
 }
@@ -7,11 +7,16 @@
 
 use bevy::prelude::*;
 
+use std::collections::HashMap;
+
 use crate::{
+    elevation::Elevation,
     icons::{IconStyle, MaterialIcon},
+    motion::AnimatedValue,
     ripple::RippleHost,
+    scroll::ScrollContainer,
     theme::MaterialTheme,
-    tokens::{CornerRadius, Spacing},
+    tokens::{CornerRadius, Duration, Easing, Spacing},
 };
 
 /// Plugin for app bar components
@@ -107,10 +112,22 @@ pub struct TopAppBar {
     pub actions: Vec<AppBarAction>,
     /// Scroll behavior
     pub scroll_behavior: TopAppBarScrollBehavior,
+    /// Scroll container whose offset drives the scroll behavior
+    pub scroll_target: Option<Entity>,
     /// Current scroll offset for collapse behavior
     pub scroll_offset: f32,
+    /// Whether content is currently scrolled beneath the bar
+    pub scrolled_under: bool,
     /// Whether elevated (has shadow)
     pub elevated: bool,
+    /// How far the bar is slid out of view (Scroll / EnterExit)
+    exit_offset: f32,
+    /// Target offset seen on the previous update
+    last_scroll: Option<f32>,
+    /// Time since the target offset last changed
+    idle: f32,
+    /// Snap animation for EnterExit once scrolling stops
+    snap: Option<AnimatedValue>,
 }
 
 /// An action button for the app bar
@@ -150,8 +167,14 @@ impl TopAppBar {
             navigation_icon: None,
             actions: Vec::new(),
             scroll_behavior: TopAppBarScrollBehavior::default(),
+            scroll_target: None,
             scroll_offset: 0.0,
+            scrolled_under: false,
             elevated: false,
+            exit_offset: 0.0,
+            last_scroll: None,
+            idle: 0.0,
+            snap: None,
         }
     }
 
@@ -189,32 +212,120 @@ impl TopAppBar {
         self
     }
 
+    /// Bind the bar to a scroll container
+    pub fn with_scroll_target(mut self, target: Entity) -> Self {
+        self.scroll_target = Some(target);
+        self
+    }
+
     /// Set elevated
     pub fn elevated(mut self) -> Self {
         self.elevated = true;
         self
     }
 
-    /// Get the height based on variant and scroll state
+    /// Get the height based on variant (fully expanded)
     pub fn height(&self) -> f32 {
         match self.variant {
             TopAppBarVariant::Small | TopAppBarVariant::CenterAligned => TOP_APP_BAR_HEIGHT_SMALL,
-            TopAppBarVariant::Medium => {
-                let collapsed = (self.scroll_offset / 100.0).clamp(0.0, 1.0);
-                TOP_APP_BAR_HEIGHT_MEDIUM
-                    - collapsed * (TOP_APP_BAR_HEIGHT_MEDIUM - TOP_APP_BAR_HEIGHT_SMALL)
+            TopAppBarVariant::Medium => TOP_APP_BAR_HEIGHT_MEDIUM,
+            TopAppBarVariant::Large => TOP_APP_BAR_HEIGHT_LARGE,
+        }
+    }
+
+    /// Scroll distance over which the bar collapses to the small height
+    pub fn collapse_range(&self) -> f32 {
+        self.height() - TOP_APP_BAR_HEIGHT_SMALL
+    }
+
+    /// Fraction (0-1) by which a Medium or Large bar has collapsed
+    pub fn collapsed_fraction(&self) -> f32 {
+        let range = self.collapse_range();
+        if range <= 0.0 {
+            return 0.0;
+        }
+        (self.scroll_offset / range).clamp(0.0, 1.0)
+    }
+
+    /// Get the height based on variant and scroll state
+    pub fn current_height(&self) -> f32 {
+        self.height() - self.collapsed_fraction() * self.collapse_range()
+    }
+
+    /// How far the bar is currently slid out of view
+    pub fn exit_offset(&self) -> f32 {
+        self.exit_offset
+    }
+
+    /// Scroll state visible to other systems: collapse, exit offset, scrolled-under
+    fn scroll_state(&self) -> (f32, f32, bool) {
+        (self.scroll_offset, self.exit_offset, self.scrolled_under)
+    }
+
+    /// Update the scroll state from the target container's vertical offset
+    pub fn apply_scroll(&mut self, offset: f32, dt: f32) {
+        let offset = offset.max(0.0);
+        let delta = self.last_scroll.map_or(0.0, |last| offset - last);
+        self.last_scroll = Some(offset);
+
+        match self.scroll_behavior {
+            TopAppBarScrollBehavior::Fixed => {
+                self.scroll_offset = 0.0;
+                self.exit_offset = 0.0;
             }
-            TopAppBarVariant::Large => {
-                let collapsed = (self.scroll_offset / 100.0).clamp(0.0, 1.0);
-                TOP_APP_BAR_HEIGHT_LARGE
-                    - collapsed * (TOP_APP_BAR_HEIGHT_LARGE - TOP_APP_BAR_HEIGHT_SMALL)
+            TopAppBarScrollBehavior::Collapse => {
+                self.scroll_offset = offset;
+                self.exit_offset = 0.0;
+            }
+            TopAppBarScrollBehavior::Scroll => {
+                self.exit_offset = offset.min(self.current_height());
+            }
+            TopAppBarScrollBehavior::EnterExit => {
+                let height = self.current_height();
+                if delta != 0.0 {
+                    self.idle = 0.0;
+                    self.snap = None;
+                    self.exit_offset = (self.exit_offset + delta).clamp(0.0, height);
+                } else {
+                    self.idle += dt;
+                    if let Some(snap) = &mut self.snap {
+                        snap.update(dt);
+                        self.exit_offset = snap.value();
+                        if snap.complete {
+                            self.snap = None;
+                        }
+                    } else if self.idle >= TOP_APP_BAR_SNAP_DELAY
+                        && self.exit_offset > 0.0
+                        && self.exit_offset < height
+                    {
+                        // Settle fully in or out, whichever is closer.
+                        let target = if offset > 0.0 && self.exit_offset > height / 2.0 {
+                            height
+                        } else {
+                            0.0
+                        };
+                        let mut snap = AnimatedValue::new(self.exit_offset)
+                            .with_duration(Duration::SHORT4)
+                            .with_easing(Easing::Standard);
+                        snap.set_target(target);
+                        self.snap = Some(snap);
+                    }
+                }
             }
         }
+
+        // Collapsing bars only count as scrolled-under once fully collapsed.
+        let threshold = if self.scroll_behavior == TopAppBarScrollBehavior::Collapse {
+            self.collapse_range()
+        } else {
+            0.0
+        };
+        self.scrolled_under = offset > threshold;
     }
 
     /// Get the background color
     pub fn background_color(&self, theme: &MaterialTheme) -> Color {
-        if self.elevated {
+        if self.elevated || self.scrolled_under {
             theme.surface_container
         } else {
             theme.surface
@@ -310,6 +421,10 @@ pub struct AppBarActionButton {
 #[derive(Component)]
 pub struct AppBarTitle;
 
+/// Marker for the expanded title of Medium and Large top app bars
+#[derive(Component)]
+pub struct AppBarLargeTitle;
+
 // ============================================================================
 // Dimensions
 // ============================================================================
@@ -322,6 +437,8 @@ pub const TOP_APP_BAR_HEIGHT_MEDIUM: f32 = 112.0;
 pub const TOP_APP_BAR_HEIGHT_LARGE: f32 = 152.0;
 /// Bottom app bar height
 pub const BOTTOM_APP_BAR_HEIGHT: f32 = 80.0;
+/// Seconds without scrolling before an Enter/Exit bar snaps in or out
+pub const TOP_APP_BAR_SNAP_DELAY: f32 = 0.15;
 
 // ============================================================================
 // Builder
@@ -388,6 +505,18 @@ impl TopAppBarBuilder {
         self
     }
 
+    /// Set scroll behavior
+    pub fn scroll_behavior(mut self, behavior: TopAppBarScrollBehavior) -> Self {
+        self.app_bar.scroll_behavior = behavior;
+        self
+    }
+
+    /// Bind the bar to a scroll container
+    pub fn scroll_target(mut self, target: Entity) -> Self {
+        self.app_bar.scroll_target = Some(target);
+        self
+    }
+
     /// Set elevated
     pub fn elevated(mut self) -> Self {
         self.app_bar.elevated = true;
@@ -396,18 +525,24 @@ impl TopAppBarBuilder {
 
     /// Build the app bar bundle
     pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
-        let height = self.app_bar.height();
+        let height = self.app_bar.current_height();
         let bg_color = self.app_bar.background_color(theme);
+        let expands = self.app_bar.collapse_range() > 0.0;
 
         (
             self.app_bar,
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Px(height),
-                padding: UiRect::axes(Val::Px(Spacing::EXTRA_SMALL), Val::Px(0.0)),
+                padding: top_app_bar_padding(height),
                 flex_direction: FlexDirection::Row,
                 align_items: AlignItems::Center,
                 justify_content: JustifyContent::SpaceBetween,
+                overflow: if expands {
+                    Overflow::clip()
+                } else {
+                    Overflow::default()
+                },
                 ..default()
             },
             BackgroundColor(bg_color),
@@ -415,6 +550,56 @@ impl TopAppBarBuilder {
     }
 }
 
+/// Padding that keeps the bar's row of icons in the top 64px, leaving the
+/// rest of a Medium or Large bar for the expanded title.
+fn top_app_bar_padding(height: f32) -> UiRect {
+    UiRect {
+        left: Val::Px(Spacing::EXTRA_SMALL),
+        right: Val::Px(Spacing::EXTRA_SMALL),
+        top: Val::Px(0.0),
+        bottom: Val::Px((height - TOP_APP_BAR_HEIGHT_SMALL).max(0.0)),
+    }
+}
+
+/// Spawn the expanded title of a Medium or Large bar, pinned to its bottom edge
+fn spawn_large_title(
+    parent: &mut ChildSpawnerCommands,
+    variant: TopAppBarVariant,
+    title: &str,
+    color: Color,
+) {
+    let (font_size, bottom) = match variant {
+        TopAppBarVariant::Medium => (24.0, Spacing::EXTRA_LARGE),
+        TopAppBarVariant::Large => (28.0, 28.0),
+        _ => return,
+    };
+
+    parent.spawn((
+        AppBarLargeTitle,
+        Text::new(title),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(Spacing::LARGE),
+            right: Val::Px(Spacing::LARGE),
+            bottom: Val::Px(bottom),
+            ..default()
+        },
+    ));
+}
+
+/// Alphas of the inline and expanded titles for a collapse fraction: the
+/// expanded title fades out over the first half, the inline one in over the second.
+fn title_alphas(collapsed: f32) -> (f32, f32) {
+    let inline = ((collapsed - 0.5) * 2.0).clamp(0.0, 1.0);
+    let large = (1.0 - collapsed * 2.0).clamp(0.0, 1.0);
+    (inline, large)
+}
+
 /// Builder for creating Bottom App Bars
 pub struct BottomAppBarBuilder {
     app_bar: BottomAppBar,
@@ -533,22 +718,32 @@ impl SpawnAppBarChild for ChildSpawnerCommands<'_> {
     ) {
         let title_text = builder.app_bar.title.clone();
         let title_color = builder.app_bar.title_color(theme);
+        let variant = builder.app_bar.variant;
+        let inline_alpha = title_alphas(builder.app_bar.collapsed_fraction()).0;
+        let expands = builder.app_bar.collapse_range() > 0.0;
 
         self.spawn(builder.build(theme)).with_children(|bar| {
             // Title
             bar.spawn((
+                AppBarTitle,
                 Text::new(&title_text),
                 TextFont {
                     font_size: 22.0,
                     ..default()
                 },
-                TextColor(title_color),
+                TextColor(if expands {
+                    title_color.with_alpha(inline_alpha)
+                } else {
+                    title_color
+                }),
                 Node {
                     flex_grow: 1.0,
                     ..default()
                 },
             ));
 
+            spawn_large_title(bar, variant, &title_text, title_color);
+
             // Additional content
             with_content(bar);
         });
@@ -587,6 +782,8 @@ pub fn spawn_top_app_bar(
     let nav_icon = builder.app_bar.navigation_icon.clone();
     let actions = builder.app_bar.actions.clone();
     let variant = builder.app_bar.variant;
+    let inline_alpha = title_alphas(builder.app_bar.collapsed_fraction()).0;
+    let expands = builder.app_bar.collapse_range() > 0.0;
 
     commands
         .spawn(builder.build(theme))
@@ -628,8 +825,8 @@ pub fn spawn_top_app_bar(
                         });
                     }
 
-                    // Title (for Small variant)
-                    if variant == TopAppBarVariant::Small {
+                    // Inline title (Medium and Large fade it in as they collapse)
+                    if variant != TopAppBarVariant::CenterAligned {
                         left.spawn((
                             AppBarTitle,
                             Text::new(&title),
@@ -637,7 +834,11 @@ pub fn spawn_top_app_bar(
                                 font_size: 22.0,
                                 ..default()
                             },
-                            TextColor(title_color),
+                            TextColor(if expands {
+                                title_color.with_alpha(inline_alpha)
+                            } else {
+                                title_color
+                            }),
                         ));
                     }
                 });
@@ -699,6 +900,9 @@ pub fn spawn_top_app_bar(
                             });
                     }
                 });
+
+            // Expanded title (Medium and Large)
+            spawn_large_title(parent, variant, &title, title_color);
         })
         .id()
 }
@@ -707,13 +911,87 @@ pub fn spawn_top_app_bar(
 // Systems
 // ============================================================================
 
-/// System to handle scroll behavior for top app bars
+/// System to drive top app bars from their target scroll container
 fn top_app_bar_scroll_system(
-    mut _app_bars: Query<(&mut TopAppBar, &mut Node)>,
-    // In a real implementation, this would listen to scroll events
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Option<Res<MaterialTheme>>,
+    mut app_bars: Query<(Entity, &mut TopAppBar, &mut Node, &mut BackgroundColor)>,
+    containers: Query<&ScrollContainer>,
+    mut titles: Query<
+        (Entity, &mut TextColor, Has<AppBarLargeTitle>),
+        Or<(With<AppBarTitle>, With<AppBarLargeTitle>)>,
+    >,
+    parents: Query<&ChildOf>,
 ) {
-    // Placeholder for scroll behavior implementation
-    // This would track scroll position and update the app bar height/style accordingly
+    let Some(theme) = theme else { return };
+    let dt = time.delta_secs();
+    let mut fades: HashMap<Entity, (f32, Color)> = HashMap::new();
+
+    for (entity, mut app_bar, mut node, mut bg) in app_bars.iter_mut() {
+        let Some(offset) = app_bar
+            .scroll_target
+            .and_then(|target| containers.get(target).ok())
+            .map(|container| container.offset.y)
+        else {
+            continue;
+        };
+
+        // The snap timers tick every frame; only the visible state marks the bar changed.
+        let before = app_bar.scroll_state();
+        app_bar.bypass_change_detection().apply_scroll(offset, dt);
+        if app_bar.scroll_state() != before {
+            app_bar.set_changed();
+        }
+        let was_under = before.2;
+
+        let height = app_bar.current_height();
+        if node.height != Val::Px(height) {
+            node.height = Val::Px(height);
+            node.padding = top_app_bar_padding(height);
+        }
+        let margin_top = Val::Px(-app_bar.exit_offset());
+        if matches!(
+            app_bar.scroll_behavior,
+            TopAppBarScrollBehavior::Scroll | TopAppBarScrollBehavior::EnterExit
+        ) && node.margin.top != margin_top
+        {
+            node.margin.top = margin_top;
+        }
+
+        bg.set_if_neq(BackgroundColor(app_bar.background_color(&theme)));
+        if app_bar.scrolled_under != was_under {
+            let shadow = if app_bar.scrolled_under {
+                Elevation::Level2.to_box_shadow()
+            } else {
+                BoxShadow::default()
+            };
+            commands.entity(entity).insert(shadow);
+        }
+
+        if app_bar.collapse_range() > 0.0 {
+            fades.insert(
+                entity,
+                (app_bar.collapsed_fraction(), app_bar.title_color(&theme)),
+            );
+        }
+    }
+
+    if fades.is_empty() {
+        return;
+    }
+
+    for (entity, mut color, is_large) in titles.iter_mut() {
+        let Some((collapsed, title_color)) = parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| fades.get(&ancestor))
+        else {
+            continue;
+        };
+        let (inline, large) = title_alphas(*collapsed);
+        let alpha = if is_large { large } else { inline };
+        color.set_if_neq(TextColor(title_color.with_alpha(alpha)));
+    }
 }
 
 /// System to handle app bar interactions
@@ -778,6 +1056,100 @@ mod tests {
         assert_eq!(large.height(), TOP_APP_BAR_HEIGHT_LARGE);
     }
 
+    #[test]
+    fn test_collapse_scroll_behavior() {
+        let mut app_bar = TopAppBar::new("Large")
+            .with_variant(TopAppBarVariant::Large)
+            .with_scroll_behavior(TopAppBarScrollBehavior::Collapse);
+
+        let range = TOP_APP_BAR_HEIGHT_LARGE - TOP_APP_BAR_HEIGHT_SMALL;
+        app_bar.apply_scroll(range / 2.0, 0.016);
+        assert_eq!(app_bar.collapsed_fraction(), 0.5);
+        assert!(!app_bar.scrolled_under);
+
+        app_bar.apply_scroll(range + 10.0, 0.016);
+        assert_eq!(app_bar.current_height(), TOP_APP_BAR_HEIGHT_SMALL);
+        assert_eq!(app_bar.height(), TOP_APP_BAR_HEIGHT_LARGE);
+        assert!(app_bar.scrolled_under);
+
+        assert_eq!(title_alphas(0.0), (0.0, 1.0));
+        assert_eq!(title_alphas(1.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_enter_exit_snaps_when_scrolling_stops() {
+        let mut app_bar =
+            TopAppBar::new("Small").with_scroll_behavior(TopAppBarScrollBehavior::EnterExit);
+
+        app_bar.apply_scroll(0.0, 0.016);
+        app_bar.apply_scroll(40.0, 0.016);
+        assert_eq!(app_bar.exit_offset(), 40.0);
+
+        // Past the halfway point, the bar settles fully hidden.
+        for _ in 0..60 {
+            app_bar.apply_scroll(40.0, 0.016);
+        }
+        assert_eq!(app_bar.exit_offset(), TOP_APP_BAR_HEIGHT_SMALL);
+
+        // Scrolling back up reveals it again.
+        app_bar.apply_scroll(5.0, 0.016);
+        assert_eq!(app_bar.exit_offset(), TOP_APP_BAR_HEIGHT_SMALL - 35.0);
+        for _ in 0..60 {
+            app_bar.apply_scroll(5.0, 0.016);
+        }
+        assert_eq!(app_bar.exit_offset(), 0.0);
+    }
+
+    /// Frames in which a top app bar was marked changed
+    #[derive(Resource, Default)]
+    struct BarChanges(usize);
+
+    fn count_bar_changes(bars: Query<(), Changed<TopAppBar>>, mut changes: ResMut<BarChanges>) {
+        if !bars.is_empty() {
+            changes.0 += 1;
+        }
+    }
+
+    #[test]
+    fn test_scroll_system_only_marks_changes() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AppBarPlugin))
+            .init_resource::<MaterialTheme>()
+            .init_resource::<BarChanges>()
+            .add_systems(PostUpdate, count_bar_changes);
+        let container = app.world_mut().spawn(ScrollContainer::vertical()).id();
+        let theme = MaterialTheme::default();
+        let bar = app
+            .world_mut()
+            .spawn(
+                TopAppBarBuilder::new("Title")
+                    .large()
+                    .scroll_behavior(TopAppBarScrollBehavior::Collapse)
+                    .scroll_target(container)
+                    .build(&theme),
+            )
+            .id();
+
+        // Spawned, then idle
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<BarChanges>().0, 1);
+
+        app.world_mut()
+            .get_mut::<ScrollContainer>(container)
+            .unwrap()
+            .offset
+            .y = 20.0;
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<BarChanges>().0, 2);
+        let app_bar = app.world().get::<TopAppBar>(bar).unwrap();
+        assert_eq!(app_bar.current_height(), TOP_APP_BAR_HEIGHT_LARGE - 20.0);
+        assert_eq!(app_bar.height(), TOP_APP_BAR_HEIGHT_LARGE);
+    }
+
     #[test]
     fn test_bottom_app_bar_creation() {
         let app_bar = BottomAppBar::new()
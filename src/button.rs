@@ -15,6 +15,7 @@ use bevy::ui::{BoxShadow, Val};
 
 use crate::{
     elevation::Elevation,
+    focus::{FocusAppExt, FocusManager, FocusableComponent},
     ripple::RippleHost,
     theme::{blend_state_layer, MaterialTheme},
     tokens::{CornerRadius, Spacing},
@@ -25,16 +26,19 @@ pub struct ButtonPlugin;
 
 impl Plugin for ButtonPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<ButtonClickEvent>().add_systems(
-            Update,
-            (
-                button_interaction_system,
-                button_style_system,
-                button_label_style_system,
-                button_theme_refresh_system,
-                button_shadow_system,
-            ),
-        );
+        app.add_message::<ButtonClickEvent>()
+            .register_focusable::<MaterialButton>()
+            .add_systems(
+                Update,
+                (
+                    button_interaction_system,
+                    button_keyboard_system,
+                    button_style_system,
+                    button_label_style_system,
+                    button_theme_refresh_system,
+                    button_shadow_system,
+                ),
+            );
     }
 }
 
@@ -388,6 +392,12 @@ impl MaterialButton {
     }
 }
 
+impl FocusableComponent for MaterialButton {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event fired when a button is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct ButtonClickEvent {
@@ -426,6 +436,25 @@ fn button_interaction_system(
     }
 }
 
+/// System to activate the focused button with Space / Enter
+fn button_keyboard_system(
+    focus: Res<FocusManager>,
+    buttons: Query<&MaterialButton>,
+    mut click_events: MessageWriter<ButtonClickEvent>,
+) {
+    if !focus.activation_requested() {
+        return;
+    }
+
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+
+    if buttons.get(entity).is_ok_and(|button| !button.disabled) {
+        click_events.write(ButtonClickEvent { entity });
+    }
+}
+
 /// System to update button visual styles based on state
 fn button_style_system(
     theme: Option<Res<MaterialTheme>>,
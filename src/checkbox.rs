@@ -11,6 +11,7 @@
 use bevy::prelude::*;
 
 use crate::{
+    focus::{FocusAppExt, FocusManager, FocusableComponent},
     icons::{MaterialIconFont, ICON_CHECK, ICON_REMOVE},
     motion::{ease_emphasized_decelerate, StateLayer},
     ripple::RippleHost,
@@ -23,16 +24,19 @@ pub struct CheckboxPlugin;
 
 impl Plugin for CheckboxPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<CheckboxChangeEvent>().add_systems(
-            Update,
-            (
-                checkbox_interaction_system,
-                checkbox_visual_update_system,
-                checkbox_theme_refresh_system,
-                checkbox_animation_system,
-            )
-                .chain(),
-        );
+        app.add_message::<CheckboxChangeEvent>()
+            .register_focusable::<MaterialCheckbox>()
+            .add_systems(
+                Update,
+                (
+                    checkbox_interaction_system,
+                    checkbox_keyboard_system,
+                    checkbox_visual_update_system,
+                    checkbox_theme_refresh_system,
+                    checkbox_animation_system,
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -220,6 +224,12 @@ impl Default for MaterialCheckbox {
     }
 }
 
+impl FocusableComponent for MaterialCheckbox {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event when checkbox state changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct CheckboxChangeEvent {
@@ -284,6 +294,36 @@ fn checkbox_interaction_system(
     }
 }
 
+/// System to toggle the focused checkbox with Space / Enter
+fn checkbox_keyboard_system(
+    focus: Res<FocusManager>,
+    mut checkboxes: Query<&mut MaterialCheckbox>,
+    mut change_events: MessageWriter<CheckboxChangeEvent>,
+) {
+    if !focus.activation_requested() {
+        return;
+    }
+
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+
+    let Ok(mut checkbox) = checkboxes.get_mut(entity) else {
+        return;
+    };
+
+    if checkbox.disabled {
+        return;
+    }
+
+    let new_state = checkbox.state.toggle();
+    checkbox.start_animation(new_state);
+    change_events.write(CheckboxChangeEvent {
+        entity,
+        state: checkbox.state,
+    });
+}
+
 /// System to update checkbox visual styles when state changes
 fn checkbox_visual_update_system(
     theme: Option<Res<MaterialTheme>>,
@@ -11,6 +11,7 @@ use bevy::ui::BoxShadow;
 
 use crate::{
     elevation::Elevation,
+    focus::{FocusAppExt, FocusManager, FocusableComponent},
     ripple::RippleHost,
     theme::{blend_state_layer, MaterialTheme},
     tokens::Spacing,
@@ -22,11 +23,13 @@ pub struct ChipPlugin;
 impl Plugin for ChipPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<ChipClickEvent>()
+            .register_focusable::<MaterialChip>()
             .add_message::<ChipDeleteEvent>()
             .add_systems(
                 Update,
                 (
                     chip_interaction_system,
+                    chip_keyboard_system,
                     chip_style_system,
                     chip_content_style_system,
                     chip_theme_refresh_system,
@@ -308,6 +311,12 @@ impl Default for MaterialChip {
     }
 }
 
+impl FocusableComponent for MaterialChip {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Marker for chip delete button
 #[derive(Component)]
 pub struct ChipDeleteButton;
@@ -753,6 +762,48 @@ fn chip_interaction_system(
     }
 }
 
+/// System to handle keyboard input for the focused chip.
+///
+/// Space / Enter act like a click (toggling filter chips), and
+/// Delete / Backspace remove deletable (input) chips.
+fn chip_keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<FocusManager>,
+    mut chips: Query<&mut MaterialChip>,
+    mut click_events: MessageWriter<ChipClickEvent>,
+    mut delete_events: MessageWriter<ChipDeleteEvent>,
+) {
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+
+    let Ok(mut chip) = chips.get_mut(entity) else {
+        return;
+    };
+
+    if chip.disabled {
+        return;
+    }
+
+    if focus.activation_requested() {
+        if chip.variant == ChipVariant::Filter {
+            chip.selected = !chip.selected;
+        }
+
+        click_events.write(ChipClickEvent {
+            entity,
+            value: chip.value.clone(),
+        });
+    }
+
+    if chip.deletable && keys.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+        delete_events.write(ChipDeleteEvent {
+            entity,
+            value: chip.value.clone(),
+        });
+    }
+}
+
 /// System to update chip styles
 fn chip_style_system(
     theme: Option<Res<MaterialTheme>>,
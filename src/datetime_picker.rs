@@ -2,10 +2,17 @@
 //!
 //! A lightweight dialog-style date+time picker built using this crate's
 //! existing button/icon-button primitives.
+//!
+//! Besides single dates, the picker can select a date range
+//! ([`DateSelectionMode::Range`]): the first tap sets the start, the second the end,
+//! and the days between are drawn as a band. [`DatePickerLayout::FullScreen`] lays
+//! the picker out over the whole window with a vertically scrolling list of months,
+//! which suits compact windows.
 
 use bevy::prelude::*;
 
 use crate::button::{ButtonLabel, ButtonVariant, MaterialButton, MaterialButtonBuilder};
+use crate::focus::{FocusAppExt, FocusTrap, OverlayComponent};
 use crate::icon_button::{IconButtonBuilder, IconButtonVariant, MaterialIconButton, ICON_SIZE};
 use crate::icons::{IconStyle, MaterialIcon};
 use crate::scroll::{spawn_scrollbars, ScrollContainerBuilder, ScrollDirection};
@@ -19,6 +26,7 @@ impl Plugin for DateTimePickerPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<DateTimePickerSubmitEvent>()
             .add_message::<DateTimePickerCancelEvent>()
+            .register_overlay::<MaterialDateTimePicker>()
             .add_systems(
                 Update,
                 (
@@ -202,6 +210,49 @@ pub enum TimeFormat {
     H12,
 }
 
+/// Whether the picker selects a single date or a range of dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateSelectionMode {
+    #[default]
+    Single,
+    /// First tap picks the start date, second tap the end date
+    Range,
+}
+
+/// How the picker is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DatePickerLayout {
+    /// Centered dialog paging one month at a time
+    #[default]
+    Dialog,
+    /// Covers the window and scrolls through consecutive months
+    FullScreen,
+}
+
+/// Most months listed by the full-screen layout.
+pub const DATE_PICKER_MAX_MONTHS: usize = 24;
+
+/// An inclusive range of dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: Date,
+    pub end: Date,
+}
+
+impl DateRange {
+    /// Create a range from two dates in either order.
+    pub fn new(a: Date, b: Date) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CalendarSelector {
     Day,
@@ -233,6 +284,60 @@ pub struct MaterialDateTimePicker {
 
     pub min_date: Option<Date>,
     pub max_date: Option<Date>,
+
+    pub selection_mode: DateSelectionMode,
+    /// Start of the selected range (range mode)
+    pub range_start: Option<Date>,
+    /// End of the selected range (range mode); `None` until the second tap
+    pub range_end: Option<Date>,
+    pub layout: DatePickerLayout,
+}
+
+impl MaterialDateTimePicker {
+    /// Whether `date` lies within `min_date` / `max_date`.
+    pub fn is_date_allowed(&self, date: Date) -> bool {
+        self.min_date.is_none_or(|min| date >= min) && self.max_date.is_none_or(|max| date <= max)
+    }
+
+    /// Select a date as if its day cell was tapped. In range mode the first tap
+    /// starts a new range and the second completes it; a second tap before the
+    /// start restarts the range there. Returns `false` for disallowed dates.
+    pub fn select_date(&mut self, date: Date) -> bool {
+        if !date.is_valid() || !self.is_date_allowed(date) {
+            return false;
+        }
+
+        match self.selection_mode {
+            DateSelectionMode::Single => self.selected_date = Some(date),
+            DateSelectionMode::Range => match (self.range_start, self.range_end) {
+                (Some(start), None) if date >= start => self.range_end = Some(date),
+                _ => {
+                    self.range_start = Some(date);
+                    self.range_end = None;
+                }
+            },
+        }
+        true
+    }
+
+    /// The selected range, once both ends are chosen.
+    pub fn date_range(&self) -> Option<DateRange> {
+        Some(DateRange::new(self.range_start?, self.range_end?))
+    }
+
+    /// Whether the picker holds a complete selection that OK can submit.
+    pub fn is_selection_complete(&self) -> bool {
+        match self.selection_mode {
+            DateSelectionMode::Single => self.selected_date.is_some(),
+            DateSelectionMode::Range => self.date_range().is_some(),
+        }
+    }
+}
+
+impl OverlayComponent for MaterialDateTimePicker {
+    fn is_overlay_open(&self) -> bool {
+        self.open
+    }
 }
 
 impl Default for MaterialDateTimePicker {
@@ -254,6 +359,10 @@ impl Default for MaterialDateTimePicker {
             dismiss_on_escape: true,
             min_date: None,
             max_date: None,
+            selection_mode: DateSelectionMode::Single,
+            range_start: None,
+            range_end: None,
+            layout: DatePickerLayout::Dialog,
         }
     }
 }
@@ -298,6 +407,31 @@ impl DateTimePickerBuilder {
         self
     }
 
+    /// Select a date range instead of a single date.
+    pub fn range_selection(mut self) -> Self {
+        self.picker.selection_mode = DateSelectionMode::Range;
+        self
+    }
+
+    /// Select a date range, starting with `start`..=`end` selected.
+    pub fn range(mut self, start: Date, end: Date) -> Self {
+        self.picker.selection_mode = DateSelectionMode::Range;
+        if start.is_valid() && end.is_valid() {
+            let range = DateRange::new(start, end);
+            self.picker.range_start = Some(range.start);
+            self.picker.range_end = Some(range.end);
+            self.picker.display_year = range.start.year;
+            self.picker.display_month = range.start.month;
+        }
+        self
+    }
+
+    /// Lay the picker out full-screen with scrolling months (for compact windows).
+    pub fn full_screen(mut self) -> Self {
+        self.picker.layout = DatePickerLayout::FullScreen;
+        self
+    }
+
     pub fn time(mut self, hour: u8, minute: u8) -> Self {
         self.picker.hour = hour % 24;
         self.picker.minute = minute % 60;
@@ -356,6 +490,7 @@ impl DateTimePickerBuilder {
         Visibility,
         GlobalZIndex,
         BackgroundColor,
+        FocusTrap,
     ) {
         (
             self.picker.clone(),
@@ -376,6 +511,7 @@ impl DateTimePickerBuilder {
             },
             GlobalZIndex(9999),
             BackgroundColor(Color::NONE),
+            FocusTrap::while_visible(),
         )
     }
 
@@ -405,7 +541,10 @@ impl DateTimePickerBuilder {
 #[derive(Event, bevy::prelude::Message)]
 pub struct DateTimePickerSubmitEvent {
     pub entity: Entity,
+    /// The selected date, or the start of the selected range
     pub date: Date,
+    /// The selected range (range mode only)
+    pub range: Option<DateRange>,
     pub hour: u8,
     pub minute: u8,
 }
@@ -484,6 +623,54 @@ struct DateTimePickerAction {
 struct DateTimePickerDayCell {
     picker: Entity,
     index: u8,
+    /// Month the cell belongs to; `None` follows the displayed month
+    month: Option<(i32, u8)>,
+}
+
+/// The date shown by a day cell, if the cell is not a blank.
+fn cell_date(picker: &MaterialDateTimePicker, cell: &DateTimePickerDayCell) -> Option<Date> {
+    let (year, month) = cell
+        .month
+        .unwrap_or((picker.display_year, picker.display_month));
+    let offset = month_offset(year, month, picker.first_day_of_week);
+    let day_number = cell.index as i32 - offset + 1;
+    let dim = days_in_month(year, month) as i32;
+    (1..=dim)
+        .contains(&day_number)
+        .then(|| Date::new(year, month, day_number as u8))
+}
+
+/// Blank cells before the first day of a month.
+fn month_offset(year: i32, month: u8, first_day_of_week: Weekday) -> i32 {
+    let first_wd = weekday_for_date(Date::new(year, month, 1));
+    (weekday_index(first_wd) - weekday_index(first_day_of_week)).rem_euclid(7)
+}
+
+/// How a day cell is highlighted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DayHighlight {
+    None,
+    /// The selected date, or a range whose ends are the same day
+    Selected,
+    RangeStart,
+    RangeEnd,
+    /// Strictly between the ends of the range
+    InRange,
+}
+
+fn day_highlight(picker: &MaterialDateTimePicker, date: Date) -> DayHighlight {
+    match picker.selection_mode {
+        DateSelectionMode::Single if picker.selected_date == Some(date) => DayHighlight::Selected,
+        DateSelectionMode::Single => DayHighlight::None,
+        DateSelectionMode::Range => match (picker.range_start, picker.range_end) {
+            (Some(start), Some(end)) if start == date && end == date => DayHighlight::Selected,
+            (Some(start), Some(_)) if start == date => DayHighlight::RangeStart,
+            (Some(start), None) if start == date => DayHighlight::Selected,
+            (_, Some(end)) if end == date => DayHighlight::RangeEnd,
+            (Some(start), Some(end)) if start < date && date < end => DayHighlight::InRange,
+            _ => DayHighlight::None,
+        },
+    }
 }
 
 #[derive(Component)]
@@ -570,6 +757,11 @@ impl SpawnDateTimePickerChild for ChildSpawnerCommands<'_> {
                 BackgroundColor(theme.scrim.with_alpha(0.32)),
             ));
 
+            if builder.picker.layout == DatePickerLayout::FullScreen {
+                spawn_full_screen_dialog(overlay, theme, picker_entity, &builder);
+                return;
+            }
+
             // Dialog
             overlay
                 .spawn((
@@ -756,6 +948,7 @@ impl SpawnDateTimePickerChild for ChildSpawnerCommands<'_> {
                                     DateTimePickerDayCell {
                                         picker: picker_entity,
                                         index,
+                                        month: None,
                                     },
                                     Button,
                                     Interaction::None,
@@ -857,7 +1050,11 @@ impl SpawnDateTimePickerChild for ChildSpawnerCommands<'_> {
                                 });
                         });
 
-                    // Time controls
+                    // Time controls (range pickers select dates only)
+                    if builder.picker.selection_mode == DateSelectionMode::Range {
+                        spawn_actions(dialog, theme, picker_entity);
+                        return;
+                    }
                     dialog
                         .spawn(Node {
                             flex_direction: FlexDirection::Column,
@@ -922,24 +1119,7 @@ impl SpawnDateTimePickerChild for ChildSpawnerCommands<'_> {
                             });
                         });
 
-                    // Actions
-                    dialog
-                        .spawn(Node {
-                            flex_direction: FlexDirection::Row,
-                            justify_content: JustifyContent::End,
-                            column_gap: Val::Px(Spacing::SMALL),
-                            ..default()
-                        })
-                        .with_children(|row| {
-                            spawn_action_button(
-                                row,
-                                theme,
-                                picker_entity,
-                                PickerAction::Cancel,
-                                "Cancel",
-                            );
-                            spawn_action_button(row, theme, picker_entity, PickerAction::Ok, "OK");
-                        });
+                    spawn_actions(dialog, theme, picker_entity);
                 });
         });
 
@@ -947,6 +1127,231 @@ impl SpawnDateTimePickerChild for ChildSpawnerCommands<'_> {
     }
 }
 
+fn spawn_actions(parent: &mut ChildSpawnerCommands, theme: &MaterialTheme, picker: Entity) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::End,
+            column_gap: Val::Px(Spacing::SMALL),
+            ..default()
+        })
+        .with_children(|row| {
+            spawn_action_button(row, theme, picker, PickerAction::Cancel, "Cancel");
+            spawn_action_button(row, theme, picker, PickerAction::Ok, "OK");
+        });
+}
+
+/// Months listed by the full-screen layout: from the displayed month through
+/// `max_date` (or a year), at most [`DATE_PICKER_MAX_MONTHS`]. The displayed
+/// month is clamped to `min_date` / `max_date`; when `max_date` cuts the list
+/// short, earlier months fill it back up to `min_date`.
+fn full_screen_months(picker: &MaterialDateTimePicker) -> Vec<(i32, u8)> {
+    let index = |year: i32, month: u8| year * 12 + month as i32 - 1;
+    let first = picker.min_date.map_or(i32::MIN, |d| index(d.year, d.month));
+    let last = picker
+        .max_date
+        .map_or(i32::MAX, |d| index(d.year, d.month))
+        .max(first);
+    let anchor = index(picker.display_year, picker.display_month).clamp(first, last);
+
+    let span = if picker.max_date.is_some() {
+        DATE_PICKER_MAX_MONTHS as i32
+    } else {
+        12
+    };
+    let end = anchor.saturating_add(span - 1).min(last);
+    let start = (end - span + 1).max(first).min(anchor);
+    (start..=end).map(|i| add_months(0, 1, i)).collect()
+}
+
+/// Full-screen layout: a close button and Save on top, the selection as headline,
+/// then every month of [`full_screen_months`] in a vertical scroll container.
+fn spawn_full_screen_dialog(
+    overlay: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    picker_entity: Entity,
+    builder: &DateTimePickerBuilder,
+) {
+    let first_dow = builder.picker.first_day_of_week;
+
+    overlay
+        .spawn((
+            DateTimePickerDialog {
+                picker: picker_entity,
+            },
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(Spacing::LARGE)),
+                row_gap: Val::Px(Spacing::MEDIUM),
+                ..default()
+            },
+            BackgroundColor(theme.surface_container_high),
+        ))
+        .with_children(|dialog| {
+            dialog
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|row| {
+                    let close_icon = "close";
+                    let close_btn = MaterialIconButton::new(close_icon)
+                        .with_variant(IconButtonVariant::Standard);
+                    let close_icon_color = close_btn.icon_color(theme);
+
+                    row.spawn((
+                        DateTimePickerAction {
+                            picker: picker_entity,
+                            action: PickerAction::Cancel,
+                        },
+                        Interaction::None,
+                        IconButtonBuilder::new(close_icon)
+                            .variant(IconButtonVariant::Standard)
+                            .build(theme),
+                    ))
+                    .with_children(|btn| {
+                        if let Some(icon) = MaterialIcon::from_name(close_icon) {
+                            btn.spawn((
+                                icon,
+                                IconStyle::outlined()
+                                    .with_color(close_icon_color)
+                                    .with_size(ICON_SIZE),
+                            ));
+                        }
+                    });
+
+                    spawn_action_button(row, theme, picker_entity, PickerAction::Ok, "Save");
+                });
+
+            dialog.spawn((
+                Text::new(builder.picker.title.clone()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.on_surface_variant),
+            ));
+
+            dialog.spawn((
+                DateTimePickerLabel {
+                    picker: picker_entity,
+                    kind: PickerLabelKind::Selected,
+                },
+                Text::new(""),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(theme.on_surface_variant),
+            ));
+
+            // Weekday header
+            dialog
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    ..default()
+                })
+                .with_children(|row| {
+                    for wd in Weekday::all_starting_from(first_dow) {
+                        row.spawn((
+                            Text::new(wd.short_name()),
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(theme.on_surface_variant),
+                            TextLayout::new_with_justify(Justify::Center),
+                            Node {
+                                width: Val::Percent(100.0 / 7.0),
+                                ..default()
+                            },
+                        ));
+                    }
+                });
+
+            dialog
+                .spawn((
+                    ScrollContainerBuilder::new()
+                        .vertical()
+                        .with_scrollbars(true)
+                        .build(),
+                    ScrollPosition::default(),
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_grow: 1.0,
+                        min_height: Val::Px(0.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(Spacing::LARGE),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                ))
+                .with_children(|months| {
+                    spawn_scrollbars(months, theme, ScrollDirection::Vertical);
+
+                    for (year, month) in full_screen_months(&builder.picker) {
+                        months.spawn((
+                            Text::new(format!("{} {}", month_name(month), year)),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(theme.on_surface_variant),
+                        ));
+
+                        let cells = month_offset(year, month, first_dow)
+                            + days_in_month(year, month) as i32;
+                        let cells = (cells + 6) / 7 * 7;
+
+                        months
+                            .spawn(Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Row,
+                                flex_wrap: FlexWrap::Wrap,
+                                ..default()
+                            })
+                            .with_children(|grid| {
+                                for index in 0..cells as u8 {
+                                    grid.spawn((
+                                        DateTimePickerDayCell {
+                                            picker: picker_entity,
+                                            index,
+                                            month: Some((year, month)),
+                                        },
+                                        Button,
+                                        Interaction::None,
+                                        Node {
+                                            width: Val::Percent(100.0 / 7.0),
+                                            height: Val::Px(40.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::NONE),
+                                        BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                                    ))
+                                    .with_children(|cell| {
+                                        cell.spawn((
+                                            DateTimePickerDayCellText,
+                                            Text::new(""),
+                                            TextFont {
+                                                font_size: 14.0,
+                                                ..default()
+                                            },
+                                            TextColor(theme.on_surface),
+                                        ));
+                                    });
+                                }
+                            });
+                    }
+                });
+        });
+}
+
 fn spawn_time_adjust(
     parent: &mut ChildSpawnerCommands,
     theme: &MaterialTheme,
@@ -1169,35 +1574,10 @@ fn datetime_picker_day_interaction_system(
             continue;
         }
 
-        let first = Date::new(picker.display_year, picker.display_month, 1);
-        let first_wd = weekday_for_date(first);
-        let offset =
-            (weekday_index(first_wd) - weekday_index(picker.first_day_of_week)).rem_euclid(7);
-        let day_number = cell.index as i32 - offset + 1;
-
-        let dim = days_in_month(picker.display_year, picker.display_month) as i32;
-        if !(1..=dim).contains(&day_number) {
-            continue;
-        }
-
-        let selected = Date::new(picker.display_year, picker.display_month, day_number as u8);
-        if !selected.is_valid() {
-            continue;
-        }
-
-        // Optional min/max constraints
-        if let Some(min) = picker.min_date {
-            if selected < min {
-                continue;
-            }
+        // Min/max constraints are applied by `select_date`.
+        if let Some(date) = cell_date(&picker, cell) {
+            picker.select_date(date);
         }
-        if let Some(max) = picker.max_date {
-            if selected > max {
-                continue;
-            }
-        }
-
-        picker.selected_date = Some(selected);
     }
 }
 
@@ -1271,11 +1651,17 @@ fn datetime_picker_action_interaction_system(
                 });
             }
             PickerAction::Ok => {
-                if let Some(date) = picker.selected_date {
+                let range = picker.date_range();
+                let date = match picker.selection_mode {
+                    DateSelectionMode::Single => picker.selected_date,
+                    DateSelectionMode::Range => range.map(|range| range.start),
+                };
+                if let Some(date) = date {
                     picker.open = false;
                     submit.write(DateTimePickerSubmitEvent {
                         entity: action.picker,
                         date,
+                        range,
                         hour: picker.hour,
                         minute: picker.minute,
                     });
@@ -1314,6 +1700,18 @@ fn datetime_picker_dialog_render_system(
                 }
                 *color = TextColor(theme.on_surface);
             }
+            PickerLabelKind::Selected if picker.selection_mode == DateSelectionMode::Range => {
+                let format_end = |date: Option<Date>, placeholder: &str| match date {
+                    Some(d) => format!("{:04}-{:02}-{:02}", d.year, d.month, d.day),
+                    None => placeholder.to_string(),
+                };
+                *text = Text::new(format!(
+                    "{}  –  {}",
+                    format_end(picker.range_start, "Start date"),
+                    format_end(picker.range_end, "End date")
+                ));
+                *color = TextColor(theme.on_surface_variant);
+            }
             PickerLabelKind::Selected => {
                 let date_part = if let Some(d) = picker.selected_date {
                     format!("{:04}-{:02}-{:02}", d.year, d.month, d.day)
@@ -1380,7 +1778,7 @@ fn datetime_picker_year_grid_render_system(
             continue;
         }
 
-        let selected_year = picker.selected_date.map(|d| d.year);
+        let selected_year = picker.selected_date.or(picker.range_start).map(|d| d.year);
         let is_selected = selected_year == Some(cell.year);
 
         *bg = if is_selected {
@@ -1405,12 +1803,18 @@ fn datetime_picker_year_grid_render_system(
 fn datetime_picker_day_grid_render_system(
     theme: Res<MaterialTheme>,
     pickers: Query<&MaterialDateTimePicker>,
-    mut cells: Query<(&DateTimePickerDayCell, &mut BackgroundColor, &Children)>,
+    mut cells: Query<(
+        &DateTimePickerDayCell,
+        &mut BackgroundColor,
+        &mut BorderRadius,
+        &Children,
+    )>,
     mut texts: Query<(&DateTimePickerDayCellText, &mut Text, &mut TextColor)>,
 ) {
     let theme_changed = theme.is_changed();
+    let full = Val::Px(CornerRadius::FULL);
 
-    for (cell, mut bg, children) in cells.iter_mut() {
+    for (cell, mut bg, mut radius, children) in cells.iter_mut() {
         let Ok(picker) = pickers.get(cell.picker) else {
             continue;
         };
@@ -1418,45 +1822,37 @@ fn datetime_picker_day_grid_render_system(
             continue;
         }
 
-        let first = Date::new(picker.display_year, picker.display_month, 1);
-        let first_wd = weekday_for_date(first);
-        let offset =
-            (weekday_index(first_wd) - weekday_index(picker.first_day_of_week)).rem_euclid(7);
-        let day_number = cell.index as i32 - offset + 1;
-
-        let dim = days_in_month(picker.display_year, picker.display_month) as i32;
-        let valid = (1..=dim).contains(&day_number);
-
-        let cell_date = if valid {
-            Some(Date::new(
-                picker.display_year,
-                picker.display_month,
-                day_number as u8,
-            ))
-        } else {
-            None
+        let cell_date = cell_date(picker, cell);
+        let highlight = cell_date.map_or(DayHighlight::None, |date| day_highlight(picker, date));
+
+        // Range ends are half pills joining the band of the days between them.
+        let (background, corners) = match highlight {
+            DayHighlight::None => (Color::NONE, BorderRadius::all(full)),
+            DayHighlight::Selected => (theme.primary, BorderRadius::all(full)),
+            DayHighlight::RangeStart => (theme.primary, BorderRadius::left(full)),
+            DayHighlight::RangeEnd => (theme.primary, BorderRadius::right(full)),
+            DayHighlight::InRange => (theme.secondary_container, BorderRadius::ZERO),
         };
+        bg.set_if_neq(BackgroundColor(background));
+        radius.set_if_neq(corners);
 
-        let is_selected = cell_date.is_some() && picker.selected_date == cell_date;
-        *bg = if is_selected {
-            BackgroundColor(theme.primary)
-        } else {
-            BackgroundColor(Color::NONE)
+        let text_color = match (highlight, cell_date) {
+            (DayHighlight::Selected | DayHighlight::RangeStart | DayHighlight::RangeEnd, _) => {
+                theme.on_primary
+            }
+            (DayHighlight::InRange, _) => theme.on_secondary_container,
+            (_, Some(date)) if !picker.is_date_allowed(date) => theme.on_surface.with_alpha(0.38),
+            (_, Some(_)) => theme.on_surface,
+            (_, None) => theme.on_surface_variant,
         };
+        let label = cell_date.map(|d| d.day.to_string()).unwrap_or_default();
 
         for child in children.iter() {
             if let Ok((_marker, mut text, mut color)) = texts.get_mut(child) {
-                if let Some(d) = cell_date {
-                    *text = Text::new(format!("{}", d.day));
-                    *color = if is_selected {
-                        TextColor(theme.on_primary)
-                    } else {
-                        TextColor(theme.on_surface)
-                    };
-                } else {
-                    *text = Text::new("");
-                    *color = TextColor(theme.on_surface_variant);
+                if text.0 != label {
+                    text.0.clone_from(&label);
                 }
+                color.set_if_neq(TextColor(text_color));
             }
         }
     }
@@ -1482,3 +1878,52 @@ fn datetime_picker_theme_refresh_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picker(display: Date, min: Option<Date>, max: Option<Date>) -> MaterialDateTimePicker {
+        MaterialDateTimePicker {
+            display_year: display.year,
+            display_month: display.month,
+            min_date: min,
+            max_date: max,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_full_screen_months_start_at_display() {
+        let months = full_screen_months(&picker(
+            Date::new(2025, 6, 15),
+            Some(Date::new(2000, 1, 1)),
+            None,
+        ));
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0], (2025, 6));
+        assert_eq!(months[11], (2026, 5));
+    }
+
+    #[test]
+    fn test_full_screen_months_respect_bounds() {
+        let min = Some(Date::new(2025, 3, 1));
+        let max = Some(Date::new(2025, 8, 31));
+
+        let months = full_screen_months(&picker(Date::new(2025, 6, 1), min, max));
+        assert_eq!(months.first(), Some(&(2025, 3)));
+        assert_eq!(months.last(), Some(&(2025, 8)));
+
+        let months = full_screen_months(&picker(Date::new(2020, 1, 1), min, max));
+        assert_eq!(months.first(), Some(&(2025, 3)));
+
+        let months = full_screen_months(&picker(
+            Date::new(2030, 12, 1),
+            Some(Date::new(2000, 1, 1)),
+            Some(Date::new(2030, 12, 31)),
+        ));
+        assert_eq!(months.len(), DATE_PICKER_MAX_MONTHS);
+        assert_eq!(months.first(), Some(&(2029, 1)));
+        assert_eq!(months.last(), Some(&(2030, 12)));
+    }
+}
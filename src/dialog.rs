@@ -5,12 +5,19 @@
 //!
 //! Reference: <https://m3.material.io/components/dialogs/overview>
 
+use bevy::ecs::message::MessageCursor;
 use bevy::picking::Pickable;
 use bevy::prelude::*;
-use bevy::ui::BoxShadow;
+use bevy::ui::{BoxShadow, FocusPolicy};
 
 use crate::{
+    button::{ButtonClickEvent, ButtonLabel, ButtonVariant, MaterialButton, MaterialButtonBuilder},
     elevation::Elevation,
+    focus::{FocusTrap, OpenOverlay},
+    motion::OpenTransition,
+    text_field::{
+        spawn_text_field_control, MaterialTextField, TextFieldBuilder, TextFieldSubmitEvent,
+    },
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
 };
@@ -23,15 +30,24 @@ impl Plugin for DialogPlugin {
         app.add_message::<DialogOpenEvent>()
             .add_message::<DialogCloseEvent>()
             .add_message::<DialogConfirmEvent>()
+            .add_message::<DialogClosedEvent>()
+            .add_message::<DialogResult>()
+            .add_message::<ButtonClickEvent>()
+            .add_message::<TextFieldSubmitEvent>()
             .add_systems(
                 Update,
                 (
+                    dialog_dismiss_system,
+                    quick_dialog_result_system,
+                    dialog_transition_setup_system,
+                    dialog_focus_trap_system,
                     dialog_visibility_system,
                     dialog_scrim_visibility_system,
-                    dialog_scrim_pickable_system,
-                    dialog_shadow_system,
-                ),
-            );
+                    quick_dialog_cleanup_system,
+                )
+                    .chain(),
+            )
+            .add_systems(Update, dialog_scrim_pickable_system);
     }
 }
 
@@ -181,45 +197,191 @@ pub struct DialogConfirmEvent {
     pub entity: Entity,
 }
 
+/// Event when a dialog's exit transition has finished and it is hidden.
+///
+/// The dialog (and its scrim) can be despawned safely from here on.
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub struct DialogClosedEvent {
+    pub entity: Entity,
+}
+
 /// Dialog dimensions
 pub const DIALOG_MIN_WIDTH: f32 = 280.0;
 pub const DIALOG_MAX_WIDTH: f32 = 560.0;
+/// Scale a dialog grows from while entering (and shrinks to while exiting)
+pub const DIALOG_ENTER_SCALE: f32 = 0.8;
+
+/// Give every dialog an [`OpenTransition`], starting closed so that dialogs spawned
+/// open animate in.
+fn dialog_transition_setup_system(
+    mut commands: Commands,
+    dialogs: Query<Entity, (With<MaterialDialog>, Without<OpenTransition>)>,
+) {
+    for entity in dialogs.iter() {
+        commands
+            .entity(entity)
+            .insert(OpenTransition::new(false))
+            .insert_if_new(FocusTrap::default());
+    }
+}
 
-/// System to handle dialog visibility
+/// Engage each modal dialog's [`FocusTrap`] while the dialog is open, so keyboard
+/// focus moves into it and returns to the previously focused element on close.
+fn dialog_focus_trap_system(mut dialogs: Query<(&MaterialDialog, &mut FocusTrap)>) {
+    for (dialog, mut trap) in dialogs.iter_mut() {
+        let active = dialog.open && dialog.modal;
+        if trap.active != active {
+            trap.active = active;
+        }
+    }
+}
+
+/// Run the dialog enter/exit transition: an emphasized scale and fade of the
+/// surface and its shadow. The dialog stays displayed until the exit finishes.
+///
+/// Dialogs spawned without a `UiTransform`, `BackgroundColor` or `BoxShadow` are
+/// still shown and hidden, just without that part of the animation.
 fn dialog_visibility_system(
-    mut dialogs: Query<(&MaterialDialog, &mut Node), Changed<MaterialDialog>>,
+    time: Res<Time>,
+    theme: Option<Res<MaterialTheme>>,
+    mut dialogs: Query<(
+        Entity,
+        &MaterialDialog,
+        &mut OpenTransition,
+        &mut Node,
+        Option<&mut UiTransform>,
+        Option<&mut BackgroundColor>,
+        Option<&mut BoxShadow>,
+    )>,
+    mut closed: MessageWriter<DialogClosedEvent>,
 ) {
-    for (dialog, mut node) in dialogs.iter_mut() {
-        node.display = if dialog.open {
+    for (entity, dialog, mut transition, mut node, transform, bg, shadow) in dialogs.iter_mut() {
+        let was_visible = transition.is_visible();
+        if transition.open != dialog.open {
+            transition.set_open(dialog.open);
+        }
+        let animating = transition.is_animating();
+        if animating {
+            transition.update(time.delta_secs());
+        }
+
+        let display = if transition.is_visible() {
             Display::Flex
         } else {
             Display::None
         };
+        if node.display != display {
+            node.display = display;
+        }
+        // Also when closed before the enter transition got anywhere
+        if was_visible && !transition.is_visible() {
+            closed.write(DialogClosedEvent { entity });
+        }
+        if !animating {
+            continue;
+        }
+
+        let value = transition.value();
+        if let Some(mut transform) = transform {
+            transform.scale = Vec2::splat(DIALOG_ENTER_SCALE + (1.0 - DIALOG_ENTER_SCALE) * value);
+        }
+        if let (Some(theme), Some(mut bg)) = (&theme, bg) {
+            let surface = dialog.surface_color(theme);
+            *bg = BackgroundColor(surface.with_alpha(surface.alpha() * value));
+        }
+        if let Some(mut shadow) = shadow {
+            *shadow = if transition.is_visible() {
+                dialog.elevation().to_box_shadow_with_alpha(value)
+            } else {
+                BoxShadow::default()
+            };
+        }
     }
 }
 
-/// System to update dialog shadows using native BoxShadow
-fn dialog_shadow_system(
-    mut dialogs: Query<(&MaterialDialog, &mut BoxShadow), Changed<MaterialDialog>>,
+/// Close the topmost open dialog on Escape, and a dialog whose scrim is clicked,
+/// as allowed by `dismiss_on_escape` / `dismiss_on_scrim_click`.
+///
+/// Escape is left to any [`OpenOverlay`] (an open select, menu, search view or
+/// date/time picker) inside the dialog. The marker lags a frame behind, so an
+/// overlay that Escape closes earlier in the same frame still counts.
+fn dialog_dismiss_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    scrims: Query<(&Interaction, &DialogScrimFor), (Changed<Interaction>, With<DialogScrim>)>,
+    open_overlays: Query<Entity, With<OpenOverlay>>,
+    parents: Query<&ChildOf>,
+    mut dialogs: Query<(Entity, &mut MaterialDialog, &ComputedNode)>,
+    mut close_events: MessageWriter<DialogCloseEvent>,
 ) {
-    for (dialog, mut shadow) in dialogs.iter_mut() {
-        // Only show shadow when dialog is open
-        if dialog.open {
-            *shadow = dialog.elevation().to_box_shadow();
-        } else {
-            *shadow = BoxShadow::default();
+    if keys.just_pressed(KeyCode::Escape) {
+        let has_nested_overlay = |dialog: Entity| {
+            open_overlays
+                .iter()
+                .any(|overlay| parents.iter_ancestors(overlay).any(|a| a == dialog))
+        };
+        let topmost = dialogs
+            .iter()
+            .filter(|(_, dialog, _)| dialog.open)
+            .max_by_key(|(_, _, node)| node.stack_index())
+            .map(|(entity, _, _)| entity)
+            .filter(|entity| !has_nested_overlay(*entity));
+        if let Some(Ok((entity, mut dialog, _))) = topmost.map(|entity| dialogs.get_mut(entity)) {
+            if dialog.dismiss_on_escape {
+                dialog.open = false;
+                close_events.write(DialogCloseEvent {
+                    entity,
+                    dismissed: true,
+                });
+            }
+        }
+    }
+
+    for (interaction, for_dialog) in scrims.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok((entity, mut dialog, _)) = dialogs.get_mut(for_dialog.0) {
+            if dialog.open && dialog.dismiss_on_scrim_click {
+                dialog.open = false;
+                close_events.write(DialogCloseEvent {
+                    entity,
+                    dismissed: true,
+                });
+            }
         }
     }
 }
 
-/// Keep dialog scrims in sync with their dialog's open state.
+/// Keep dialog scrims in sync with their dialog, fading in and out with it.
 fn dialog_scrim_visibility_system(
-    dialogs: Query<&MaterialDialog>,
-    mut scrims: Query<(&DialogScrimFor, &mut Node), With<DialogScrim>>,
+    theme: Option<Res<MaterialTheme>>,
+    dialogs: Query<(&MaterialDialog, Option<&OpenTransition>)>,
+    mut scrims: Query<(&DialogScrimFor, &mut Node, &mut BackgroundColor), With<DialogScrim>>,
 ) {
-    for (for_dialog, mut node) in scrims.iter_mut() {
-        let open = dialogs.get(for_dialog.0).map(|d| d.open).unwrap_or(false);
-        node.display = if open { Display::Flex } else { Display::None };
+    for (for_dialog, mut node, mut bg) in scrims.iter_mut() {
+        let Ok((dialog, transition)) = dialogs.get(for_dialog.0) else {
+            if node.display != Display::None {
+                node.display = Display::None;
+            }
+            continue;
+        };
+        let (visible, value) = match transition {
+            Some(transition) => (transition.is_visible(), transition.value()),
+            None => (dialog.open, 1.0),
+        };
+
+        let display = if visible {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+        if let (true, Some(theme)) = (visible, &theme) {
+            let color = dialog.scrim_color(theme);
+            bg.set_if_neq(BackgroundColor(color.with_alpha(color.alpha() * value)));
+        }
     }
 }
 
@@ -352,6 +514,8 @@ impl DialogBuilder {
             })),
             // Native Bevy 0.17 shadow support (starts hidden since dialog is closed)
             BoxShadow::default(),
+            // Keep clicks on the dialog from reaching its scrim
+            FocusPolicy::Block,
         )
     }
 }
@@ -429,6 +593,8 @@ pub fn create_dialog_scrim_for(
             ..default()
         },
         BackgroundColor(theme.scrim.with_alpha(0.32)),
+        // Scrim clicks dismiss the dialog
+        Interaction::None,
         if modal {
             Pickable {
                 should_block_lower: true,
@@ -533,3 +699,670 @@ impl SpawnDialogChild for ChildSpawnerCommands<'_> {
         self.spawn(create_dialog_scrim(theme));
     }
 }
+
+// ============================================================================
+// Quick dialogs
+// ============================================================================
+
+/// Handle to a dialog spawned with [`SpawnQuickDialog`], used to match its [`DialogResult`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DialogHandle {
+    /// The dialog entity
+    pub dialog: Entity,
+    /// The scrim the dialog is spawned in (the root entity, despawned after closing)
+    pub root: Entity,
+}
+
+/// How a quick dialog was answered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogResponse {
+    /// The confirm button was pressed (alert and confirm dialogs)
+    Confirmed,
+    /// The cancel button was pressed, or the dialog was dismissed with Escape or the scrim
+    Cancelled,
+    /// A prompt was submitted with this text
+    Text(String),
+}
+
+impl DialogResponse {
+    /// Whether the dialog was confirmed or a prompt submitted
+    pub fn is_confirmed(&self) -> bool {
+        !matches!(self, DialogResponse::Cancelled)
+    }
+
+    /// The submitted text of a prompt
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            DialogResponse::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// Message sent once when a quick dialog is answered
+#[derive(Event, Clone, Debug, bevy::prelude::Message)]
+pub struct DialogResult {
+    /// The dialog that was answered
+    pub handle: DialogHandle,
+    /// The answer
+    pub response: DialogResponse,
+}
+
+/// Kinds of quick dialogs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuickDialogKind {
+    /// A message with a single confirm button
+    #[default]
+    Alert,
+    /// A question with confirm and cancel buttons
+    Confirm,
+    /// A text input with confirm and cancel buttons
+    Prompt,
+}
+
+/// State of a dialog spawned with [`SpawnQuickDialog`]
+#[derive(Component, Debug, Clone)]
+pub struct QuickDialog {
+    /// Handle reported in [`DialogResult`]
+    pub handle: DialogHandle,
+    /// Kind of dialog
+    pub kind: QuickDialogKind,
+    /// Text field of a prompt
+    pub input: Option<Entity>,
+    /// Whether a result has been sent
+    pub answered: bool,
+}
+
+/// Marker for the confirm / cancel buttons of a quick dialog
+#[derive(Component, Debug, Clone, Copy)]
+pub struct QuickDialogAction {
+    /// The dialog entity
+    pub dialog: Entity,
+    /// Whether this is the confirm button
+    pub confirm: bool,
+}
+
+/// Builder for alert, confirm and prompt dialogs
+pub struct QuickDialogBuilder {
+    kind: QuickDialogKind,
+    title: String,
+    message: Option<String>,
+    confirm_label: String,
+    cancel_label: String,
+    input_label: Option<String>,
+    initial_text: String,
+    placeholder: Option<String>,
+    dismiss_on_scrim_click: bool,
+    dismiss_on_escape: bool,
+}
+
+impl QuickDialogBuilder {
+    /// Create a builder for the given kind of dialog
+    pub fn new(kind: QuickDialogKind, title: impl Into<String>) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            message: None,
+            confirm_label: "OK".to_string(),
+            cancel_label: "Cancel".to_string(),
+            input_label: None,
+            initial_text: String::new(),
+            placeholder: None,
+            dismiss_on_scrim_click: true,
+            dismiss_on_escape: true,
+        }
+    }
+
+    /// An alert: a message with an OK button
+    pub fn alert(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(QuickDialogKind::Alert, title).message(message)
+    }
+
+    /// A confirmation: a message with OK and Cancel buttons
+    pub fn confirm(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(QuickDialogKind::Confirm, title).message(message)
+    }
+
+    /// A prompt: a labelled text field with OK and Cancel buttons
+    pub fn prompt(title: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(QuickDialogKind::Prompt, title).input_label(label)
+    }
+
+    /// Set the supporting text
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set the confirm button label
+    pub fn confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    /// Set the cancel button label
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Set the prompt's text field label
+    pub fn input_label(mut self, label: impl Into<String>) -> Self {
+        self.input_label = Some(label.into());
+        self
+    }
+
+    /// Set the prompt's initial text
+    pub fn initial_text(mut self, text: impl Into<String>) -> Self {
+        self.initial_text = text.into();
+        self
+    }
+
+    /// Set the prompt's placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Disable scrim dismissal
+    pub fn no_scrim_dismiss(mut self) -> Self {
+        self.dismiss_on_scrim_click = false;
+        self
+    }
+
+    /// Disable escape dismissal
+    pub fn no_escape_dismiss(mut self) -> Self {
+        self.dismiss_on_escape = false;
+        self
+    }
+
+    /// Get the kind of dialog
+    pub fn kind(&self) -> QuickDialogKind {
+        self.kind
+    }
+
+    /// Whether the dialog has a cancel button
+    pub fn has_cancel(&self) -> bool {
+        self.kind != QuickDialogKind::Alert
+    }
+}
+
+/// Extension trait to spawn alert, confirm and prompt dialogs in one call
+///
+/// The dialog opens right away inside its own modal scrim. When it is answered a
+/// [`DialogResult`] is sent with the returned handle (Escape and scrim clicks count
+/// as cancelling), and once its exit transition ends the dialog is despawned.
+///
+/// ## Example:
+/// ```ignore
+/// let handle = commands.confirm(&theme, "Delete file?", "This cannot be undone.");
+/// // later
+/// for result in results.read() {
+///     if result.handle == handle && result.response.is_confirmed() { /* ... */ }
+/// }
+/// ```
+pub trait SpawnQuickDialog {
+    /// Spawn an alert with an OK button
+    fn alert(
+        &mut self,
+        theme: &MaterialTheme,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> DialogHandle;
+
+    /// Spawn a confirmation with OK and Cancel buttons
+    fn confirm(
+        &mut self,
+        theme: &MaterialTheme,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> DialogHandle;
+
+    /// Spawn a prompt with a text field
+    fn prompt(
+        &mut self,
+        theme: &MaterialTheme,
+        title: impl Into<String>,
+        label: impl Into<String>,
+    ) -> DialogHandle;
+
+    /// Spawn a quick dialog with full builder control
+    fn spawn_quick_dialog(
+        &mut self,
+        theme: &MaterialTheme,
+        builder: QuickDialogBuilder,
+    ) -> DialogHandle;
+}
+
+impl SpawnQuickDialog for Commands<'_, '_> {
+    fn alert(
+        &mut self,
+        theme: &MaterialTheme,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> DialogHandle {
+        self.spawn_quick_dialog(theme, QuickDialogBuilder::alert(title, message))
+    }
+
+    fn confirm(
+        &mut self,
+        theme: &MaterialTheme,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> DialogHandle {
+        self.spawn_quick_dialog(theme, QuickDialogBuilder::confirm(title, message))
+    }
+
+    fn prompt(
+        &mut self,
+        theme: &MaterialTheme,
+        title: impl Into<String>,
+        label: impl Into<String>,
+    ) -> DialogHandle {
+        self.spawn_quick_dialog(theme, QuickDialogBuilder::prompt(title, label))
+    }
+
+    fn spawn_quick_dialog(
+        &mut self,
+        theme: &MaterialTheme,
+        builder: QuickDialogBuilder,
+    ) -> DialogHandle {
+        let mut dialog_builder = DialogBuilder::new().title(builder.title.clone()).open();
+        if !builder.dismiss_on_scrim_click {
+            dialog_builder = dialog_builder.no_scrim_dismiss();
+        }
+        if !builder.dismiss_on_escape {
+            dialog_builder = dialog_builder.no_escape_dismiss();
+        }
+        let headline_color = dialog_builder.dialog.title_color(theme);
+        let content_color = dialog_builder.dialog.content_color(theme);
+
+        let dialog = self.spawn(dialog_builder.build(theme)).id();
+        let root = self
+            .spawn(create_dialog_scrim_for(theme, dialog, true))
+            .id();
+        self.entity(root).add_child(dialog);
+        let handle = DialogHandle { dialog, root };
+
+        let mut input = None;
+        self.entity(dialog).with_children(|parent| {
+            parent.spawn((
+                DialogHeadline,
+                Text::new(builder.title.as_str()),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(headline_color),
+                Node {
+                    margin: UiRect::bottom(Val::Px(Spacing::LARGE)),
+                    ..default()
+                },
+            ));
+
+            parent
+                .spawn((
+                    DialogContent,
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(Spacing::LARGE),
+                        margin: UiRect::bottom(Val::Px(Spacing::EXTRA_LARGE)),
+                        ..default()
+                    },
+                ))
+                .with_children(|content| {
+                    if let Some(message) = &builder.message {
+                        content.spawn((
+                            Text::new(message.as_str()),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(content_color),
+                        ));
+                    }
+
+                    if builder.kind == QuickDialogKind::Prompt {
+                        let mut field = TextFieldBuilder::new()
+                            .outlined()
+                            .value(builder.initial_text.clone())
+                            .auto_focus(true)
+                            .width(Val::Percent(100.0));
+                        if let Some(label) = &builder.input_label {
+                            field = field.label(label.clone());
+                        }
+                        if let Some(placeholder) = &builder.placeholder {
+                            field = field.placeholder(placeholder.clone());
+                        }
+                        input = Some(spawn_text_field_control(content, theme, field));
+                    }
+                });
+
+            parent
+                .spawn((
+                    DialogActions,
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::End,
+                        column_gap: Val::Px(Spacing::SMALL),
+                        ..default()
+                    },
+                ))
+                .with_children(|actions| {
+                    if builder.has_cancel() {
+                        spawn_quick_dialog_button(
+                            actions,
+                            theme,
+                            &builder.cancel_label,
+                            dialog,
+                            false,
+                        );
+                    }
+                    spawn_quick_dialog_button(actions, theme, &builder.confirm_label, dialog, true);
+                });
+        });
+
+        self.entity(dialog).insert(QuickDialog {
+            handle,
+            kind: builder.kind,
+            input,
+            answered: false,
+        });
+
+        handle
+    }
+}
+
+/// Spawn a text button for a quick dialog's actions row
+fn spawn_quick_dialog_button(
+    actions: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    label: &str,
+    dialog: Entity,
+    confirm: bool,
+) {
+    let text_color = MaterialButton::new(label)
+        .with_variant(ButtonVariant::Text)
+        .text_color(theme);
+
+    actions
+        .spawn((
+            QuickDialogAction { dialog, confirm },
+            MaterialButtonBuilder::new(label).text().build(theme),
+        ))
+        .with_children(|button| {
+            button.spawn((
+                ButtonLabel,
+                Text::new(label),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(text_color),
+            ));
+        });
+}
+
+/// Turn button clicks, prompt submissions and dismissals of quick dialogs into
+/// [`DialogResult`]s, closing the dialog.
+fn quick_dialog_result_system(
+    mut clicks: MessageReader<ButtonClickEvent>,
+    mut submits: MessageReader<TextFieldSubmitEvent>,
+    mut close_cursor: Local<MessageCursor<DialogCloseEvent>>,
+    mut close_events: ResMut<Messages<DialogCloseEvent>>,
+    actions: Query<&QuickDialogAction>,
+    fields: Query<&MaterialTextField>,
+    mut dialogs: Query<(Entity, &mut MaterialDialog, &mut QuickDialog)>,
+    mut results: MessageWriter<DialogResult>,
+) {
+    // (dialog, confirmed) in arrival order; the first answer wins.
+    let mut answers: Vec<(Entity, bool)> = Vec::new();
+    for click in clicks.read() {
+        if let Ok(action) = actions.get(click.entity) {
+            answers.push((action.dialog, action.confirm));
+        }
+    }
+    for submit in submits.read() {
+        if let Some((entity, _, _)) = dialogs
+            .iter()
+            .find(|(_, _, quick)| quick.input == Some(submit.entity))
+        {
+            answers.push((entity, true));
+        }
+    }
+    // Any other way of closing (Escape, scrim, gamepad, app code) cancels.
+    for close in close_cursor.read(&close_events) {
+        if dialogs.contains(close.entity) {
+            answers.push((close.entity, false));
+        }
+    }
+
+    for (entity, confirmed) in answers {
+        let Ok((_, mut dialog, mut quick)) = dialogs.get_mut(entity) else {
+            continue;
+        };
+        if quick.answered {
+            continue;
+        }
+        quick.answered = true;
+
+        let response = if !confirmed {
+            DialogResponse::Cancelled
+        } else if quick.kind == QuickDialogKind::Prompt {
+            let text = quick
+                .input
+                .and_then(|input| fields.get(input).ok())
+                .map(|field| field.value.clone())
+                .unwrap_or_default();
+            DialogResponse::Text(text)
+        } else {
+            DialogResponse::Confirmed
+        };
+
+        if dialog.open {
+            dialog.open = false;
+            close_events.write(DialogCloseEvent {
+                entity,
+                dismissed: false,
+            });
+        }
+        results.write(DialogResult {
+            handle: quick.handle,
+            response,
+        });
+    }
+}
+
+/// Despawn quick dialogs (with their scrim) once their exit transition has ended.
+fn quick_dialog_cleanup_system(
+    mut commands: Commands,
+    mut closed: MessageReader<DialogClosedEvent>,
+    dialogs: Query<&QuickDialog>,
+) {
+    for event in closed.read() {
+        if let Ok(quick) = dialogs.get(event.entity) {
+            commands.entity(quick.handle.root).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::focus::{FocusAppExt, FocusPlugin};
+    use crate::select::{MaterialSelect, SelectOption};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+    use bevy::input::{ButtonState, InputPlugin};
+    use bevy::time::TimeUpdateStrategy;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, FocusPlugin, DialogPlugin))
+            .init_resource::<MaterialTheme>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(50),
+            ));
+        app
+    }
+
+    fn closed_dialogs(app: &App) -> Vec<Entity> {
+        let messages = app.world().resource::<Messages<DialogClosedEvent>>();
+        messages
+            .get_cursor()
+            .read(messages)
+            .map(|event| event.entity)
+            .collect()
+    }
+
+    fn spawn_confirm(app: &mut App) -> DialogHandle {
+        app.world_mut()
+            .run_system_once(|mut commands: Commands, theme: Res<MaterialTheme>| {
+                commands.confirm(&theme, "Delete file?", "This cannot be undone.")
+            })
+            .unwrap()
+    }
+
+    fn press_escape(app: &mut App) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            app.world_mut().write_message(KeyboardInput {
+                key_code: KeyCode::Escape,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+        }
+    }
+
+    fn results(app: &App) -> Vec<(DialogHandle, DialogResponse)> {
+        let messages = app.world().resource::<Messages<DialogResult>>();
+        messages
+            .get_cursor()
+            .read(messages)
+            .map(|result| (result.handle, result.response.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_quick_dialog_confirm_then_cleanup() {
+        let mut app = test_app();
+        let handle = spawn_confirm(&mut app);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(
+            app.world()
+                .get::<MaterialDialog>(handle.dialog)
+                .unwrap()
+                .open
+        );
+
+        let world = app.world_mut();
+        let confirm = world
+            .query::<(Entity, &QuickDialogAction)>()
+            .iter(world)
+            .find(|(_, action)| action.dialog == handle.dialog && action.confirm)
+            .map(|(entity, _)| entity)
+            .unwrap();
+        app.world_mut()
+            .write_message(ButtonClickEvent { entity: confirm });
+        app.update();
+
+        assert_eq!(results(&app), vec![(handle, DialogResponse::Confirmed)]);
+        assert!(
+            !app.world()
+                .get::<MaterialDialog>(handle.dialog)
+                .unwrap()
+                .open
+        );
+
+        // Despawned with its scrim once the exit transition ends.
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(app.world().get_entity(handle.root).is_err());
+        assert!(app.world().get_entity(handle.dialog).is_err());
+    }
+
+    #[test]
+    fn test_quick_dialog_dismissed_while_opening_is_cleaned_up() {
+        let mut app = test_app();
+        let handle = spawn_confirm(&mut app);
+        app.update();
+
+        press_escape(&mut app);
+        assert_eq!(results(&app), vec![(handle, DialogResponse::Cancelled)]);
+        app.update();
+        assert!(app.world().get_entity(handle.root).is_err());
+    }
+
+    #[test]
+    fn test_escape_goes_to_open_select_inside_dialog() {
+        let mut app = test_app();
+        app.register_overlay::<MaterialSelect>();
+        let handle = spawn_confirm(&mut app);
+        for _ in 0..10 {
+            app.update();
+        }
+
+        let mut select = MaterialSelect::new(vec![SelectOption::new("One")]);
+        select.open = true;
+        let select = app.world_mut().spawn((select, ChildOf(handle.dialog))).id();
+        app.update();
+
+        press_escape(&mut app);
+        assert!(
+            app.world()
+                .get::<MaterialDialog>(handle.dialog)
+                .unwrap()
+                .open
+        );
+
+        // Once the select has closed, Escape dismisses the dialog as a cancel.
+        app.world_mut()
+            .get_mut::<MaterialSelect>(select)
+            .unwrap()
+            .open = false;
+        app.update();
+        press_escape(&mut app);
+        assert!(
+            !app.world()
+                .get::<MaterialDialog>(handle.dialog)
+                .unwrap()
+                .open
+        );
+        assert_eq!(results(&app), vec![(handle, DialogResponse::Cancelled)]);
+    }
+
+    #[test]
+    fn test_dialog_stays_displayed_until_exit_ends() {
+        let mut app = test_app();
+        // Spawned by hand, without a background or shadow to animate.
+        let dialog = app
+            .world_mut()
+            .spawn((MaterialDialog::new().open(true), Node::default()))
+            .id();
+        for _ in 0..10 {
+            app.update();
+        }
+        let display = |app: &App| app.world().get::<Node>(dialog).unwrap().display;
+        assert_eq!(display(&app), Display::Flex);
+
+        app.world_mut()
+            .get_mut::<MaterialDialog>(dialog)
+            .unwrap()
+            .open = false;
+        // The exit takes `Duration::SHORT4` (four 50ms frames).
+        for _ in 0..3 {
+            app.update();
+            assert_eq!(display(&app), Display::Flex);
+            assert!(closed_dialogs(&app).is_empty());
+        }
+        app.update();
+        assert_eq!(display(&app), Display::None);
+        assert_eq!(closed_dialogs(&app), vec![dialog]);
+    }
+}
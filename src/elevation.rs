@@ -115,6 +115,17 @@ impl Elevation {
         )
     }
 
+    /// Convert this elevation to a `BoxShadow` whose opacity is scaled by `alpha`
+    ///
+    /// Used to fade shadows in and out with enter/exit transitions.
+    pub fn to_box_shadow_with_alpha(&self, alpha: f32) -> BoxShadow {
+        let mut shadow = self.to_box_shadow();
+        for style in shadow.iter_mut() {
+            style.color = style.color.with_alpha(style.color.alpha() * alpha);
+        }
+        shadow
+    }
+
     /// Create a `ShadowStyle` for this elevation level
     ///
     /// Useful when you need more control over the shadow styling.
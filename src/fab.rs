@@ -12,6 +12,7 @@ use bevy::ui::BoxShadow;
 
 use crate::{
     elevation::Elevation,
+    focus::{FocusAppExt, FocusManager, FocusableComponent},
     icons::IconStyle,
     ripple::RippleHost,
     theme::{blend_state_layer, MaterialTheme},
@@ -23,16 +24,19 @@ pub struct FabPlugin;
 
 impl Plugin for FabPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<FabClickEvent>().add_systems(
-            Update,
-            (
-                fab_interaction_system,
-                fab_style_system,
-                fab_content_style_system,
-                fab_theme_refresh_system,
-                fab_shadow_system,
-            ),
-        );
+        app.add_message::<FabClickEvent>()
+            .register_focusable::<MaterialFab>()
+            .add_systems(
+                Update,
+                (
+                    fab_interaction_system,
+                    fab_keyboard_system,
+                    fab_style_system,
+                    fab_content_style_system,
+                    fab_theme_refresh_system,
+                    fab_shadow_system,
+                ),
+            );
     }
 }
 
@@ -212,6 +216,12 @@ impl MaterialFab {
     }
 }
 
+impl FocusableComponent for MaterialFab {
+    fn is_focus_disabled(&self) -> bool {
+        false
+    }
+}
+
 /// Event when FAB is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct FabClickEvent {
@@ -245,6 +255,21 @@ fn fab_interaction_system(
     }
 }
 
+/// System to activate the focused FAB with Space / Enter
+fn fab_keyboard_system(
+    focus: Res<FocusManager>,
+    fabs: Query<(), With<MaterialFab>>,
+    mut click_events: MessageWriter<FabClickEvent>,
+) {
+    if !focus.activation_requested() {
+        return;
+    }
+
+    if let Some(entity) = focus.focused().filter(|entity| fabs.contains(*entity)) {
+        click_events.write(FabClickEvent { entity });
+    }
+}
+
 /// System to update FAB styles
 fn fab_style_system(
     theme: Option<Res<MaterialTheme>>,
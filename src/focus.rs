@@ -5,6 +5,34 @@
 //!
 //! This module now leverages Bevy 0.17's native `Outline` component for rendering
 //! focus rings, providing better performance and simpler implementation.
+//!
+//! ## Focus traversal
+//!
+//! [`FocusManager`] tracks the focused entity and a tab order built from every
+//! enabled, visible [`Focusable`]. Tab / Shift+Tab move focus through that order,
+//! pressing a focusable entity with the pointer focuses it without showing the
+//! ring, and [`FocusGained`] / [`FocusLost`] are written whenever focus moves.
+//!
+//! Interactive components opt in through [`FocusAppExt::register_focusable`], which
+//! attaches a [`Focusable`] to every entity carrying the component and mirrors its
+//! disabled state.
+//!
+//! Space / Enter raise an activation request on the manager for one frame; other
+//! input layers (such as gamepads) can raise the same request with
+//! [`FocusManager::request_activation`], and components treat it like a click on
+//! their focused entity.
+//!
+//! ## Focus traps
+//!
+//! Modal overlays carry a [`FocusTrap`]. While a trap is engaged, Tab order and
+//! focus requests are confined to its descendants, focus moves to its initial
+//! element (see [`InitialFocus`]), and when it disengages focus returns to the
+//! entity that held it before. Engaged traps form a stack, so a dialog opened from
+//! another dialog hands focus back to the first one when it closes.
+//!
+//! Popups that close on Escape (dropdowns, menus, search views, pickers) register
+//! through [`FocusAppExt::register_overlay`]. Their entity carries [`OpenOverlay`]
+//! while open, so containers such as dialogs can leave Escape to them.
 
 use bevy::prelude::*;
 use bevy::ui::Outline;
@@ -14,10 +42,27 @@ pub struct FocusPlugin;
 
 impl Plugin for FocusPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (update_focus_outline_system, update_focus_ring_system),
-        );
+        app.add_message::<FocusGained>()
+            .add_message::<FocusLost>()
+            .init_resource::<FocusManager>()
+            .add_systems(
+                PreUpdate,
+                focus_activation_key_system.after(bevy::input::InputSystems),
+            )
+            .add_systems(Last, reset_focus_activation_system)
+            .add_systems(
+                Update,
+                (
+                    focus_trap_system,
+                    focus_tab_order_system,
+                    focus_keyboard_navigation_system,
+                    focus_pointer_system,
+                    apply_focus_system,
+                    update_focus_outline_system,
+                    update_focus_ring_system,
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -31,6 +76,14 @@ pub struct Focusable {
     pub focused: bool,
     /// Whether focus came from keyboard navigation
     pub focus_visible: bool,
+    /// Explicit position in the tab order.
+    ///
+    /// Follows HTML `tabindex` semantics: positive values come first in ascending
+    /// order, `None`/`0` follow in document order, and negative values are skipped
+    /// by Tab traversal but can still be focused programmatically.
+    pub tab_index: Option<i32>,
+    /// Whether the element currently refuses focus
+    pub disabled: bool,
     /// Custom focus ring color
     pub ring_color: Option<Color>,
     /// Focus ring offset from the element
@@ -47,6 +100,8 @@ impl Focusable {
         Self {
             focused: false,
             focus_visible: false,
+            tab_index: None,
+            disabled: false,
             ring_color: None,
             ring_offset: 2.0,
             ring_width: 3.0,
@@ -80,6 +135,17 @@ impl Focusable {
         self
     }
 
+    /// Set an explicit tab index
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Whether this element takes part in Tab / Shift+Tab traversal
+    pub fn is_tabbable(&self) -> bool {
+        !self.disabled && self.tab_index.is_none_or(|index| index >= 0)
+    }
+
     /// Convert to a Bevy `Outline` component
     ///
     /// This leverages Bevy 0.17's native outline rendering.
@@ -117,6 +183,671 @@ pub struct FocusLost {
     pub entity: Entity,
 }
 
+/// Confines keyboard focus to an entity and its descendants while engaged.
+///
+/// Dialogs, the date/time picker and modal drawers insert one automatically.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusTrap {
+    /// Whether the trap is engaged (ignored when `while_visible` is set)
+    pub active: bool,
+    /// Engage the trap whenever the entity is visible instead of following `active`
+    pub while_visible: bool,
+    /// Element to focus when the trap engages; defaults to the first
+    /// [`InitialFocus`] descendant, then the first tabbable descendant
+    pub initial_focus: Option<Entity>,
+    /// Return focus to the previously focused entity when the trap disengages
+    pub restore_focus: bool,
+}
+
+impl Default for FocusTrap {
+    fn default() -> Self {
+        Self {
+            active: false,
+            while_visible: false,
+            initial_focus: None,
+            restore_focus: true,
+        }
+    }
+}
+
+impl FocusTrap {
+    /// Create a trap that is engaged while `active` is set
+    pub fn new(active: bool) -> Self {
+        Self {
+            active,
+            ..default()
+        }
+    }
+
+    /// Create a trap that is engaged while its entity is visible
+    pub fn while_visible() -> Self {
+        Self {
+            while_visible: true,
+            ..default()
+        }
+    }
+
+    /// Focus a specific element when the trap engages
+    pub fn with_initial_focus(mut self, entity: Entity) -> Self {
+        self.initial_focus = Some(entity);
+        self
+    }
+
+    /// Leave focus where it is when the trap disengages
+    pub fn without_restore(mut self) -> Self {
+        self.restore_focus = false;
+        self
+    }
+
+    /// Whether the trap is engaged, given its entity's visibility
+    pub fn is_engaged(&self, visibility: Option<&InheritedVisibility>) -> bool {
+        if self.while_visible {
+            visibility.is_none_or(|visibility| visibility.get())
+        } else {
+            self.active
+        }
+    }
+}
+
+/// Marks the element a [`FocusTrap`] focuses when it engages.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct InitialFocus;
+
+/// An engaged focus trap and the focus to return to when it disengages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TrapEntry {
+    root: Entity,
+    restore: Option<Entity>,
+    restore_visible: bool,
+    /// Still looking for an element to focus (its focusables may not exist yet)
+    awaiting_initial: bool,
+}
+
+/// A pending focus change, applied by the focus systems on the next update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FocusRequest {
+    /// Move focus to an entity
+    Focus { entity: Entity, from_keyboard: bool },
+    /// Clear focus
+    Blur,
+}
+
+/// Resource that owns keyboard focus for Material UI.
+///
+/// Exactly one [`Focusable`] is focused at a time. The tab order is rebuilt
+/// every frame from document order (UI stack order) and each element's
+/// `tab_index`, skipping disabled and hidden entities.
+#[derive(Resource, Default, Debug)]
+pub struct FocusManager {
+    focused: Option<Entity>,
+    focus_visible: bool,
+    order: Vec<Entity>,
+    pending: Option<FocusRequest>,
+    activation_requested: bool,
+    traps: Vec<TrapEntry>,
+}
+
+impl FocusManager {
+    /// The currently focused entity, if any
+    pub fn focused(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    /// Whether the current focus came from keyboard navigation
+    pub fn is_focus_visible(&self) -> bool {
+        self.focused.is_some() && self.focus_visible
+    }
+
+    /// Entities in Tab order (as of the last update)
+    pub fn tab_order(&self) -> &[Entity] {
+        &self.order
+    }
+
+    /// Focus an entity programmatically (the focus ring is not shown)
+    pub fn focus(&mut self, entity: Entity) {
+        self.pending = Some(FocusRequest::Focus {
+            entity,
+            from_keyboard: false,
+        });
+    }
+
+    /// Focus an entity as if it was reached by keyboard (the focus ring is shown)
+    pub fn focus_visible(&mut self, entity: Entity) {
+        self.pending = Some(FocusRequest::Focus {
+            entity,
+            from_keyboard: true,
+        });
+    }
+
+    /// Clear focus
+    pub fn blur(&mut self) {
+        self.pending = Some(FocusRequest::Blur);
+    }
+
+    /// Move focus to the next entity in tab order (wrapping)
+    pub fn focus_next(&mut self) {
+        if let Some(entity) = next_in_tab_order(&self.order, self.current_target(), false) {
+            self.focus_visible(entity);
+        }
+    }
+
+    /// Move focus to the previous entity in tab order (wrapping)
+    pub fn focus_previous(&mut self) {
+        if let Some(entity) = next_in_tab_order(&self.order, self.current_target(), true) {
+            self.focus_visible(entity);
+        }
+    }
+
+    /// Ask the focused element to activate this frame, as if Space / Enter was pressed
+    pub fn request_activation(&mut self) {
+        self.activation_requested = true;
+    }
+
+    /// Whether the focused element should activate this frame
+    pub fn activation_requested(&self) -> bool {
+        self.focused.is_some() && self.activation_requested
+    }
+
+    /// Root of the innermost engaged [`FocusTrap`], if any
+    pub fn active_trap(&self) -> Option<Entity> {
+        self.traps.last().map(|trap| trap.root)
+    }
+
+    /// Whether `entity` may hold focus, i.e. it lies inside the active trap
+    pub fn allows_focus(&self, entity: Entity, parents: &Query<&ChildOf>) -> bool {
+        self.active_trap().is_none_or(|root| {
+            entity == root
+                || parents
+                    .iter_ancestors(entity)
+                    .any(|ancestor| ancestor == root)
+        })
+    }
+
+    /// The entity focus is heading to, taking unapplied requests into account.
+    fn current_target(&self) -> Option<Entity> {
+        match self.pending {
+            Some(FocusRequest::Focus { entity, .. }) => Some(entity),
+            Some(FocusRequest::Blur) => None,
+            None => self.focused,
+        }
+    }
+}
+
+/// Implemented by interactive components that take part in focus traversal.
+pub trait FocusableComponent: Component {
+    /// Whether the component currently refuses focus (e.g. it is disabled)
+    fn is_focus_disabled(&self) -> bool;
+}
+
+/// Implemented by components with a popup that closes on Escape.
+pub trait OverlayComponent: Component {
+    /// Whether the popup is currently open
+    fn is_overlay_open(&self) -> bool;
+}
+
+/// Present on an [`OverlayComponent`] entity while its popup is open.
+///
+/// Updated in `PostUpdate`, so during `Update` it still marks popups that were
+/// open at the end of the previous frame, including ones Escape just closed.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct OpenOverlay;
+
+/// Extension trait for registering focusable components on an [`App`].
+pub trait FocusAppExt {
+    /// Attach a [`Focusable`] (and focus `Outline`) to every entity with `T`,
+    /// keeping [`Focusable::disabled`] in sync with the component.
+    fn register_focusable<T: FocusableComponent>(&mut self) -> &mut Self;
+
+    /// Keep [`OpenOverlay`] on every entity whose `T` is open.
+    fn register_overlay<T: OverlayComponent>(&mut self) -> &mut Self;
+}
+
+impl FocusAppExt for App {
+    fn register_focusable<T: FocusableComponent>(&mut self) -> &mut Self {
+        self.add_systems(
+            Update,
+            (
+                ensure_focusable_system::<T>,
+                sync_focusable_disabled_system::<T>,
+            ),
+        )
+    }
+
+    fn register_overlay<T: OverlayComponent>(&mut self) -> &mut Self {
+        self.add_systems(PostUpdate, sync_open_overlay_system::<T>)
+    }
+}
+
+/// Order focusable entities for Tab traversal.
+///
+/// `candidates` must already be in document order. Entities with a positive
+/// tab index come first (ascending, stable), followed by entities without an
+/// explicit index (or index `0`) in document order. Negative indices are dropped.
+pub fn compute_tab_order(candidates: &[(Entity, Option<i32>)]) -> Vec<Entity> {
+    let mut explicit: Vec<(i32, Entity)> = candidates
+        .iter()
+        .filter_map(|(entity, index)| match index {
+            Some(index) if *index > 0 => Some((*index, *entity)),
+            _ => None,
+        })
+        .collect();
+    explicit.sort_by_key(|(index, _)| *index);
+
+    explicit
+        .into_iter()
+        .map(|(_, entity)| entity)
+        .chain(
+            candidates
+                .iter()
+                .filter(|(_, index)| index.is_none_or(|index| index == 0))
+                .map(|(entity, _)| *entity),
+        )
+        .collect()
+}
+
+/// Find the entity after (or before, when `reverse`) `current` in `order`, wrapping.
+///
+/// If `current` is not part of the order, traversal starts from the first
+/// (or last, when `reverse`) entity.
+pub fn next_in_tab_order(
+    order: &[Entity],
+    current: Option<Entity>,
+    reverse: bool,
+) -> Option<Entity> {
+    if order.is_empty() {
+        return None;
+    }
+
+    let position = current.and_then(|current| order.iter().position(|e| *e == current));
+    let index = match (position, reverse) {
+        (Some(i), false) => (i + 1) % order.len(),
+        (Some(i), true) => (i + order.len() - 1) % order.len(),
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+    };
+
+    Some(order[index])
+}
+
+/// Whether a keyboard activation key (Space / Enter) was pressed this frame.
+///
+/// The focus plugin turns these presses into [`FocusManager::activation_requested`],
+/// which components should prefer so other input devices can activate them too.
+pub fn activation_key_just_pressed(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_just_pressed([KeyCode::Space, KeyCode::Enter, KeyCode::NumpadEnter])
+}
+
+/// Raise an activation request when Space / Enter is pressed.
+fn focus_activation_key_system(keys: Res<ButtonInput<KeyCode>>, mut manager: ResMut<FocusManager>) {
+    if activation_key_just_pressed(&keys) {
+        manager.request_activation();
+    }
+}
+
+/// Activation requests only live for the frame they were raised in.
+fn reset_focus_activation_system(mut manager: ResMut<FocusManager>) {
+    if manager.activation_requested {
+        manager.bypass_change_detection().activation_requested = false;
+    }
+}
+
+/// Find the next enabled item after (or before, when `!forward`) `current`, wrapping.
+///
+/// Used for arrow-key movement inside composite widgets such as radio groups
+/// and tab rows. Returns `None` when no other item is enabled.
+pub fn next_enabled_index(enabled: &[bool], current: usize, forward: bool) -> Option<usize> {
+    let len = enabled.len();
+    (1..len)
+        .map(|offset| {
+            if forward {
+                (current + offset) % len
+            } else {
+                (current + len - offset) % len
+            }
+        })
+        .find(|index| enabled[*index])
+}
+
+/// Attach focus support to newly spawned focusable components.
+fn ensure_focusable_system<T: FocusableComponent>(
+    mut commands: Commands,
+    components: Query<(Entity, &T), Without<Focusable>>,
+) {
+    for (entity, component) in components.iter() {
+        let focusable = Focusable {
+            disabled: component.is_focus_disabled(),
+            ..Focusable::new()
+        };
+        let outline =
+            create_native_focus_outline(Color::NONE, focusable.ring_offset, focusable.ring_width);
+        commands.entity(entity).try_insert((focusable, outline));
+    }
+}
+
+/// Mirror the component's disabled state onto its `Focusable`.
+fn sync_focusable_disabled_system<T: FocusableComponent>(
+    mut components: Query<(&T, &mut Focusable), Changed<T>>,
+) {
+    for (component, mut focusable) in components.iter_mut() {
+        let disabled = component.is_focus_disabled();
+        if focusable.disabled != disabled {
+            focusable.disabled = disabled;
+        }
+    }
+}
+
+/// Add or remove [`OpenOverlay`] as the component opens and closes.
+fn sync_open_overlay_system<T: OverlayComponent>(
+    mut commands: Commands,
+    components: Query<(Entity, &T, Has<OpenOverlay>), Changed<T>>,
+) {
+    for (entity, component, marked) in components.iter() {
+        let open = component.is_overlay_open();
+        if open && !marked {
+            commands.entity(entity).try_insert(OpenOverlay);
+        } else if !open && marked {
+            commands.entity(entity).try_remove::<OpenOverlay>();
+        }
+    }
+}
+
+/// Whether a focusable entity is currently hidden or not laid out.
+pub(crate) fn is_focus_hidden(
+    visibility: Option<&InheritedVisibility>,
+    node: &ComputedNode,
+) -> bool {
+    visibility.is_some_and(|visibility| !visibility.get()) || node.is_empty()
+}
+
+/// First element a trap should focus: its explicit target, an [`InitialFocus`]
+/// descendant, or the first tabbable descendant in depth-first order.
+fn initial_focus_target(
+    root: Entity,
+    trap: &FocusTrap,
+    focusables: &Query<(&Focusable, Option<&InheritedVisibility>)>,
+    markers: &Query<(), With<InitialFocus>>,
+    children: &Query<&Children>,
+) -> Option<Entity> {
+    let available = |entity: Entity| {
+        focusables.get(entity).is_ok_and(|(focusable, visibility)| {
+            !focusable.disabled && visibility.is_none_or(|visibility| visibility.get())
+        })
+    };
+
+    if let Some(entity) = trap.initial_focus.filter(|entity| available(*entity)) {
+        return Some(entity);
+    }
+
+    let mut first = None;
+    for entity in children.iter_descendants_depth_first(root) {
+        if !available(entity) {
+            continue;
+        }
+        if markers.contains(entity) {
+            return Some(entity);
+        }
+        if first.is_none() && focusables.get(entity).is_ok_and(|(f, _)| f.is_tabbable()) {
+            first = Some(entity);
+        }
+    }
+    first
+}
+
+/// Keep the trap stack in sync with engaged [`FocusTrap`]s, moving focus into
+/// traps as they engage and restoring it as they disengage.
+fn focus_trap_system(
+    mut manager: ResMut<FocusManager>,
+    traps: Query<(Entity, &FocusTrap, Option<&InheritedVisibility>)>,
+    focusables: Query<(&Focusable, Option<&InheritedVisibility>)>,
+    markers: Query<(), With<InitialFocus>>,
+    children: Query<&Children>,
+    parents: Query<&ChildOf>,
+) {
+    let engaged = |entity: Entity| {
+        traps
+            .get(entity)
+            .is_ok_and(|(_, trap, visibility)| trap.is_engaged(visibility))
+    };
+
+    // Disengaged or despawned traps leave the stack, innermost first.
+    for index in (0..manager.traps.len()).rev() {
+        let entry = manager.traps[index];
+        if engaged(entry.root) {
+            continue;
+        }
+        manager.traps.remove(index);
+
+        if index < manager.traps.len() {
+            // A trap above this one would return focus into it; hand it this
+            // trap's restore target instead.
+            let above = &mut manager.traps[index];
+            if above.restore.is_some_and(|restore| {
+                restore == entry.root
+                    || parents.iter_ancestors(restore).any(|a| a == entry.root)
+                    || focusables.get(restore).is_err()
+            }) {
+                above.restore = entry.restore;
+                above.restore_visible = entry.restore_visible;
+            }
+            continue;
+        }
+
+        let restore_focus = traps
+            .get(entry.root)
+            .map_or(true, |(_, trap, _)| trap.restore_focus);
+        if !restore_focus {
+            continue;
+        }
+        match entry
+            .restore
+            .filter(|restore| focusables.contains(*restore))
+        {
+            Some(restore) if entry.restore_visible => manager.focus_visible(restore),
+            Some(restore) => manager.focus(restore),
+            None => manager.blur(),
+        }
+    }
+
+    // Newly engaged traps join the stack and take focus.
+    for (entity, trap, visibility) in traps.iter() {
+        if !trap.is_engaged(visibility) || manager.traps.iter().any(|t| t.root == entity) {
+            continue;
+        }
+        let entry = TrapEntry {
+            root: entity,
+            restore: manager.current_target(),
+            restore_visible: manager.is_focus_visible(),
+            awaiting_initial: true,
+        };
+        manager.traps.push(entry);
+        manager.blur();
+    }
+
+    // The innermost trap focuses its initial element once one is available,
+    // unless focus already moved inside it.
+    let Some(top) = manager.traps.last().copied() else {
+        return;
+    };
+    if !top.awaiting_initial {
+        return;
+    }
+    if manager
+        .current_target()
+        .is_some_and(|target| manager.allows_focus(target, &parents))
+    {
+        manager.traps.last_mut().unwrap().awaiting_initial = false;
+        return;
+    }
+    let Ok((_, trap, _)) = traps.get(top.root) else {
+        return;
+    };
+    if let Some(target) = initial_focus_target(top.root, trap, &focusables, &markers, &children) {
+        if top.restore_visible {
+            manager.focus_visible(target);
+        } else {
+            manager.focus(target);
+        }
+        manager.traps.last_mut().unwrap().awaiting_initial = false;
+    }
+}
+
+/// Rebuild the tab order from document order and tab indices.
+fn focus_tab_order_system(
+    mut manager: ResMut<FocusManager>,
+    focusables: Query<(
+        Entity,
+        &Focusable,
+        &ComputedNode,
+        Option<&InheritedVisibility>,
+    )>,
+    parents: Query<&ChildOf>,
+) {
+    let mut candidates: Vec<(u32, Entity, Option<i32>)> = focusables
+        .iter()
+        .filter(|(entity, focusable, node, visibility)| {
+            focusable.is_tabbable()
+                && !is_focus_hidden(*visibility, node)
+                && manager.allows_focus(*entity, &parents)
+        })
+        .map(|(entity, focusable, node, _)| (node.stack_index(), entity, focusable.tab_index))
+        .collect();
+    candidates.sort_by_key(|(stack_index, _, _)| *stack_index);
+
+    let candidates: Vec<(Entity, Option<i32>)> = candidates
+        .into_iter()
+        .map(|(_, entity, tab_index)| (entity, tab_index))
+        .collect();
+    let order = compute_tab_order(&candidates);
+
+    if manager.order != order {
+        manager.order = order;
+    }
+}
+
+/// Move focus with Tab / Shift+Tab.
+fn focus_keyboard_navigation_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut manager: ResMut<FocusManager>,
+) {
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        manager.focus_previous();
+    } else {
+        manager.focus_next();
+    }
+}
+
+/// Focus entities pressed with the pointer, and blur when clicking elsewhere.
+fn focus_pointer_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut manager: ResMut<FocusManager>,
+    pressed: Query<(Entity, &Interaction, &Focusable), Changed<Interaction>>,
+) {
+    let mut focused_this_frame = false;
+
+    for (entity, interaction, focusable) in pressed.iter() {
+        if *interaction != Interaction::Pressed || focusable.disabled {
+            continue;
+        }
+
+        manager.focus(entity);
+        focused_this_frame = true;
+    }
+
+    if mouse.just_pressed(MouseButton::Left) && !focused_this_frame && manager.focused.is_some() {
+        manager.blur();
+    }
+}
+
+/// Apply pending focus requests and drop focus from entities that can no longer hold it.
+fn apply_focus_system(
+    mut manager: ResMut<FocusManager>,
+    mut focusables: Query<(&mut Focusable, &ComputedNode, Option<&InheritedVisibility>)>,
+    parents: Query<&ChildOf>,
+    mut gained: MessageWriter<FocusGained>,
+    mut lost: MessageWriter<FocusLost>,
+) {
+    let request = manager.pending.take();
+
+    // The focused entity was despawned, disabled, hidden or left outside a trap.
+    if request.is_none() {
+        if let Some(current) = manager.focused {
+            let still_valid = focusables
+                .get(current)
+                .is_ok_and(|(focusable, node, visibility)| {
+                    !focusable.disabled && !is_focus_hidden(visibility, node)
+                })
+                && manager.allows_focus(current, &parents);
+            if !still_valid {
+                if let Ok((mut focusable, _, _)) = focusables.get_mut(current) {
+                    focusable.focused = false;
+                    focusable.focus_visible = false;
+                }
+                manager.focused = None;
+                manager.focus_visible = false;
+                lost.write(FocusLost { entity: current });
+            }
+        }
+        return;
+    }
+
+    let (target, from_keyboard) = match request {
+        Some(FocusRequest::Focus {
+            entity,
+            from_keyboard,
+        }) => {
+            let accepts = focusables
+                .get(entity)
+                .is_ok_and(|(focusable, _, _)| !focusable.disabled)
+                && manager.allows_focus(entity, &parents);
+            if !accepts {
+                return;
+            }
+            (Some(entity), from_keyboard)
+        }
+        _ => (None, false),
+    };
+
+    let previous = manager.focused;
+    if previous == target {
+        // Re-focusing the same entity only updates ring visibility.
+        if let Some(entity) = target {
+            if let Ok((mut focusable, _, _)) = focusables.get_mut(entity) {
+                if focusable.focus_visible != from_keyboard {
+                    focusable.focus_visible = from_keyboard;
+                }
+            }
+            manager.focus_visible = from_keyboard;
+        }
+        return;
+    }
+
+    if let Some(previous) = previous {
+        if let Ok((mut focusable, _, _)) = focusables.get_mut(previous) {
+            focusable.focused = false;
+            focusable.focus_visible = false;
+        }
+        lost.write(FocusLost { entity: previous });
+    }
+
+    manager.focused = target;
+    manager.focus_visible = from_keyboard;
+
+    if let Some(entity) = target {
+        if let Ok((mut focusable, _, _)) = focusables.get_mut(entity) {
+            focusable.focused = true;
+            focusable.focus_visible = from_keyboard;
+        }
+        gained.write(FocusGained {
+            entity,
+            from_keyboard,
+        });
+    }
+}
+
 /// System to update focus using Bevy's native Outline component
 ///
 /// This is the recommended approach for Bevy 0.17+ as it leverages
@@ -191,3 +922,166 @@ pub fn create_focus_ring(target: Entity, color: Color, offset: f32, width: f32)
 pub fn create_native_focus_outline(color: Color, offset: f32, width: f32) -> Outline {
     Outline::new(Val::Px(width), Val::Px(offset), color)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entities(count: u32) -> Vec<Entity> {
+        (0..count)
+            .map(Entity::from_raw_u32)
+            .map(Option::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn test_focusable_defaults() {
+        let focusable = Focusable::new();
+        assert!(!focusable.focused);
+        assert!(!focusable.focus_visible);
+        assert!(!focusable.disabled);
+        assert_eq!(focusable.tab_index, None);
+        assert!(focusable.is_tabbable());
+    }
+
+    #[test]
+    fn test_focusable_tabbable() {
+        assert!(Focusable::new().with_tab_index(0).is_tabbable());
+        assert!(Focusable::new().with_tab_index(3).is_tabbable());
+        assert!(!Focusable::new().with_tab_index(-1).is_tabbable());
+
+        let disabled = Focusable {
+            disabled: true,
+            ..Focusable::new()
+        };
+        assert!(!disabled.is_tabbable());
+    }
+
+    #[test]
+    fn test_tab_order_document_order() {
+        let e = entities(3);
+        let order = compute_tab_order(&[(e[0], None), (e[1], None), (e[2], Some(0))]);
+        assert_eq!(order, vec![e[0], e[1], e[2]]);
+    }
+
+    #[test]
+    fn test_tab_order_explicit_indices_first() {
+        let e = entities(5);
+        let order = compute_tab_order(&[
+            (e[0], None),
+            (e[1], Some(2)),
+            (e[2], Some(-1)),
+            (e[3], Some(1)),
+            (e[4], Some(2)),
+        ]);
+        assert_eq!(order, vec![e[3], e[1], e[4], e[0]]);
+    }
+
+    #[test]
+    fn test_next_in_tab_order_wraps() {
+        let e = entities(3);
+        assert_eq!(next_in_tab_order(&e, Some(e[0]), false), Some(e[1]));
+        assert_eq!(next_in_tab_order(&e, Some(e[2]), false), Some(e[0]));
+        assert_eq!(next_in_tab_order(&e, Some(e[0]), true), Some(e[2]));
+        assert_eq!(next_in_tab_order(&e, None, false), Some(e[0]));
+        assert_eq!(next_in_tab_order(&e, None, true), Some(e[2]));
+        assert_eq!(next_in_tab_order(&[], None, false), None);
+    }
+
+    #[test]
+    fn test_next_enabled_index_skips_disabled() {
+        let enabled = [true, false, true, true];
+        assert_eq!(next_enabled_index(&enabled, 0, true), Some(2));
+        assert_eq!(next_enabled_index(&enabled, 3, true), Some(0));
+        assert_eq!(next_enabled_index(&enabled, 2, false), Some(0));
+        assert_eq!(next_enabled_index(&enabled, 0, false), Some(3));
+        assert_eq!(next_enabled_index(&[true, false], 0, true), None);
+        assert_eq!(next_enabled_index(&[], 0, true), None);
+    }
+
+    #[test]
+    fn test_focus_manager_requests() {
+        let e = entities(2);
+        let mut manager = FocusManager {
+            order: e.clone(),
+            ..default()
+        };
+
+        manager.focus_next();
+        assert_eq!(
+            manager.pending,
+            Some(FocusRequest::Focus {
+                entity: e[0],
+                from_keyboard: true
+            })
+        );
+
+        // Traversal continues from the pending target.
+        manager.focus_next();
+        assert_eq!(manager.current_target(), Some(e[1]));
+
+        manager.focus(e[0]);
+        assert_eq!(
+            manager.pending,
+            Some(FocusRequest::Focus {
+                entity: e[0],
+                from_keyboard: false
+            })
+        );
+
+        manager.blur();
+        assert_eq!(manager.current_target(), None);
+    }
+
+    #[test]
+    fn test_activation_requires_focus() {
+        let e = entities(1);
+        let mut manager = FocusManager::default();
+
+        manager.request_activation();
+        assert!(!manager.activation_requested());
+
+        manager.focused = Some(e[0]);
+        assert!(manager.activation_requested());
+    }
+
+    #[test]
+    fn test_focus_trap_engagement() {
+        let hidden = InheritedVisibility::HIDDEN;
+        let visible = InheritedVisibility::VISIBLE;
+
+        let trap = FocusTrap::default();
+        assert!(!trap.is_engaged(Some(&visible)));
+        assert!(trap.restore_focus);
+        assert!(FocusTrap::new(true).is_engaged(Some(&hidden)));
+
+        let trap = FocusTrap::while_visible();
+        assert!(trap.is_engaged(Some(&visible)));
+        assert!(trap.is_engaged(None));
+        assert!(!trap.is_engaged(Some(&hidden)));
+
+        let e = entities(1);
+        let trap = FocusTrap::new(true)
+            .with_initial_focus(e[0])
+            .without_restore();
+        assert_eq!(trap.initial_focus, Some(e[0]));
+        assert!(!trap.restore_focus);
+    }
+
+    #[test]
+    fn test_active_trap_is_innermost() {
+        let e = entities(2);
+        let mut manager = FocusManager::default();
+        assert_eq!(manager.active_trap(), None);
+
+        for root in e.iter().copied() {
+            manager.traps.push(TrapEntry {
+                root,
+                restore: None,
+                restore_visible: false,
+                awaiting_initial: false,
+            });
+        }
+        assert_eq!(manager.active_trap(), Some(e[1]));
+    }
+}
@@ -0,0 +1,712 @@
+//! Form container
+//!
+//! A [`MaterialForm`] groups the inputs below it in the hierarchy. Inputs join the
+//! form by carrying a [`FormField`] key; the form keeps a [`FormValues`] snapshot
+//! of their values, tracks dirty / valid state, and sends one [`FormSubmitEvent`]
+//! when the form is submitted.
+//!
+//! Supported inputs: [`MaterialTextField`], [`MaterialCheckbox`], [`MaterialSwitch`],
+//! [`MaterialSlider`] and [`MaterialSelect`].
+//!
+//! A form is submitted by:
+//! - Enter in one of its text fields ([`TextFieldSubmitEvent`])
+//! - clicking a button marked with [`FormSubmitButton`]
+//! - sending [`SubmitForm`]
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! commands
+//!     .spawn((MaterialForm::new(), Node::default()))
+//!     .with_children(|form| {
+//!         let name = TextFieldBuilder::new().label("Name").validator(Required::new());
+//!         spawn_text_field_control_with(form, &theme, name, FormField::new("name"));
+//!         form.spawn((SwitchBuilder::new().build(&theme), FormField::new("newsletter")));
+//!         form.spawn((MaterialButtonBuilder::new("Save").filled().build(&theme), FormSubmitButton));
+//!     });
+//!
+//! fn on_submit(mut submits: MessageReader<FormSubmitEvent>) {
+//!     for ev in submits.read().filter(|ev| ev.valid) {
+//!         let name = ev.values.text("name").unwrap_or_default();
+//!     }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    button::ButtonClickEvent,
+    checkbox::{CheckboxState, MaterialCheckbox},
+    select::MaterialSelect,
+    slider::MaterialSlider,
+    switch::MaterialSwitch,
+    text_field::{MaterialTextField, TextFieldSubmitEvent},
+};
+
+/// Plugin for the form container
+pub struct FormPlugin;
+
+impl Plugin for FormPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<SubmitForm>()
+            .add_message::<ResetForm>()
+            .add_message::<FormSubmitEvent>()
+            // Inputs update their values during `Update`; read them once they settle.
+            .add_systems(
+                PostUpdate,
+                (form_reset_system, form_sync_system, form_submit_system).chain(),
+            );
+    }
+}
+
+/// The value of a single form input
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormValue {
+    /// Text field value
+    Text(String),
+    /// Checkbox (checked) or switch (on) state
+    Bool(bool),
+    /// Slider value
+    Number(f32),
+    /// Select state: the option index and its value (or label when it has none).
+    /// An editable select holding free text has no index and the text as value.
+    Selection {
+        index: Option<usize>,
+        value: Option<String>,
+    },
+    /// Multi-select state: the selected option indices and their values
+    Selections {
+        indices: Vec<usize>,
+        values: Vec<String>,
+    },
+}
+
+impl FormValue {
+    /// The text, if this is a text value
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The flag, if this is a checkbox / switch value
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The number, if this is a slider value
+    pub fn as_number(&self) -> Option<f32> {
+        match self {
+            Self::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The selected option index, if this is a select value with a selection
+    pub fn as_selection(&self) -> Option<usize> {
+        match self {
+            Self::Selection { index, .. } => *index,
+            _ => None,
+        }
+    }
+
+    /// The selected option indices, if this is a multi-select value
+    pub fn as_selections(&self) -> Option<&[usize]> {
+        match self {
+            Self::Selections { indices, .. } => Some(indices),
+            _ => None,
+        }
+    }
+}
+
+/// Snapshot of a form's values, keyed by [`FormField`] key
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormValues(BTreeMap<String, FormValue>);
+
+impl FormValues {
+    /// Value of a field
+    pub fn get(&self, key: &str) -> Option<&FormValue> {
+        self.0.get(key)
+    }
+
+    /// Text of a text field
+    pub fn text(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(FormValue::as_text)
+    }
+
+    /// State of a checkbox or switch
+    pub fn bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(FormValue::as_bool)
+    }
+
+    /// Value of a slider
+    pub fn number(&self, key: &str) -> Option<f32> {
+        self.get(key).and_then(FormValue::as_number)
+    }
+
+    /// Selected option index of a select
+    pub fn selection(&self, key: &str) -> Option<usize> {
+        self.get(key).and_then(FormValue::as_selection)
+    }
+
+    /// Selected option indices of a multi-select
+    pub fn selections(&self, key: &str) -> Option<&[usize]> {
+        self.get(key).and_then(FormValue::as_selections)
+    }
+
+    /// Whether a field with this key is registered
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// Number of fields
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the form has no fields
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over `(key, value)` pairs in key order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FormValue)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+/// Form container component.
+///
+/// Put it on a node whose descendants are the form's inputs.
+#[derive(Component, Debug, Clone, Default)]
+pub struct MaterialForm {
+    values: FormValues,
+    initial: FormValues,
+    errors: BTreeMap<String, String>,
+}
+
+impl MaterialForm {
+    /// Create an empty form
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current values of all registered fields
+    pub fn values(&self) -> &FormValues {
+        &self.values
+    }
+
+    /// Values the fields had when they registered (or at the last [`Self::mark_pristine`])
+    pub fn initial_values(&self) -> &FormValues {
+        &self.initial
+    }
+
+    /// Whether any field differs from its initial value
+    pub fn is_dirty(&self) -> bool {
+        self.values != self.initial
+    }
+
+    /// Whether all fields still have their initial values
+    pub fn is_pristine(&self) -> bool {
+        !self.is_dirty()
+    }
+
+    /// Keys of the fields that differ from their initial value
+    pub fn dirty_fields(&self) -> impl Iterator<Item = &str> {
+        self.values
+            .iter()
+            .filter(|(key, value)| self.initial.get(key) != Some(*value))
+            .map(|(key, _)| key)
+    }
+
+    /// Whether every field is valid
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Error messages of invalid fields, keyed by field key
+    pub fn errors(&self) -> &BTreeMap<String, String> {
+        &self.errors
+    }
+
+    /// Make the current values the new initial values (e.g. after saving)
+    pub fn mark_pristine(&mut self) {
+        self.initial = self.values.clone();
+    }
+}
+
+/// Registers an input under a key in the nearest [`MaterialForm`] ancestor
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct FormField {
+    pub key: String,
+}
+
+impl FormField {
+    /// Create a field key
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+/// Marker for buttons that submit their form when clicked
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FormSubmitButton;
+
+/// Request to submit a form
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub struct SubmitForm {
+    pub form: Entity,
+}
+
+/// Request to reset a form's fields to their initial values
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub struct ResetForm {
+    pub form: Entity,
+}
+
+/// Event sent when a form is submitted
+#[derive(Event, Clone, bevy::prelude::Message)]
+pub struct FormSubmitEvent {
+    /// The form entity
+    pub form: Entity,
+    /// Snapshot of the form's values
+    pub values: FormValues,
+    /// Whether every field passed validation
+    pub valid: bool,
+}
+
+type FormInputs<'a> = (
+    Option<&'a MaterialTextField>,
+    Option<&'a MaterialCheckbox>,
+    Option<&'a MaterialSwitch>,
+    Option<&'a MaterialSlider>,
+    Option<&'a MaterialSelect>,
+);
+
+/// The nearest form above `entity`
+fn owning_form(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    is_form: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    parents
+        .iter_ancestors(entity)
+        .find(|&ancestor| is_form(ancestor))
+}
+
+/// Read the value (and error message, if invalid) of a form input
+fn read_input(
+    (text_field, checkbox, switch, slider, select): FormInputs,
+) -> Option<(FormValue, Option<String>)> {
+    if let Some(field) = text_field {
+        let error = match field.check_validity() {
+            Err(message) => Some(message),
+            // Errors set by the app (e.g. server-side checks) count too; a stale
+            // validation error doesn't.
+            Ok(()) if field.error && field.validation_error.is_none() => {
+                Some(field.error_text.clone().unwrap_or_default())
+            }
+            Ok(()) => None,
+        };
+        return Some((FormValue::Text(field.value.clone()), error));
+    }
+    if let Some(checkbox) = checkbox {
+        return Some((FormValue::Bool(checkbox.state.is_checked()), None));
+    }
+    if let Some(switch) = switch {
+        return Some((FormValue::Bool(switch.selected), None));
+    }
+    if let Some(slider) = slider {
+        return Some((FormValue::Number(slider.value), None));
+    }
+    if let Some(select) = select {
+        let error = select
+            .error
+            .then(|| select.error_text.clone().unwrap_or_default());
+        if select.multiple {
+            return Some((
+                FormValue::Selections {
+                    indices: select.selected_indices.iter().copied().collect(),
+                    values: select.selected_values(),
+                },
+                error,
+            ));
+        }
+        let value = select
+            .selected_values()
+            .pop()
+            .or_else(|| select.custom_value.clone());
+        return Some((
+            FormValue::Selection {
+                index: select.selected_index,
+                value,
+            },
+            error,
+        ));
+    }
+    None
+}
+
+/// Inputs whose value, key or parent changed since the last sync
+type ChangedFormInputs = (
+    With<FormField>,
+    Or<(
+        Changed<FormField>,
+        Changed<ChildOf>,
+        Changed<MaterialTextField>,
+        Changed<MaterialCheckbox>,
+        Changed<MaterialSwitch>,
+        Changed<MaterialSlider>,
+        Changed<MaterialSelect>,
+    )>,
+);
+
+/// Collect the values of every form's fields and register new fields.
+///
+/// Validators can be expensive, so this only runs when a field or form was
+/// added, changed or removed.
+fn form_sync_system(
+    mut forms: Query<(Entity, &mut MaterialForm)>,
+    fields: Query<(Entity, &FormField, FormInputs)>,
+    changed_fields: Query<(), ChangedFormInputs>,
+    mut removed_fields: RemovedComponents<FormField>,
+    parents: Query<&ChildOf>,
+) {
+    let removed = removed_fields.read().count() > 0;
+    let added_form = forms.iter_mut().any(|(_, form)| form.is_added());
+    if !removed && !added_form && changed_fields.is_empty() {
+        return;
+    }
+
+    let mut collected: BTreeMap<Entity, (FormValues, BTreeMap<String, String>)> = forms
+        .iter()
+        .map(|(entity, _)| (entity, Default::default()))
+        .collect();
+
+    for (entity, field, inputs) in fields.iter() {
+        let Some(form) = owning_form(entity, &parents, |e| collected.contains_key(&e)) else {
+            continue;
+        };
+        let Some((value, error)) = read_input(inputs) else {
+            continue;
+        };
+        let (values, errors) = collected.get_mut(&form).expect("form was collected");
+        values.0.insert(field.key.clone(), value);
+        if let Some(error) = error {
+            errors.insert(field.key.clone(), error);
+        }
+    }
+
+    for (entity, mut form) in forms.iter_mut() {
+        let Some((values, errors)) = collected.remove(&entity) else {
+            continue;
+        };
+
+        // New fields start out pristine; removed fields are forgotten.
+        let mut initial = form.initial.clone();
+        initial.0.retain(|key, _| values.contains(key));
+        for (key, value) in values.iter() {
+            if !initial.contains(key) {
+                initial.0.insert(key.to_string(), value.clone());
+            }
+        }
+
+        if form.values != values {
+            form.values = values;
+        }
+        if form.initial != initial {
+            form.initial = initial;
+        }
+        if form.errors != errors {
+            form.errors = errors;
+        }
+    }
+}
+
+/// Submit forms on Enter in a text field, submit button clicks and [`SubmitForm`].
+fn form_submit_system(
+    mut submit_requests: MessageReader<SubmitForm>,
+    mut text_submits: MessageReader<TextFieldSubmitEvent>,
+    mut button_clicks: MessageReader<ButtonClickEvent>,
+    submit_buttons: Query<(), With<FormSubmitButton>>,
+    parents: Query<&ChildOf>,
+    forms: Query<&MaterialForm>,
+    mut text_fields: Query<(Entity, &mut MaterialTextField), With<FormField>>,
+    mut submit_events: MessageWriter<FormSubmitEvent>,
+) {
+    let is_form = |entity: Entity| forms.contains(entity);
+    let mut submitted: Vec<Entity> = Vec::new();
+
+    for ev in submit_requests.read() {
+        if is_form(ev.form) {
+            submitted.push(ev.form);
+        }
+    }
+    for ev in text_submits.read() {
+        submitted.extend(owning_form(ev.entity, &parents, is_form));
+    }
+    for ev in button_clicks.read() {
+        if submit_buttons.contains(ev.entity) {
+            submitted.extend(owning_form(ev.entity, &parents, is_form));
+        }
+    }
+
+    submitted.sort();
+    submitted.dedup();
+    for form_entity in submitted {
+        let Ok(form) = forms.get(form_entity) else {
+            continue;
+        };
+
+        // Show every field's validation result, whatever its trigger.
+        for (entity, mut field) in text_fields.iter_mut() {
+            if owning_form(entity, &parents, is_form) == Some(form_entity) {
+                field.validate();
+            }
+        }
+
+        submit_events.write(FormSubmitEvent {
+            form: form_entity,
+            values: form.values().clone(),
+            valid: form.is_valid(),
+        });
+    }
+}
+
+/// Put the fields of forms in [`ResetForm`] requests back to their initial values.
+fn form_reset_system(
+    mut reset_requests: MessageReader<ResetForm>,
+    forms: Query<&MaterialForm>,
+    parents: Query<&ChildOf>,
+    mut fields: Query<(
+        Entity,
+        &FormField,
+        Option<&mut MaterialTextField>,
+        Option<&mut MaterialCheckbox>,
+        Option<&mut MaterialSwitch>,
+        Option<&mut MaterialSlider>,
+        Option<&mut MaterialSelect>,
+    )>,
+) {
+    for ev in reset_requests.read() {
+        let Ok(form) = forms.get(ev.form) else {
+            continue;
+        };
+
+        for (entity, key, text_field, checkbox, switch, slider, select) in fields.iter_mut() {
+            if owning_form(entity, &parents, |e| forms.contains(e)) != Some(ev.form) {
+                continue;
+            }
+            let Some(initial) = form.initial.get(&key.key) else {
+                continue;
+            };
+
+            match initial {
+                FormValue::Text(text) => {
+                    if let Some(mut field) = text_field {
+                        if field.value != *text {
+                            field.set_value(text.clone());
+                        }
+                        // A pristine field shows no validation errors.
+                        if field.validation_error.take().is_some() {
+                            field.error = false;
+                            field.error_text = None;
+                        }
+                    }
+                }
+                &FormValue::Bool(checked) => {
+                    if let Some(mut checkbox) = checkbox {
+                        if checkbox.state.is_checked() != checked {
+                            checkbox.state = if checked {
+                                CheckboxState::Checked
+                            } else {
+                                CheckboxState::Unchecked
+                            };
+                        }
+                    } else if let Some(mut switch) = switch {
+                        if switch.selected != checked {
+                            switch.selected = checked;
+                        }
+                    }
+                }
+                &FormValue::Number(value) => {
+                    if let Some(mut slider) = slider {
+                        if slider.value != value {
+                            slider.value = value;
+                        }
+                    }
+                }
+                FormValue::Selection { index, value } => {
+                    if let Some(mut select) = select {
+                        if select.selected_index != *index {
+                            select.selected_index = *index;
+                        }
+                        let custom = if index.is_none() && select.editable {
+                            value.clone()
+                        } else {
+                            None
+                        };
+                        if select.custom_value != custom {
+                            select.custom_value = custom;
+                        }
+                    }
+                }
+                FormValue::Selections { indices, .. } => {
+                    if let Some(mut select) = select {
+                        let indices = indices.iter().copied().collect();
+                        if select.selected_indices != indices {
+                            select.selected_indices = indices;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Required;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_read_input_values_and_errors() {
+        let field = MaterialTextField::new().validator(Required::new());
+        let (value, error) = read_input((Some(&field), None, None, None, None)).unwrap();
+        assert_eq!(value, FormValue::Text(String::new()));
+        assert_eq!(error.as_deref(), Some("Required"));
+
+        let switch = MaterialSwitch::new().selected(true);
+        let (value, error) = read_input((None, None, Some(&switch), None, None)).unwrap();
+        assert_eq!(value.as_bool(), Some(true));
+        assert_eq!(error, None);
+
+        assert!(read_input((None, None, None, None, None)).is_none());
+    }
+
+    #[test]
+    fn test_read_select_values() {
+        use crate::select::SelectOption;
+
+        let options = vec![
+            SelectOption::new("Red").value("r"),
+            SelectOption::new("Green"),
+            SelectOption::new("Blue").value("b"),
+        ];
+        let multi = MaterialSelect::new(options.clone())
+            .multiple(true)
+            .selected(0)
+            .selected(2);
+        let (value, _) = read_input((None, None, None, None, Some(&multi))).unwrap();
+        assert_eq!(
+            value,
+            FormValue::Selections {
+                indices: vec![0, 2],
+                values: vec!["r".into(), "b".into()],
+            }
+        );
+
+        let mut combobox = MaterialSelect::new(options)
+            .editable(true)
+            .allow_custom(true);
+        combobox.custom_value = Some("Teal".into());
+        let (value, _) = read_input((None, None, None, None, Some(&combobox))).unwrap();
+        assert_eq!(
+            value,
+            FormValue::Selection {
+                index: None,
+                value: Some("Teal".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_sync_validates_only_on_change() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = calls.clone();
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, FormPlugin))
+            .add_message::<TextFieldSubmitEvent>()
+            .add_message::<ButtonClickEvent>();
+
+        let form = app.world_mut().spawn(MaterialForm::new()).id();
+        let field = app
+            .world_mut()
+            .spawn((
+                FormField::new("name"),
+                MaterialTextField::new().validator(move |value: &str| {
+                    counted.fetch_add(1, Ordering::Relaxed);
+                    if value.is_empty() {
+                        Err("Required".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }),
+                ChildOf(form),
+            ))
+            .id();
+
+        app.update();
+        let after_spawn = calls.load(Ordering::Relaxed);
+        assert!(after_spawn > 0);
+        let form_state = app.world().get::<MaterialForm>(form).unwrap();
+        assert_eq!(
+            form_state.errors.get("name").map(String::as_str),
+            Some("Required")
+        );
+
+        app.update();
+        app.update();
+        assert_eq!(calls.load(Ordering::Relaxed), after_spawn);
+
+        app.world_mut()
+            .get_mut::<MaterialTextField>(field)
+            .unwrap()
+            .value = "Ada".to_string();
+        app.update();
+        assert!(calls.load(Ordering::Relaxed) > after_spawn);
+        let form_state = app.world().get::<MaterialForm>(form).unwrap();
+        assert!(form_state.errors.is_empty());
+        assert_eq!(
+            form_state.values.get("name"),
+            Some(&FormValue::Text("Ada".into()))
+        );
+
+        app.world_mut().entity_mut(field).despawn();
+        app.update();
+        assert!(app
+            .world()
+            .get::<MaterialForm>(form)
+            .unwrap()
+            .values
+            .is_empty());
+    }
+
+    #[test]
+    fn test_dirty_tracking() {
+        let mut form = MaterialForm::new();
+        form.initial
+            .0
+            .insert("name".into(), FormValue::Text("Ada".into()));
+        form.values = form.initial.clone();
+        assert!(form.is_pristine());
+
+        form.values
+            .0
+            .insert("name".into(), FormValue::Text("Grace".into()));
+        assert!(form.is_dirty());
+        assert_eq!(form.dirty_fields().collect::<Vec<_>>(), vec!["name"]);
+
+        form.mark_pristine();
+        assert!(form.is_pristine());
+        assert_eq!(form.initial_values().text("name"), Some("Grace"));
+    }
+}
@@ -0,0 +1,444 @@
+//! Fuzzy matching for filtering and ranking text entries
+//!
+//! [`fuzzy_match`] checks whether the characters of a query appear, in order, in a
+//! candidate (a subsequence match) and scores how good the match is:
+//!
+//! - Matches are case- and diacritic-insensitive (`"cafe"` matches `"Café"`).
+//! - Runs of consecutive characters, word starts (`"nf"` in `"New File"`), camel-case
+//!   humps and the start of the candidate score higher; skipped characters cost a little.
+//! - Whitespace in the query is ignored.
+//!
+//! The returned byte ranges point into the original candidate, ready to be drawn with
+//! emphasis through [`MatchHighlight`]. [`fuzzy_filter`] ranks a whole list.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use bevy_material_ui::fuzzy::{fuzzy_filter, fuzzy_match};
+//!
+//! let m = fuzzy_match("nf", "New File").unwrap();
+//! assert_eq!(m.ranges, vec![0..1, 4..5]);
+//!
+//! let ranked = fuzzy_filter("fil", &["Profile", "File", "Open"]);
+//! assert_eq!(ranked[0].0, 1); // "File" before "Profile"
+//! assert_eq!(ranked.len(), 2);
+//! ```
+
+use std::ops::Range;
+
+use bevy::prelude::*;
+
+/// Plugin rendering [`MatchHighlight`] texts
+pub struct FuzzyPlugin;
+
+impl Plugin for FuzzyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, match_highlight_system);
+    }
+}
+
+/// Score of a matched character
+const SCORE_MATCH: i32 = 16;
+/// Bonus for a match right after the previous one
+const BONUS_CONSECUTIVE: i32 = 12;
+/// Bonus for a match at the start of a word (or of the candidate)
+const BONUS_WORD_START: i32 = 10;
+/// Extra bonus for matching the first character of the candidate
+const BONUS_FIRST_CHAR: i32 = 6;
+/// Cost of each candidate character skipped between two matches
+const PENALTY_GAP: i32 = 1;
+/// Maximum cost of the characters skipped before the first match
+const MAX_LEADING_PENALTY: i32 = 3;
+
+/// Result of a successful fuzzy match
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FuzzyMatch {
+    /// Match quality; higher is better
+    pub score: i32,
+    /// Byte ranges of the matched characters in the candidate (merged, ascending)
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Fold a character for matching: lowercase, without diacritics.
+pub fn fold_char(c: char) -> char {
+    let c = c.to_lowercase().next().unwrap_or(c);
+    match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        other => other,
+    }
+}
+
+/// Fold a whole string with [`fold_char`], dropping combining marks.
+pub fn fold(text: &str) -> String {
+    text.chars()
+        .filter(|c| !is_combining_mark(*c))
+        .map(fold_char)
+        .collect()
+}
+
+/// Combining diacritical marks (as left by decomposed text such as `"e\u{301}"`)
+fn is_combining_mark(c: char) -> bool {
+    matches!(c, '\u{0300}'..='\u{036F}')
+}
+
+/// A matchable character of a candidate
+struct CandidateChar {
+    folded: char,
+    bytes: Range<usize>,
+    word_start: bool,
+}
+
+fn candidate_chars(candidate: &str) -> Vec<CandidateChar> {
+    let mut chars: Vec<CandidateChar> = Vec::with_capacity(candidate.len());
+    let mut prev: Option<char> = None;
+    for (byte, c) in candidate.char_indices() {
+        let end = byte + c.len_utf8();
+        if is_combining_mark(c) {
+            // Marks belong to the character before them.
+            if let Some(last) = chars.last_mut() {
+                last.bytes.end = end;
+            }
+            continue;
+        }
+        let word_start = match prev {
+            None => true,
+            Some(p) => {
+                !p.is_alphanumeric()
+                    || (p.is_lowercase() && c.is_uppercase())
+                    || (!p.is_numeric() && c.is_numeric())
+            }
+        };
+        chars.push(CandidateChar {
+            folded: fold_char(c),
+            bytes: byte..end,
+            word_start: word_start && c.is_alphanumeric(),
+        });
+        prev = Some(c);
+    }
+    chars
+}
+
+/// Match `query` against `candidate`.
+///
+/// Returns `None` unless every (non-whitespace) query character appears in the
+/// candidate in order. An empty query matches everything with a score of 0.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace() && !is_combining_mark(*c))
+        .map(fold_char)
+        .collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch::default());
+    }
+
+    let chars = candidate_chars(candidate);
+    let (n, m) = (query.len(), chars.len());
+    if n > m {
+        return None;
+    }
+
+    let bonus = |j: usize| {
+        let c = &chars[j];
+        let mut bonus = 0;
+        if c.word_start {
+            bonus += BONUS_WORD_START;
+        }
+        if j == 0 {
+            bonus += BONUS_FIRST_CHAR;
+        }
+        bonus
+    };
+
+    // score[i][j]: best score with query[i] matched at chars[j] (None if impossible);
+    // from[i][j]: where query[i - 1] was matched on that best path.
+    let mut score: Vec<Vec<Option<i32>>> = vec![vec![None; m]; n];
+    let mut from: Vec<Vec<usize>> = vec![vec![0; m]; n];
+
+    for j in 0..m {
+        if chars[j].folded == query[0] {
+            let leading = (j as i32 * PENALTY_GAP).min(MAX_LEADING_PENALTY);
+            score[0][j] = Some(SCORE_MATCH + bonus(j) - leading);
+        }
+    }
+
+    for i in 1..n {
+        // Best `score[i - 1][k] + k * PENALTY_GAP` over k < j - 1 (a gap before j).
+        let mut best_gap: Option<(i32, usize)> = None;
+        for j in i..m {
+            if j >= 2 {
+                let k = j - 2;
+                if let Some(s) = score[i - 1][k] {
+                    let value = s + k as i32 * PENALTY_GAP;
+                    if best_gap.is_none_or(|(best, _)| value > best) {
+                        best_gap = Some((value, k));
+                    }
+                }
+            }
+            if chars[j].folded != query[i] {
+                continue;
+            }
+
+            let consecutive = score[i - 1][j - 1].map(|s| (s + BONUS_CONSECUTIVE, j - 1));
+            let gapped = best_gap.map(|(value, k)| (value - (j as i32 - 1) * PENALTY_GAP, k));
+            let best = match (consecutive, gapped) {
+                (Some(a), Some(b)) => Some(if a.0 >= b.0 { a } else { b }),
+                (a, b) => a.or(b),
+            };
+            if let Some((s, k)) = best {
+                score[i][j] = Some(s + SCORE_MATCH + bonus(j));
+                from[i][j] = k;
+            }
+        }
+    }
+
+    let (mut j, best) = score[n - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, s)| s.map(|s| (j, s)))
+        .max_by_key(|(j, s)| (*s, std::cmp::Reverse(*j)))?;
+
+    let mut positions = vec![0; n];
+    for i in (0..n).rev() {
+        positions[i] = j;
+        if i > 0 {
+            j = from[i][j];
+        }
+    }
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for &p in &positions {
+        let bytes = chars[p].bytes.clone();
+        match ranges.last_mut() {
+            Some(last) if last.end == bytes.start => last.end = bytes.end,
+            _ => ranges.push(bytes),
+        }
+    }
+
+    Some(FuzzyMatch {
+        score: best,
+        ranges,
+    })
+}
+
+/// Match `query` against every candidate and rank the matches.
+///
+/// Returns `(index, match)` pairs, best first. Equal scores prefer the shorter
+/// candidate, then the original order. An empty query keeps every candidate in order.
+pub fn fuzzy_filter<S: AsRef<str>>(query: &str, candidates: &[S]) -> Vec<(usize, FuzzyMatch)> {
+    let mut matches: Vec<(usize, FuzzyMatch)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| fuzzy_match(query, candidate.as_ref()).map(|m| (index, m)))
+        .collect();
+    if query.chars().all(char::is_whitespace) {
+        return matches;
+    }
+    matches.sort_by_key(|(index, m)| {
+        (
+            std::cmp::Reverse(m.score),
+            candidates[*index].as_ref().chars().count(),
+            *index,
+        )
+    });
+    matches
+}
+
+/// Split `text` into `(segment, matched)` runs following `ranges`.
+///
+/// Ranges that are out of bounds or not on character boundaries are ignored.
+pub fn highlight_segments<'a>(text: &'a str, ranges: &[Range<usize>]) -> Vec<(&'a str, bool)> {
+    let mut segments = Vec::new();
+    let mut cursor = 0;
+    for range in ranges {
+        if range.start < cursor
+            || range.end > text.len()
+            || range.start >= range.end
+            || !text.is_char_boundary(range.start)
+            || !text.is_char_boundary(range.end)
+        {
+            continue;
+        }
+        if range.start > cursor {
+            segments.push((&text[cursor..range.start], false));
+        }
+        segments.push((&text[range.clone()], true));
+        cursor = range.end;
+    }
+    if cursor < text.len() {
+        segments.push((&text[cursor..], false));
+    }
+    segments
+}
+
+/// Text drawn with its matched characters emphasized.
+///
+/// Put it on an entity with [`Text`], [`TextFont`] and [`TextColor`]; the text is
+/// rebuilt as spans in the entity's font, with matched ranges in `highlight_color`
+/// and the rest in the entity's `TextColor`.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct MatchHighlight {
+    /// Full text
+    pub text: String,
+    /// Byte ranges to emphasize (as in [`FuzzyMatch::ranges`])
+    pub ranges: Vec<Range<usize>>,
+    /// Color of the matched characters
+    pub highlight_color: Color,
+}
+
+impl MatchHighlight {
+    pub fn new(text: impl Into<String>, highlight_color: Color) -> Self {
+        Self {
+            text: text.into(),
+            ranges: Vec::new(),
+            highlight_color,
+        }
+    }
+
+    /// Set the ranges to emphasize
+    pub fn with_ranges(mut self, ranges: Vec<Range<usize>>) -> Self {
+        self.ranges = ranges;
+        self
+    }
+}
+
+/// Marker for the spans spawned by [`MatchHighlight`]
+#[derive(Component)]
+pub struct MatchHighlightSpan;
+
+/// Rebuild the spans of changed [`MatchHighlight`] texts.
+fn match_highlight_system(
+    mut commands: Commands,
+    mut highlights: Query<
+        (
+            Entity,
+            &MatchHighlight,
+            &mut Text,
+            &TextFont,
+            &TextColor,
+            Option<&Children>,
+        ),
+        Or<(
+            Changed<MatchHighlight>,
+            Changed<TextFont>,
+            Changed<TextColor>,
+        )>,
+    >,
+    spans: Query<(), With<MatchHighlightSpan>>,
+) {
+    for (entity, highlight, mut text, font, color, children) in highlights.iter_mut() {
+        // The spans carry the whole text.
+        if !text.0.is_empty() {
+            text.0.clear();
+        }
+        for child in children.into_iter().flatten() {
+            if spans.contains(*child) {
+                commands.entity(*child).despawn();
+            }
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            for (segment, matched) in highlight_segments(&highlight.text, &highlight.ranges) {
+                parent.spawn((
+                    MatchHighlightSpan,
+                    TextSpan::new(segment),
+                    font.clone(),
+                    TextColor(if matched {
+                        highlight.highlight_color
+                    } else {
+                        color.0
+                    }),
+                ));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_required() {
+        assert!(fuzzy_match("abc", "aXbXc").is_some());
+        assert!(fuzzy_match("acb", "abc").is_none());
+        assert!(fuzzy_match("abcd", "abc").is_none());
+        assert_eq!(fuzzy_match("", "anything"), Some(FuzzyMatch::default()));
+        assert_eq!(fuzzy_match("  ", "x"), Some(FuzzyMatch::default()));
+    }
+
+    #[test]
+    fn test_case_and_diacritic_folding() {
+        assert_eq!(fold("Crème Brûlée"), "creme brulee");
+        assert_eq!(fuzzy_match("CAFE", "Café").unwrap().ranges, vec![0..5]);
+        // Decomposed accents stay attached to their letter.
+        assert_eq!(
+            fuzzy_match("cafe", "Cafe\u{301}").unwrap().ranges,
+            vec![0..6]
+        );
+        assert_eq!(fuzzy_match("é", "resume").unwrap().ranges, vec![1..2]);
+    }
+
+    #[test]
+    fn test_prefers_word_starts_and_runs() {
+        assert_eq!(
+            fuzzy_match("nf", "New File").unwrap().ranges,
+            vec![0..1, 4..5]
+        );
+        // "fil" also matches inside "Profile"; the word start wins.
+        assert_eq!(
+            fuzzy_match("fil", "Profile File").unwrap().ranges,
+            vec![8..11]
+        );
+        assert_eq!(
+            fuzzy_match("sb", "searchBar").unwrap().ranges,
+            vec![0..1, 6..7]
+        );
+
+        let run = fuzzy_match("app", "apple").unwrap().score;
+        let spread = fuzzy_match("app", "a_p_p").unwrap().score;
+        assert!(run > spread);
+    }
+
+    #[test]
+    fn test_filter_ranks_and_breaks_ties() {
+        let items = ["Save As", "Save", "Open", "Preferences - Saved Searches"];
+        let ranked: Vec<usize> = fuzzy_filter("save", &items)
+            .iter()
+            .map(|(i, _)| *i)
+            .collect();
+        assert_eq!(ranked, vec![1, 0, 3]);
+
+        let all: Vec<usize> = fuzzy_filter("", &items).iter().map(|(i, _)| *i).collect();
+        assert_eq!(all, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_highlight_segments() {
+        assert_eq!(
+            highlight_segments("New File", &[0..1, 4..5]),
+            vec![("N", true), ("ew ", false), ("F", true), ("ile", false)]
+        );
+        assert_eq!(highlight_segments("abc", &[]), vec![("abc", false)]);
+        // Invalid ranges are skipped.
+        assert_eq!(highlight_segments("é", &[0..1, 5..9]), vec![("é", false)]);
+    }
+}
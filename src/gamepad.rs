@@ -0,0 +1,596 @@
+//! Gamepad navigation for Material UI
+//!
+//! [`GamepadNavigationPlugin`] drives the focus system from game controllers:
+//!
+//! - D-pad / left stick move focus to the nearest [`Focusable`] in that direction
+//! - South activates the focused element (like Space / Enter), East cancels
+//! - Left / right shoulder buttons select the previous / next [`MaterialTabs`] tab
+//! - Left / right triggers decrease / increase the focused [`MaterialSlider`]
+//!
+//! While a [`MaterialDialog`] or [`MaterialMenu`] is open, navigation is confined
+//! to the topmost one and East closes it.
+//!
+//! The plugin is not part of [`MaterialUiPlugin`](crate::MaterialUiPlugin), so games
+//! that use the controller for gameplay can decide when UI navigation applies.
+
+use bevy::prelude::*;
+
+use crate::{
+    dialog::{DialogCloseEvent, MaterialDialog},
+    focus::{is_focus_hidden, next_enabled_index, FocusManager, Focusable},
+    menu::{MaterialMenu, MenuCloseEvent},
+    slider::{MaterialSlider, SliderChangeEvent},
+    tabs::{MaterialTab, MaterialTabs, TabChangeEvent},
+};
+
+/// Plugin that maps gamepad input onto focus navigation
+pub struct GamepadNavigationPlugin;
+
+impl Plugin for GamepadNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GamepadNavigationSettings>()
+            .init_resource::<GamepadNavigationState>()
+            .add_systems(
+                PreUpdate,
+                (
+                    gamepad_cancel_system,
+                    gamepad_focus_navigation_system,
+                    gamepad_tabs_system,
+                    gamepad_slider_system,
+                )
+                    .chain()
+                    .after(bevy::input::InputSystems),
+            );
+    }
+}
+
+/// Tuning for gamepad navigation
+#[derive(Resource, Debug, Clone)]
+pub struct GamepadNavigationSettings {
+    /// How far the left stick must be pushed before it moves focus (0.0 - 1.0)
+    pub stick_threshold: f32,
+    /// Seconds a direction or trigger must be held before it starts repeating
+    pub repeat_delay: f32,
+    /// Seconds between repeats while a direction or trigger is held
+    pub repeat_interval: f32,
+}
+
+impl Default for GamepadNavigationSettings {
+    fn default() -> Self {
+        Self {
+            stick_threshold: 0.5,
+            repeat_delay: 0.4,
+            repeat_interval: 0.1,
+        }
+    }
+}
+
+/// Direction of a spatial focus move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationDirection {
+    /// Towards the top of the screen
+    Up,
+    /// Towards the bottom of the screen
+    Down,
+    /// Towards the left edge of the screen
+    Left,
+    /// Towards the right edge of the screen
+    Right,
+}
+
+impl NavigationDirection {
+    /// Map a stick / D-pad vector (Y up) to its dominant direction.
+    ///
+    /// Returns `None` while the input is shorter than `threshold`.
+    pub fn from_input(input: Vec2, threshold: f32) -> Option<Self> {
+        if input.length() < threshold.max(f32::EPSILON) {
+            return None;
+        }
+
+        Some(if input.x.abs() > input.y.abs() {
+            if input.x > 0.0 {
+                Self::Right
+            } else {
+                Self::Left
+            }
+        } else if input.y > 0.0 {
+            Self::Up
+        } else {
+            Self::Down
+        })
+    }
+}
+
+/// Find the nearest candidate in `direction` from `current` (UI coordinates, Y down).
+///
+/// Candidates must lie beyond the current element's center in that direction.
+/// They are ranked by the gap along the direction plus a penalty for being offset
+/// sideways, so elements in the same row or column win over diagonal ones.
+pub fn find_spatial_neighbor(
+    current: Rect,
+    candidates: &[(Entity, Rect)],
+    direction: NavigationDirection,
+) -> Option<Entity> {
+    candidates
+        .iter()
+        .filter_map(|(entity, rect)| {
+            spatial_score(current, *rect, direction).map(|score| (*entity, score))
+        })
+        .min_by(|(_, a), (_, b)| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)))
+        .map(|(entity, _)| entity)
+}
+
+/// Rank `to` as a move from `from`: (weighted distance, center distance).
+fn spatial_score(from: Rect, to: Rect, direction: NavigationDirection) -> Option<(f32, f32)> {
+    let from_center = from.center();
+    let to_center = to.center();
+
+    let (progress, gap, from_span, to_span) = match direction {
+        NavigationDirection::Right => (
+            to_center.x - from_center.x,
+            to.min.x - from.max.x,
+            (from.min.y, from.max.y),
+            (to.min.y, to.max.y),
+        ),
+        NavigationDirection::Left => (
+            from_center.x - to_center.x,
+            from.min.x - to.max.x,
+            (from.min.y, from.max.y),
+            (to.min.y, to.max.y),
+        ),
+        NavigationDirection::Down => (
+            to_center.y - from_center.y,
+            to.min.y - from.max.y,
+            (from.min.x, from.max.x),
+            (to.min.x, to.max.x),
+        ),
+        NavigationDirection::Up => (
+            from_center.y - to_center.y,
+            from.min.y - to.max.y,
+            (from.min.x, from.max.x),
+            (to.min.x, to.max.x),
+        ),
+    };
+
+    if progress <= f32::EPSILON {
+        return None;
+    }
+
+    // Distance between the spans across the direction (0 when they overlap).
+    let offset = (from_span.0.max(to_span.0) - from_span.1.min(to_span.1)).max(0.0);
+
+    Some((gap.max(0.0) + offset * 2.0, from_center.distance(to_center)))
+}
+
+/// Fires on the first frame an input is held, then repeats after a delay.
+#[derive(Debug)]
+struct HoldRepeat<T> {
+    held: Option<T>,
+    timer: f32,
+}
+
+impl<T> Default for HoldRepeat<T> {
+    fn default() -> Self {
+        Self {
+            held: None,
+            timer: 0.0,
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> HoldRepeat<T> {
+    /// Feed this frame's input; returns it when the action should fire.
+    fn update(&mut self, input: Option<T>, delta: f32, delay: f32, interval: f32) -> Option<T> {
+        if input != self.held {
+            self.held = input;
+            self.timer = delay;
+            return input;
+        }
+
+        let held = input?;
+        self.timer -= delta;
+        if self.timer > 0.0 {
+            return None;
+        }
+        self.timer += interval.max(f32::EPSILON);
+        Some(held)
+    }
+}
+
+/// Held-input tracking for directions and slider triggers
+#[derive(Resource, Default, Debug)]
+struct GamepadNavigationState {
+    direction: HoldRepeat<NavigationDirection>,
+    slider: HoldRepeat<i8>,
+}
+
+/// The open dialog or menu drawn on top, if any.
+fn topmost_overlay(overlays: impl IntoIterator<Item = (Entity, bool, u32)>) -> Option<Entity> {
+    overlays
+        .into_iter()
+        .filter(|(_, open, _)| *open)
+        .max_by_key(|(_, _, stack_index)| *stack_index)
+        .map(|(entity, _, _)| entity)
+}
+
+/// Whether `entity` may take focus while `overlay` is open.
+fn is_within_overlay(ancestors: &Query<&ChildOf>, entity: Entity, overlay: Option<Entity>) -> bool {
+    overlay.is_none_or(|overlay| ancestors.iter_ancestors(entity).any(|a| a == overlay))
+}
+
+/// East closes the topmost overlay, or clears focus when none is open.
+fn gamepad_cancel_system(
+    gamepads: Query<&Gamepad>,
+    mut focus: ResMut<FocusManager>,
+    mut dialogs: Query<(Entity, &mut MaterialDialog, &ComputedNode)>,
+    mut menus: Query<(Entity, &mut MaterialMenu, &ComputedNode)>,
+    mut dialog_close: MessageWriter<DialogCloseEvent>,
+    mut menu_close: MessageWriter<MenuCloseEvent>,
+) {
+    if !gamepads
+        .iter()
+        .any(|gamepad| gamepad.just_pressed(GamepadButton::East))
+    {
+        return;
+    }
+
+    let overlay = topmost_overlay(
+        dialogs
+            .iter()
+            .map(|(entity, dialog, node)| (entity, dialog.open, node.stack_index()))
+            .chain(
+                menus
+                    .iter()
+                    .map(|(entity, menu, node)| (entity, menu.open, node.stack_index())),
+            ),
+    );
+
+    let Some(overlay) = overlay else {
+        focus.blur();
+        return;
+    };
+
+    if let Ok((entity, mut dialog, _)) = dialogs.get_mut(overlay) {
+        if dialog.dismiss_on_escape {
+            dialog.open = false;
+            dialog_close.write(DialogCloseEvent {
+                entity,
+                dismissed: true,
+            });
+        }
+    } else if let Ok((entity, mut menu, _)) = menus.get_mut(overlay) {
+        menu.open = false;
+        menu_close.write(MenuCloseEvent { entity });
+    }
+}
+
+/// D-pad / left stick move focus spatially, South activates the focused element.
+fn gamepad_focus_navigation_system(
+    time: Res<Time>,
+    settings: Res<GamepadNavigationSettings>,
+    mut state: ResMut<GamepadNavigationState>,
+    gamepads: Query<&Gamepad>,
+    mut focus: ResMut<FocusManager>,
+    focusables: Query<(
+        Entity,
+        &Focusable,
+        &ComputedNode,
+        &UiGlobalTransform,
+        Option<&InheritedVisibility>,
+    )>,
+    ancestors: Query<&ChildOf>,
+    dialogs: Query<(Entity, &MaterialDialog, &ComputedNode)>,
+    menus: Query<(Entity, &MaterialMenu, &ComputedNode)>,
+) {
+    let input = gamepads.iter().find_map(|gamepad| {
+        NavigationDirection::from_input(gamepad.dpad(), 0.5).or_else(|| {
+            NavigationDirection::from_input(gamepad.left_stick(), settings.stick_threshold)
+        })
+    });
+    let direction = state.direction.update(
+        input,
+        time.delta_secs(),
+        settings.repeat_delay,
+        settings.repeat_interval,
+    );
+    let activate = gamepads
+        .iter()
+        .any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+
+    if direction.is_none() && !activate {
+        return;
+    }
+
+    let overlay = topmost_overlay(
+        dialogs
+            .iter()
+            .map(|(entity, dialog, node)| (entity, dialog.open, node.stack_index()))
+            .chain(
+                menus
+                    .iter()
+                    .map(|(entity, menu, node)| (entity, menu.open, node.stack_index())),
+            ),
+    );
+
+    let mut candidates: Vec<(u32, Entity, Rect)> = focusables
+        .iter()
+        .filter(|(entity, focusable, node, _, visibility)| {
+            focusable.is_tabbable()
+                && !is_focus_hidden(*visibility, node)
+                && is_within_overlay(&ancestors, *entity, overlay)
+                && focus.allows_focus(*entity, &ancestors)
+        })
+        .map(|(entity, _, node, transform, _)| {
+            (
+                node.stack_index(),
+                entity,
+                Rect::from_center_size(transform.translation, node.size()),
+            )
+        })
+        .collect();
+    candidates.sort_by_key(|(stack_index, _, _)| *stack_index);
+
+    let current = focus.focused().and_then(|focused| {
+        candidates
+            .iter()
+            .find(|(_, entity, _)| *entity == focused)
+            .map(|(_, entity, rect)| (*entity, *rect))
+    });
+
+    let Some((current, current_rect)) = current else {
+        // Nothing usable is focused (or focus sits behind an overlay): start at the
+        // first element in tab order.
+        let first = focus
+            .tab_order()
+            .iter()
+            .copied()
+            .find(|entity| candidates.iter().any(|(_, e, _)| e == entity))
+            .or_else(|| candidates.first().map(|(_, entity, _)| *entity));
+        if let Some(first) = first {
+            focus.focus_visible(first);
+        }
+        return;
+    };
+
+    if activate {
+        focus.request_activation();
+    }
+
+    if let Some(direction) = direction {
+        let others: Vec<(Entity, Rect)> = candidates
+            .iter()
+            .filter(|(_, entity, _)| *entity != current)
+            .map(|(_, entity, rect)| (*entity, *rect))
+            .collect();
+        if let Some(next) = find_spatial_neighbor(current_rect, &others, direction) {
+            focus.focus_visible(next);
+        }
+    }
+}
+
+/// Shoulder buttons select the previous / next tab.
+///
+/// Applies to the tab row holding focus, otherwise to the first visible tab row.
+fn gamepad_tabs_system(
+    gamepads: Query<&Gamepad>,
+    mut focus: ResMut<FocusManager>,
+    tab_query: Query<(&MaterialTab, &ChildOf)>,
+    mut tabs_query: Query<(
+        Entity,
+        &mut MaterialTabs,
+        &Children,
+        &ComputedNode,
+        Option<&InheritedVisibility>,
+    )>,
+    ancestors: Query<&ChildOf>,
+    dialogs: Query<(Entity, &MaterialDialog, &ComputedNode)>,
+    menus: Query<(Entity, &MaterialMenu, &ComputedNode)>,
+    mut change_events: MessageWriter<TabChangeEvent>,
+) {
+    let forward = gamepads
+        .iter()
+        .any(|gamepad| gamepad.just_pressed(GamepadButton::RightTrigger));
+    let backward = gamepads
+        .iter()
+        .any(|gamepad| gamepad.just_pressed(GamepadButton::LeftTrigger));
+    if forward == backward {
+        return;
+    }
+
+    let focused_tab = focus.focused().and_then(|focused| {
+        tab_query
+            .get(focused)
+            .ok()
+            .map(|(_, parent)| parent.parent())
+    });
+
+    let tabs_entity = focused_tab.or_else(|| {
+        let overlay = topmost_overlay(
+            dialogs
+                .iter()
+                .map(|(entity, dialog, node)| (entity, dialog.open, node.stack_index()))
+                .chain(
+                    menus
+                        .iter()
+                        .map(|(entity, menu, node)| (entity, menu.open, node.stack_index())),
+                ),
+        );
+        tabs_query
+            .iter()
+            .filter(|(entity, _, _, node, visibility)| {
+                !is_focus_hidden(*visibility, node)
+                    && is_within_overlay(&ancestors, *entity, overlay)
+                    && focus.allows_focus(*entity, &ancestors)
+            })
+            .min_by_key(|(_, _, _, node, _)| node.stack_index())
+            .map(|(entity, _, _, _, _)| entity)
+    });
+
+    let Some(tabs_entity) = tabs_entity else {
+        return;
+    };
+
+    let Ok((tabs_entity, mut tabs, children, _, _)) = tabs_query.get_mut(tabs_entity) else {
+        return;
+    };
+
+    let mut siblings: Vec<(usize, Entity, bool)> = children
+        .iter()
+        .filter_map(|child| {
+            tab_query
+                .get(child)
+                .ok()
+                .map(|(tab, _)| (tab.index, child, !tab.disabled))
+        })
+        .collect();
+    siblings.sort_by_key(|(index, _, _)| *index);
+
+    let Some(current) = siblings
+        .iter()
+        .position(|(index, _, _)| *index == tabs.selected)
+    else {
+        return;
+    };
+    let enabled: Vec<bool> = siblings.iter().map(|(_, _, enabled)| *enabled).collect();
+
+    let Some(target) = next_enabled_index(&enabled, current, forward) else {
+        return;
+    };
+
+    let (tab_index, tab_entity, _) = siblings[target];
+    if focused_tab.is_some() {
+        focus.focus_visible(tab_entity);
+    }
+
+    // `sync_tabs_selection_system` updates the individual tab flags.
+    tabs.selected = tab_index;
+    change_events.write(TabChangeEvent {
+        tabs_entity,
+        tab_entity,
+        index: tab_index,
+    });
+}
+
+/// Triggers decrease / increase the focused slider, repeating while held.
+fn gamepad_slider_system(
+    time: Res<Time>,
+    settings: Res<GamepadNavigationSettings>,
+    mut state: ResMut<GamepadNavigationState>,
+    gamepads: Query<&Gamepad>,
+    focus: Res<FocusManager>,
+    mut sliders: Query<&mut MaterialSlider>,
+    mut change_events: MessageWriter<SliderChangeEvent>,
+) {
+    let input = gamepads.iter().find_map(|gamepad| {
+        if gamepad.pressed(GamepadButton::RightTrigger2) {
+            Some(1)
+        } else if gamepad.pressed(GamepadButton::LeftTrigger2) {
+            Some(-1)
+        } else {
+            None
+        }
+    });
+    let Some(sign) = state.slider.update(
+        input,
+        time.delta_secs(),
+        settings.repeat_delay,
+        settings.repeat_interval,
+    ) else {
+        return;
+    };
+
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+
+    let Ok(mut slider) = sliders.get_mut(entity) else {
+        return;
+    };
+
+    if slider.disabled {
+        return;
+    }
+
+    let step = slider.keyboard_step() * f32::from(sign);
+    if slider.step_by(step) {
+        change_events.write(SliderChangeEvent {
+            entity,
+            value: slider.value,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw_u32(index).unwrap()
+    }
+
+    fn rect(x: f32, y: f32) -> Rect {
+        Rect::from_center_size(Vec2::new(x, y), Vec2::splat(40.0))
+    }
+
+    #[test]
+    fn test_direction_from_input() {
+        assert_eq!(NavigationDirection::from_input(Vec2::ZERO, 0.5), None);
+        assert_eq!(
+            NavigationDirection::from_input(Vec2::new(0.3, 0.0), 0.5),
+            None
+        );
+        assert_eq!(
+            NavigationDirection::from_input(Vec2::new(0.9, 0.2), 0.5),
+            Some(NavigationDirection::Right)
+        );
+        assert_eq!(
+            NavigationDirection::from_input(Vec2::new(0.1, -0.8), 0.5),
+            Some(NavigationDirection::Down)
+        );
+        assert_eq!(
+            NavigationDirection::from_input(Vec2::Y, 0.5),
+            Some(NavigationDirection::Up)
+        );
+    }
+
+    #[test]
+    fn test_spatial_neighbor_prefers_same_row() {
+        let current = rect(0.0, 0.0);
+        let candidates = [
+            (entity(1), rect(100.0, 0.0)),
+            (entity(2), rect(60.0, 80.0)),
+            (entity(3), rect(-100.0, 0.0)),
+        ];
+
+        assert_eq!(
+            find_spatial_neighbor(current, &candidates, NavigationDirection::Right),
+            Some(entity(1))
+        );
+        assert_eq!(
+            find_spatial_neighbor(current, &candidates, NavigationDirection::Left),
+            Some(entity(3))
+        );
+        assert_eq!(
+            find_spatial_neighbor(current, &candidates, NavigationDirection::Down),
+            Some(entity(2))
+        );
+        assert_eq!(
+            find_spatial_neighbor(current, &candidates, NavigationDirection::Up),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hold_repeat() {
+        let mut repeat = HoldRepeat::default();
+
+        assert_eq!(repeat.update(Some(1), 0.016, 0.4, 0.1), Some(1));
+        assert_eq!(repeat.update(Some(1), 0.2, 0.4, 0.1), None);
+        assert_eq!(repeat.update(Some(1), 0.2, 0.4, 0.1), Some(1));
+        assert_eq!(repeat.update(Some(1), 0.05, 0.4, 0.1), None);
+        assert_eq!(repeat.update(Some(1), 0.05, 0.4, 0.1), Some(1));
+
+        assert_eq!(repeat.update(None, 0.016, 0.4, 0.1), None);
+        assert_eq!(repeat.update(Some(-1), 0.016, 0.4, 0.1), Some(-1));
+    }
+}
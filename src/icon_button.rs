@@ -6,6 +6,7 @@
 use bevy::prelude::*;
 
 use crate::{
+    focus::{FocusAppExt, FocusManager, FocusableComponent},
     icons::IconStyle,
     ripple::RippleHost,
     theme::{blend_state_layer, MaterialTheme},
@@ -17,15 +18,18 @@ pub struct IconButtonPlugin;
 
 impl Plugin for IconButtonPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<IconButtonClickEvent>().add_systems(
-            Update,
-            (
-                icon_button_interaction_system,
-                icon_button_style_system,
-                icon_button_content_style_system,
-                icon_button_theme_refresh_system,
-            ),
-        );
+        app.add_message::<IconButtonClickEvent>()
+            .register_focusable::<MaterialIconButton>()
+            .add_systems(
+                Update,
+                (
+                    icon_button_interaction_system,
+                    icon_button_keyboard_system,
+                    icon_button_style_system,
+                    icon_button_content_style_system,
+                    icon_button_theme_refresh_system,
+                ),
+            );
     }
 }
 
@@ -218,6 +222,12 @@ impl MaterialIconButton {
     }
 }
 
+impl FocusableComponent for MaterialIconButton {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event fired when an icon button is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct IconButtonClickEvent {
@@ -266,6 +276,38 @@ fn icon_button_interaction_system(
     }
 }
 
+/// System to activate the focused icon button with Space / Enter
+fn icon_button_keyboard_system(
+    focus: Res<FocusManager>,
+    mut buttons: Query<&mut MaterialIconButton>,
+    mut click_events: MessageWriter<IconButtonClickEvent>,
+) {
+    if !focus.activation_requested() {
+        return;
+    }
+
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+
+    let Ok(mut button) = buttons.get_mut(entity) else {
+        return;
+    };
+
+    if button.disabled {
+        return;
+    }
+
+    if button.toggle {
+        button.selected = !button.selected;
+    }
+
+    click_events.write(IconButtonClickEvent {
+        entity,
+        selected: button.selected,
+    });
+}
+
 /// System to update icon button styles
 fn icon_button_style_system(
     theme: Option<Res<MaterialTheme>>,
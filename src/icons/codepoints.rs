@@ -75,6 +75,8 @@ pub const ICON_CONTENT_CUT: char = '\u{E14E}';
 pub const ICON_UNDO: char = '\u{E166}';
 /// Redo icon codepoint
 pub const ICON_REDO: char = '\u{E15A}';
+/// History icon codepoint (recent items)
+pub const ICON_HISTORY: char = '\u{E889}';
 
 // Toggle Icons
 /// Checkbox checked icon
@@ -357,6 +359,7 @@ pub fn icon_by_name(name: &str) -> Option<char> {
         "cut" | "content_cut" => Some(ICON_CONTENT_CUT),
         "undo" => Some(ICON_UNDO),
         "redo" => Some(ICON_REDO),
+        "history" | "recent" => Some(ICON_HISTORY),
 
         // Toggles
         "checkbox" | "check_box" => Some(ICON_CHECK_BOX),
@@ -191,6 +191,10 @@ impl MaterialIcon {
     pub fn redo() -> Self {
         Self::new(ICON_REDO)
     }
+    /// History icon
+    pub fn history() -> Self {
+        Self::new(ICON_HISTORY)
+    }
 
     // Toggle icons
     /// Checkbox checked icon
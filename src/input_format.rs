@@ -0,0 +1,537 @@
+//! Input masks and number formats for text fields
+//!
+//! An [`InputFormat`] separates a text field's raw value (what is stored in
+//! `MaterialTextField::value`) from the text shown on screen:
+//!
+//! - [`InputMask`] - fixed patterns such as `(###) ###-####` or `##/##/####`. The
+//!   raw value holds only the characters typed into the mask's slots.
+//! - [`NumberFormat`] - numbers with locale-aware grouping and decimal separators,
+//!   a fixed number of decimal places and optional min / max bounds. The raw value
+//!   is a plain number (`-1234.5`) that parses with `str::parse::<f64>()`.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let phone = TextFieldBuilder::new().label("Phone").format(InputMask::new("(###) ###-####"));
+//! let price = TextFieldBuilder::new()
+//!     .label("Price")
+//!     .prefix_text("€")
+//!     .format(NumberFormat::currency(NumberSeparators::COMMA_DECIMAL).min(0.0));
+//! ```
+
+/// How a text field's raw value is entered and displayed
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputFormat {
+    /// Fixed pattern with literal characters
+    Mask(InputMask),
+    /// Formatted number
+    Number(NumberFormat),
+}
+
+impl From<InputMask> for InputFormat {
+    fn from(mask: InputMask) -> Self {
+        Self::Mask(mask)
+    }
+}
+
+impl From<NumberFormat> for InputFormat {
+    fn from(format: NumberFormat) -> Self {
+        Self::Number(format)
+    }
+}
+
+impl InputFormat {
+    /// The raw character to insert when `ch` is typed at `index` of `raw`, or `None`
+    /// if the format rejects it.
+    pub fn accept(&self, raw: &[char], index: usize, ch: char) -> Option<char> {
+        match self {
+            Self::Mask(mask) => mask.accept(raw, index, ch),
+            Self::Number(format) => format.accept(raw, index, ch),
+        }
+    }
+
+    /// Maximum raw length, if the format has one
+    pub fn max_len(&self) -> Option<usize> {
+        match self {
+            Self::Mask(mask) => Some(mask.slot_count()),
+            Self::Number(_) => None,
+        }
+    }
+
+    /// Convert text (e.g. a pasted, already formatted value) to a raw value
+    pub fn parse(&self, text: &str) -> String {
+        let mut raw: Vec<char> = Vec::new();
+        for ch in text.chars() {
+            if self.max_len().is_some_and(|max| raw.len() >= max) {
+                break;
+            }
+            if let Some(ch) = self.accept(&raw, raw.len(), ch) {
+                raw.push(ch);
+            }
+        }
+        raw.into_iter().collect()
+    }
+
+    /// Clean up a raw value, dropping characters the format doesn't accept.
+    ///
+    /// Unlike [`Self::parse`], numbers always use `.` as the decimal separator here,
+    /// whatever the locale. Mask literals are skipped, so formatted mask text works too.
+    pub fn parse_raw(&self, raw: &str) -> String {
+        match self {
+            Self::Mask(_) => self.parse(raw),
+            Self::Number(format) => Self::Number(NumberFormat {
+                separators: NumberSeparators::POINT_DECIMAL,
+                ..format.clone()
+            })
+            .parse(raw),
+        }
+    }
+
+    /// Format a raw value for display
+    pub fn format(&self, raw: &str) -> String {
+        self.layout(raw).text
+    }
+
+    /// Format a raw value and record where each raw character ends up.
+    pub(crate) fn layout(&self, raw: &str) -> FormattedText {
+        match self {
+            Self::Mask(mask) => mask.layout(raw),
+            Self::Number(format) => format.layout(raw),
+        }
+    }
+}
+
+/// Formatted text with the display position of every raw character
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct FormattedText {
+    pub text: String,
+    /// Display character index of each raw character
+    pub positions: Vec<usize>,
+}
+
+impl FormattedText {
+    /// Display index for a raw caret position
+    pub fn raw_to_display(&self, index: usize) -> usize {
+        match self.positions.get(index) {
+            Some(&position) => position,
+            None => self.positions.last().map_or(0, |&last| last + 1),
+        }
+    }
+
+    /// Raw caret position for a display index (snapping past inserted literals)
+    pub fn display_to_raw(&self, index: usize) -> usize {
+        self.positions
+            .iter()
+            .position(|&position| position >= index)
+            .unwrap_or(self.positions.len())
+    }
+}
+
+// ============================================================================
+// Masks
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MaskToken {
+    /// `#`
+    Digit,
+    /// `A`
+    Letter,
+    /// `*`
+    Alphanumeric,
+    Literal(char),
+}
+
+impl MaskToken {
+    fn accepts(self, ch: char) -> bool {
+        match self {
+            Self::Digit => ch.is_ascii_digit(),
+            Self::Letter => ch.is_alphabetic(),
+            Self::Alphanumeric => ch.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// A fixed input pattern.
+///
+/// `#` is a digit slot, `A` a letter slot and `*` a letter-or-digit slot; every
+/// other character is shown as-is. Escape slot characters with `\` to show them
+/// literally (`\#`). Literals appear as soon as the slot after them is filled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMask {
+    tokens: Vec<MaskToken>,
+}
+
+impl InputMask {
+    /// Parse a mask pattern
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(ch) = chars.next() {
+            tokens.push(match ch {
+                '#' => MaskToken::Digit,
+                'A' => MaskToken::Letter,
+                '*' => MaskToken::Alphanumeric,
+                '\\' => MaskToken::Literal(chars.next().unwrap_or('\\')),
+                ch => MaskToken::Literal(ch),
+            });
+        }
+        Self { tokens }
+    }
+
+    /// North American phone number: `(###) ###-####`
+    pub fn phone() -> Self {
+        Self::new("(###) ###-####")
+    }
+
+    /// Numeric date: `##/##/####`
+    pub fn date() -> Self {
+        Self::new("##/##/####")
+    }
+
+    /// Number of input slots
+    pub fn slot_count(&self) -> usize {
+        self.slots().count()
+    }
+
+    fn slots(&self) -> impl Iterator<Item = MaskToken> + '_ {
+        self.tokens
+            .iter()
+            .copied()
+            .filter(|token| !matches!(token, MaskToken::Literal(_)))
+    }
+
+    fn accept(&self, raw: &[char], index: usize, ch: char) -> Option<char> {
+        if raw.len() >= self.slot_count() {
+            return None;
+        }
+        // Characters after the insertion point shift one slot right and must
+        // still fit.
+        let mut shifted = raw.to_vec();
+        shifted.insert(index.min(raw.len()), ch);
+        shifted
+            .iter()
+            .zip(self.slots())
+            .all(|(&ch, slot)| slot.accepts(ch))
+            .then_some(ch)
+    }
+
+    fn layout(&self, raw: &str) -> FormattedText {
+        let raw: Vec<char> = raw.chars().collect();
+        let mut text = String::new();
+        let mut positions = Vec::with_capacity(raw.len());
+        let mut next = 0;
+
+        for (display_index, token) in self.tokens.iter().enumerate() {
+            if next >= raw.len() {
+                break;
+            }
+            match token {
+                MaskToken::Literal(ch) => text.push(*ch),
+                _ => {
+                    text.push(raw[next]);
+                    positions.push(display_index);
+                    next += 1;
+                }
+            }
+        }
+
+        FormattedText { text, positions }
+    }
+}
+
+// ============================================================================
+// Numbers
+// ============================================================================
+
+/// Decimal and digit-grouping characters of a locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberSeparators {
+    pub decimal: char,
+    pub group: char,
+}
+
+impl NumberSeparators {
+    /// `1,234.56` (English, Chinese, Japanese, ...)
+    pub const POINT_DECIMAL: Self = Self {
+        decimal: '.',
+        group: ',',
+    };
+    /// `1.234,56` (German, Spanish, Italian, ...)
+    pub const COMMA_DECIMAL: Self = Self {
+        decimal: ',',
+        group: '.',
+    };
+    /// `1 234,56` (French, Polish, Swedish, ...), grouped with a narrow no-break space
+    pub const SPACE_GROUPED: Self = Self {
+        decimal: ',',
+        group: '\u{202F}',
+    };
+    /// `1'234.56` (Swiss)
+    pub const APOSTROPHE_GROUPED: Self = Self {
+        decimal: '.',
+        group: '\'',
+    };
+
+    /// Separators for a BCP 47 language tag such as `"en-US"` or `"de"`.
+    ///
+    /// Unknown languages use [`Self::POINT_DECIMAL`].
+    pub fn for_locale(locale: &str) -> Self {
+        let mut parts = locale.split(['-', '_']);
+        let language = parts.next().unwrap_or("").to_ascii_lowercase();
+        let region = parts.next().unwrap_or("").to_ascii_uppercase();
+        match (language.as_str(), region.as_str()) {
+            ("de" | "it", "CH") | ("rm", _) => Self::APOSTROPHE_GROUPED,
+            ("fr" | "pl" | "sv" | "nb" | "no" | "fi" | "cs" | "sk" | "uk" | "ru", _) => {
+                Self::SPACE_GROUPED
+            }
+            (
+                "de" | "es" | "it" | "nl" | "pt" | "da" | "tr" | "id" | "el" | "ro" | "hr" | "sl",
+                _,
+            ) => Self::COMMA_DECIMAL,
+            _ => Self::POINT_DECIMAL,
+        }
+    }
+}
+
+impl Default for NumberSeparators {
+    fn default() -> Self {
+        Self::POINT_DECIMAL
+    }
+}
+
+/// A formatted number input.
+///
+/// The raw value is a plain number (`-1234.5`); the display groups digits and uses
+/// the locale's separators. Typing accepts digits, the decimal separator (when
+/// `decimal_places > 0`) and a leading `-` (unless `min` is zero or more).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberFormat {
+    /// Maximum digits after the decimal separator
+    pub decimal_places: usize,
+    /// Whether to group thousands
+    pub grouping: bool,
+    /// Decimal and grouping characters
+    pub separators: NumberSeparators,
+    /// Lowest accepted value (applied when the field loses focus or is submitted)
+    pub min: Option<f64>,
+    /// Highest accepted value (applied when the field loses focus or is submitted)
+    pub max: Option<f64>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_places: 0,
+            grouping: true,
+            separators: NumberSeparators::default(),
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Whole numbers with digit grouping
+    pub fn integer() -> Self {
+        Self::default()
+    }
+
+    /// Numbers with up to `decimal_places` fraction digits
+    pub fn decimal(decimal_places: usize) -> Self {
+        Self {
+            decimal_places,
+            ..Self::default()
+        }
+    }
+
+    /// Currency amounts (two decimal places) with the given separators.
+    ///
+    /// Show the currency symbol with the field's `prefix_text` / `suffix_text`.
+    pub fn currency(separators: NumberSeparators) -> Self {
+        Self {
+            decimal_places: 2,
+            separators,
+            ..Self::default()
+        }
+    }
+
+    /// Set the separators
+    pub fn separators(mut self, separators: NumberSeparators) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Enable or disable digit grouping
+    pub fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Set the lowest accepted value
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the highest accepted value
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    fn allows_negative(&self) -> bool {
+        self.min.is_none_or(|min| min < 0.0)
+    }
+
+    fn accept(&self, raw: &[char], index: usize, ch: char) -> Option<char> {
+        let index = index.min(raw.len());
+        let has_sign = raw.first() == Some(&'-');
+        // Nothing goes in front of the sign.
+        if has_sign && index == 0 {
+            return None;
+        }
+        let point = raw.iter().position(|&c| c == '.');
+
+        if ch.is_ascii_digit() {
+            let fraction_digits = point.map_or(0, |point| raw.len() - point - 1);
+            let in_fraction = point.is_some_and(|point| index > point);
+            return (!in_fraction || fraction_digits < self.decimal_places).then_some(ch);
+        }
+
+        let is_decimal_separator = ch == self.separators.decimal
+            // Accept `.` too, unless it is this locale's group separator.
+            || (ch == '.' && self.separators.group != '.');
+        if is_decimal_separator {
+            let digits_after = raw.len() - index;
+            return (self.decimal_places > 0
+                && point.is_none()
+                && digits_after <= self.decimal_places)
+                .then_some('.');
+        }
+
+        (ch == '-' && index == 0 && !has_sign && self.allows_negative()).then_some('-')
+    }
+
+    fn layout(&self, raw: &str) -> FormattedText {
+        let raw: Vec<char> = raw.chars().collect();
+        let sign_len = usize::from(raw.first() == Some(&'-'));
+        let integer_end = raw.iter().position(|&c| c == '.').unwrap_or(raw.len());
+
+        let mut text = String::new();
+        let mut positions = Vec::with_capacity(raw.len());
+        let mut display_len = 0;
+        for (i, &ch) in raw.iter().enumerate() {
+            let digits_left = integer_end.saturating_sub(i);
+            if self.grouping && i > sign_len && i < integer_end && digits_left % 3 == 0 {
+                text.push(self.separators.group);
+                display_len += 1;
+            }
+            text.push(if ch == '.' {
+                self.separators.decimal
+            } else {
+                ch
+            });
+            positions.push(display_len);
+            display_len += 1;
+        }
+
+        FormattedText { text, positions }
+    }
+
+    /// `raw` clamped to the bounds, or `None` if it is in range (or not a number)
+    pub fn clamp(&self, raw: &str) -> Option<String> {
+        let value: f64 = raw.parse().ok()?;
+        let clamped = value
+            .max(self.min.unwrap_or(f64::NEG_INFINITY))
+            .min(self.max.unwrap_or(f64::INFINITY));
+        (clamped != value).then(|| format!("{clamped:.*}", self.decimal_places))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_into(format: &InputFormat, text: &str) -> String {
+        format.parse(text)
+    }
+
+    #[test]
+    fn test_mask_layout() {
+        let phone = InputFormat::from(InputMask::phone());
+        assert_eq!(type_into(&phone, "(555) 123-4567 ext"), "5551234567");
+        assert_eq!(phone.format("555"), "(555");
+        assert_eq!(phone.format("5551"), "(555) 1");
+        assert_eq!(phone.format("5551234567"), "(555) 123-4567");
+        assert_eq!(phone.format(""), "");
+
+        let layout = phone.layout("5551");
+        assert_eq!(layout.raw_to_display(0), 1);
+        assert_eq!(layout.raw_to_display(3), 6);
+        assert_eq!(layout.raw_to_display(4), 7);
+        assert_eq!(layout.display_to_raw(5), 3);
+        assert_eq!(layout.display_to_raw(0), 0);
+    }
+
+    #[test]
+    fn test_mask_rejects_shifted_characters() {
+        let plate = InputMask::new("AA-##");
+        assert_eq!(plate.accept(&['A', 'B', '1'], 3, '2'), Some('2'));
+        assert_eq!(plate.accept(&['A', 'B', '1'], 3, 'C'), None);
+        // Inserting a letter at the front would push `B` into a digit slot.
+        assert_eq!(plate.accept(&['A', 'B'], 0, 'C'), None);
+        assert_eq!(plate.accept(&['A', 'B', '1', '2'], 4, '3'), None);
+    }
+
+    #[test]
+    fn test_number_layout() {
+        let format = InputFormat::from(NumberFormat::decimal(2));
+        assert_eq!(type_into(&format, "-1,234.567"), "-1234.56");
+        assert_eq!(format.format("-1234567.5"), "-1,234,567.5");
+        assert_eq!(format.format("123"), "123");
+
+        let layout = format.layout("1234");
+        assert_eq!(layout.text, "1,234");
+        assert_eq!(layout.raw_to_display(1), 2);
+        assert_eq!(layout.display_to_raw(1), 1);
+
+        let euro = InputFormat::from(NumberFormat::currency(NumberSeparators::COMMA_DECIMAL));
+        assert_eq!(type_into(&euro, "1.234,5"), "1234.5");
+        assert_eq!(euro.format("1234.5"), "1.234,5");
+    }
+
+    #[test]
+    fn test_number_bounds() {
+        let format = NumberFormat::integer().min(0.0).max(100.0);
+        assert_eq!(format.accept(&[], 0, '-'), None);
+        assert_eq!(format.accept(&[], 0, '.'), None);
+        assert_eq!(format.clamp("250"), Some("100".to_string()));
+        assert_eq!(format.clamp("50"), None);
+        assert_eq!(format.clamp(""), None);
+
+        let money = NumberFormat::currency(NumberSeparators::POINT_DECIMAL).min(1.0);
+        assert_eq!(money.clamp("0.5"), Some("1.00".to_string()));
+    }
+
+    #[test]
+    fn test_separators_for_locale() {
+        assert_eq!(
+            NumberSeparators::for_locale("en-US"),
+            NumberSeparators::POINT_DECIMAL
+        );
+        assert_eq!(
+            NumberSeparators::for_locale("de_DE"),
+            NumberSeparators::COMMA_DECIMAL
+        );
+        assert_eq!(
+            NumberSeparators::for_locale("de-CH"),
+            NumberSeparators::APOSTROPHE_GROUPED
+        );
+        assert_eq!(
+            NumberSeparators::for_locale("fr"),
+            NumberSeparators::SPACE_GROUPED
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::focus::FocusTrap;
 use crate::theme::MaterialTheme;
 
 use super::{ScaffoldEntities, ScaffoldTestIds};
@@ -11,6 +12,8 @@ pub struct ModalDrawerScaffold {
     pub root_padding_px: f32,
     pub content_padding_px: f32,
     pub scrim_color: Color,
+    /// Confine keyboard focus to the drawer while it is visible
+    pub trap_focus: bool,
     pub test_ids: ScaffoldTestIds,
 }
 
@@ -21,6 +24,7 @@ impl Default for ModalDrawerScaffold {
             root_padding_px: 0.0,
             content_padding_px: 0.0,
             scrim_color: Color::srgba(0.0, 0.0, 0.0, 0.32),
+            trap_focus: true,
             test_ids: ScaffoldTestIds::default(),
         }
     }
@@ -28,6 +32,9 @@ impl Default for ModalDrawerScaffold {
 
 /// Spawn a modal drawer scaffold. The drawer is always shown in this helper; show/hide
 /// behavior can be layered by toggling `Visibility` on the returned entities.
+///
+/// With `trap_focus`, the drawer carries a [`FocusTrap`] that is engaged while it is
+/// visible, so hiding the navigation entity hands focus back to the content.
 pub fn spawn_modal_drawer_scaffold(
     parent: &mut ChildSpawnerCommands,
     theme: &MaterialTheme,
@@ -78,6 +85,7 @@ pub fn spawn_modal_drawer_scaffold(
                     BackgroundColor(theme.surface_container_low),
                     config.test_ids.navigation.clone(),
                 ))
+                .insert_if(FocusTrap::while_visible(), || config.trap_focus)
                 .with_children(drawer_children)
                 .id();
 
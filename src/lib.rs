@@ -11,7 +11,8 @@
 //! - **Theme System**: Complete MD3 color scheme with light/dark mode support
 //! - **Components**: Button, Card, Checkbox, Dialog, Divider, FAB, List, Menu,
 //!   Progress, Radio, Ripple, Select, Slider, Switch, Tabs, TextField
-//! - **Accessibility**: Built-in support for focus rings
+//! - **Forms**: Input validation and form containers with dirty / valid tracking
+//! - **Accessibility**: Focus rings, Tab / Shift+Tab focus traversal and gamepad navigation
 //! - **Customization**: Token-based styling system for easy theming
 //!
 //! ## Quick Start
@@ -73,15 +74,30 @@ pub mod tokens;
 /// Elevation and shadow utilities
 pub mod elevation;
 
-/// Focus ring component for accessibility
+/// Focus ring and keyboard focus traversal
 pub mod focus;
 
+/// Gamepad / controller focus navigation
+pub mod gamepad;
+
 /// Ripple effect component for touch feedback
 pub mod ripple;
 
 /// Telemetry and test automation support
 pub mod telemetry;
 
+/// Validation rules for text input
+pub mod validation;
+
+/// Input masks and number formats for text input
+pub mod input_format;
+
+/// Fuzzy matching for filtering and ranking text
+pub mod fuzzy;
+
+/// Anchored positioning for menus, dropdowns and tooltips
+pub mod popover;
+
 // ============================================================================
 // Component modules
 // ============================================================================
@@ -176,6 +192,9 @@ pub mod search;
 /// Animation and transformation system
 pub mod animation;
 
+/// Form container grouping inputs
+pub mod form;
+
 // ============================================================================
 // Prelude
 // ============================================================================
@@ -192,7 +211,21 @@ pub mod prelude {
     // Core
     pub use crate::elevation::{Elevation, ElevationShadow};
     pub use crate::focus::{
-        create_native_focus_outline, FocusGained, FocusLost, FocusPlugin, FocusRing, Focusable,
+        create_native_focus_outline, FocusAppExt, FocusGained, FocusLost, FocusManager,
+        FocusPlugin, FocusRing, FocusTrap, Focusable, FocusableComponent, InitialFocus,
+        OpenOverlay, OverlayComponent,
+    };
+    pub use crate::fuzzy::{
+        fold, fold_char, fuzzy_filter, fuzzy_match, highlight_segments, FuzzyMatch, FuzzyPlugin,
+        MatchHighlight,
+    };
+    pub use crate::gamepad::{
+        find_spatial_neighbor, GamepadNavigationPlugin, GamepadNavigationSettings,
+        NavigationDirection,
+    };
+    pub use crate::input_format::{InputFormat, InputMask, NumberFormat, NumberSeparators};
+    pub use crate::popover::{
+        Popover, PopoverAlign, PopoverAnchor, PopoverPlacement, PopoverPlugin, PopoverSide,
     };
     pub use crate::ripple::{Ripple, RippleHost, RipplePlugin, SpawnRipple};
     pub use crate::telemetry::{
@@ -201,6 +234,10 @@ pub mod prelude {
     pub use crate::theme::{ColorScheme, MaterialTheme};
     pub use crate::tokens::{CornerRadius, Duration, Easing, Spacing};
     pub use crate::typography::Typography;
+    pub use crate::validation::{
+        validate_all, Email, MaxLength, MinLength, NumberRange, Pattern, PatternError, Required,
+        Url, ValidationTrigger, Validator,
+    };
 
     // Color System
     pub use crate::color::{Hct, MaterialColorScheme, TonalPalette};
@@ -235,10 +272,17 @@ pub mod prelude {
         SpawnLoadingIndicatorChild, LOADING_INDICATOR_SIZE,
     };
 
+    // Form
+    pub use crate::form::{
+        FormField, FormPlugin, FormSubmitButton, FormSubmitEvent, FormValue, FormValues,
+        MaterialForm, ResetForm, SubmitForm,
+    };
+
     // Search
     pub use crate::search::{
-        MaterialSearchBar, SearchBarBuilder, SearchBarClickEvent, SearchPlugin, SearchQueryEvent,
-        SpawnSearchBarChild, SEARCH_BAR_HEIGHT,
+        MaterialSearchBar, SearchBarBuilder, SearchBarClickEvent, SearchBarInput, SearchPlugin,
+        SearchQueryEvent, SearchSubmitEvent, SearchView, SearchViewItem, SearchViewItemKind,
+        SearchViewRow, SpawnSearchBarChild, SEARCH_BAR_HEIGHT, SEARCH_QUERY_DEBOUNCE,
     };
 
     // Animation
@@ -291,10 +335,12 @@ pub mod prelude {
 
     // Text Field
     pub use crate::text_field::{
-        spawn_text_field_control, spawn_text_field_control_with, MaterialTextField,
-        SpawnTextFieldChild, TextFieldBuilder, TextFieldChangeEvent, TextFieldInput,
-        TextFieldLabel, TextFieldPlugin, TextFieldSubmitEvent, TextFieldSupportingText,
-        TextFieldVariant, TEXT_FIELD_HEIGHT, TEXT_FIELD_MIN_WIDTH,
+        spawn_text_field_control, spawn_text_field_control_with, CaretMotion, MaterialTextField,
+        SpawnTextFieldChild, TextFieldBuilder, TextFieldCaret, TextFieldChangeEvent,
+        TextFieldHistory, TextFieldInput, TextFieldLabel, TextFieldPlugin, TextFieldPreedit,
+        TextFieldSelection, TextFieldSubmitEvent, TextFieldSupportingText,
+        TextFieldValidationEvent, TextFieldVariant, TEXT_FIELD_HEIGHT, TEXT_FIELD_LINE_HEIGHT,
+        TEXT_FIELD_MIN_WIDTH,
     };
 
     // Progress
@@ -308,31 +354,40 @@ pub mod prelude {
     // Dialog
     pub use crate::dialog::{
         create_dialog_scrim, create_dialog_scrim_for, DialogActions, DialogBuilder,
-        DialogCloseEvent, DialogConfirmEvent, DialogContent, DialogHeadline, DialogOpenEvent,
-        DialogPlugin, DialogScrim, DialogScrimFor, DialogType, MaterialDialog, SpawnDialogChild,
-        DIALOG_MAX_WIDTH, DIALOG_MIN_WIDTH,
+        DialogCloseEvent, DialogClosedEvent, DialogConfirmEvent, DialogContent, DialogHandle,
+        DialogHeadline, DialogOpenEvent, DialogPlugin, DialogResponse, DialogResult, DialogScrim,
+        DialogScrimFor, DialogType, MaterialDialog, QuickDialog, QuickDialogAction,
+        QuickDialogBuilder, QuickDialogKind, SpawnDialogChild, SpawnQuickDialog,
+        DIALOG_ENTER_SCALE, DIALOG_MAX_WIDTH, DIALOG_MIN_WIDTH,
     };
 
     // DateTime Picker
     pub use crate::datetime_picker::{
-        Date, DateTimePickerBuilder, DateTimePickerCancelEvent, DateTimePickerPlugin,
-        DateTimePickerSubmitEvent, MaterialDateTimePicker, SpawnDateTimePickerChild, TimeFormat,
-        Weekday,
+        Date, DatePickerLayout, DateRange, DateSelectionMode, DateTimePickerBuilder,
+        DateTimePickerCancelEvent, DateTimePickerPlugin, DateTimePickerSubmitEvent,
+        MaterialDateTimePicker, SpawnDateTimePickerChild, TimeFormat, Weekday,
+        DATE_PICKER_MAX_MONTHS,
     };
 
     // List
     pub use crate::list::{
         create_list_divider, ListBuilder, ListDivider, ListItemBody, ListItemBuilder,
-        ListItemClickEvent, ListItemHeadline, ListItemLeading, ListItemSupportingText,
-        ListItemTrailing, ListItemVariant, ListPlugin, ListSelectionMode, MaterialList,
-        MaterialListItem, ScrollableList, SpawnListChild,
+        ListItemClickEvent, ListItemDismissEvent, ListItemDragHandle, ListItemHeadline,
+        ListItemLeading, ListItemSupportingText, ListItemTrailing, ListItemVariant, ListPlugin,
+        ListReorderEvent, ListSelectionChangedEvent, ListSelectionMode, ListSwipeAction,
+        ListSwipeBackground, MaterialList, MaterialListItem, ScrollableList, SetListSelection,
+        SpawnListChild, VirtualList, VirtualListRow, VirtualListSpacer, VirtualRowFn,
+        LIST_REORDER_SCROLL_EDGE, LIST_REORDER_SCROLL_SPEED, LIST_SWIPE_DISMISS_THRESHOLD,
+        LIST_SWIPE_SLOP,
     };
 
     // Menu
     pub use crate::menu::{
-        create_menu_divider, MaterialMenu, MaterialMenuItem, MenuAnchor, MenuBuilder,
-        MenuCloseEvent, MenuDivider, MenuItemBuilder, MenuItemSelectEvent, MenuOpenEvent,
-        MenuPlugin, SpawnMenuChild, MENU_ITEM_HEIGHT, MENU_MAX_WIDTH, MENU_MIN_WIDTH,
+        create_menu_divider, ContextMenu, MaterialMenu, MaterialMenuItem, MenuAnchor, MenuBuilder,
+        MenuCloseEvent, MenuDivider, MenuItemBuilder, MenuItemLabel, MenuItemSelectEvent,
+        MenuOpenEvent, MenuPlugin, SpawnMenuChild, CONTEXT_MENU_LONG_PRESS,
+        CONTEXT_MENU_TOUCH_SLOP, MENU_ITEM_HEIGHT, MENU_MAX_WIDTH, MENU_MIN_WIDTH,
+        SUBMENU_HOVER_DELAY, SUBMENU_Z_INDEX,
     };
 
     // Tabs
@@ -351,9 +406,12 @@ pub mod prelude {
 
     // Select
     pub use crate::select::{
-        MaterialSelect, SelectBuilder, SelectChangeEvent, SelectContainer, SelectDisplayText,
-        SelectDropdown, SelectOption, SelectOptionItem, SelectPlugin, SelectTrigger, SelectVariant,
-        SpawnSelectChild, SELECT_HEIGHT, SELECT_OPTION_HEIGHT,
+        MaterialSelect, MultiSelectDisplay, SelectBuilder, SelectChangeEvent, SelectChip,
+        SelectChipRow, SelectComboboxInput, SelectContainer, SelectCustomValueEvent,
+        SelectDisplayText, SelectDropdown, SelectFilterInput, SelectMultiChangeEvent, SelectOption,
+        SelectOptionCheckbox, SelectOptionItem, SelectPlugin, SelectTrigger, SelectVariant,
+        SpawnSelectChild, SELECT_DROPDOWN_GAP, SELECT_HEIGHT, SELECT_OPTION_HEIGHT,
+        SELECT_TYPE_AHEAD_TIMEOUT,
     };
 
     // Adaptive Layout
@@ -377,7 +435,8 @@ pub mod prelude {
     // Motion
     pub use crate::motion::{
         ease_emphasized, ease_emphasized_accelerate, ease_emphasized_decelerate, ease_standard,
-        ease_standard_accelerate, ease_standard_decelerate, MotionPlugin, SpringConfig, StateLayer,
+        ease_standard_accelerate, ease_standard_decelerate, MotionPlugin, OpenTransition,
+        SpringConfig, StateLayer,
     };
 
     // Snackbar
@@ -395,9 +454,10 @@ pub mod prelude {
 
     // App Bar
     pub use crate::app_bar::{
-        AppBarPlugin, BottomAppBar, BottomAppBarBuilder, SpawnAppBarChild, TopAppBar,
-        TopAppBarBuilder, TopAppBarVariant, BOTTOM_APP_BAR_HEIGHT, TOP_APP_BAR_HEIGHT_LARGE,
-        TOP_APP_BAR_HEIGHT_MEDIUM, TOP_APP_BAR_HEIGHT_SMALL,
+        AppBarLargeTitle, AppBarPlugin, BottomAppBar, BottomAppBarBuilder, SpawnAppBarChild,
+        TopAppBar, TopAppBarBuilder, TopAppBarScrollBehavior, TopAppBarVariant,
+        BOTTOM_APP_BAR_HEIGHT, TOP_APP_BAR_HEIGHT_LARGE, TOP_APP_BAR_HEIGHT_MEDIUM,
+        TOP_APP_BAR_HEIGHT_SMALL, TOP_APP_BAR_SNAP_DELAY,
     };
 
     // Badge
@@ -460,6 +520,8 @@ impl Plugin for MaterialUiPlugin {
             ripple::RipplePlugin,
             icons::icon::IconPlugin,
             icons::MaterialIconsPlugin,
+            fuzzy::FuzzyPlugin,
+            popover::PopoverPlugin,
         ));
 
         // Component plugins
@@ -495,6 +557,7 @@ impl Plugin for MaterialUiPlugin {
             loading_indicator::LoadingIndicatorPlugin,
             search::SearchPlugin,
             animation::AnimationPlugin,
+            form::FormPlugin,
         ));
 
         // Adaptive layout
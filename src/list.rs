@@ -3,15 +3,23 @@
 //! Lists are continuous, vertical indexes of text and images.
 //! Reference: <https://m3.material.io/components/lists/overview>
 
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::sync::Arc;
+
 use bevy::prelude::*;
-use bevy::ui::ScrollPosition;
+use bevy::ui::{ScrollPosition, UiGlobalTransform, Val2};
+use bevy::window::PrimaryWindow;
 
 use crate::{
-    icons::{icon_by_name, IconStyle, MaterialIcon},
+    elevation::Elevation,
+    focus::{FocusAppExt, FocusManager, FocusableComponent},
+    icons::{icon_by_name, IconStyle, MaterialIcon, ICON_DRAG_HANDLE},
+    motion::AnimatedValue,
     ripple::RippleHost,
-    scroll::ScrollContainerBuilder,
+    scroll::{ScrollContainer, ScrollContainerBuilder},
     theme::{blend_state_layer, MaterialTheme},
-    tokens::Spacing,
+    tokens::{Duration, Easing, Spacing},
 };
 
 fn resolve_icon_codepoint(icon: &str) -> Option<char> {
@@ -32,15 +40,25 @@ pub struct ListPlugin;
 
 impl Plugin for ListPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<ListItemClickEvent>().add_systems(
-            Update,
-            (
-                list_item_interaction_system,
-                list_selection_system,
-                list_item_style_system,
-                list_item_text_style_system,
-            ),
-        );
+        app.add_message::<ListItemClickEvent>()
+            .add_message::<ListSelectionChangedEvent>()
+            .add_message::<SetListSelection>()
+            .add_message::<ListReorderEvent>()
+            .add_message::<ListItemDismissEvent>()
+            .register_focusable::<MaterialListItem>()
+            .add_systems(
+                Update,
+                (
+                    list_item_interaction_system,
+                    list_item_keyboard_system,
+                    list_selection_system,
+                    list_item_style_system,
+                    list_item_text_style_system,
+                    (virtual_list_setup_system, virtual_list_rows_system).chain(),
+                    list_drag_handle_system,
+                    (list_reorder_system, list_swipe_system).chain(),
+                ),
+            );
     }
 }
 
@@ -53,6 +71,9 @@ pub enum ListSelectionMode {
     /// Exactly one item is selected at a time.
     Single,
     /// Multiple items may be selected.
+    ///
+    /// Clicking toggles an item, Shift+click selects the range from the last clicked
+    /// item, and Ctrl+A selects every item.
     Multi,
 }
 
@@ -83,24 +104,60 @@ impl ListItemVariant {
 #[derive(Component, Default)]
 pub struct MaterialList {
     pub selection_mode: ListSelectionMode,
+    /// Whether items can be dragged by their handle to a new position
+    pub reorderable: bool,
+    /// Action revealed behind items swiped sideways; swiping far enough dismisses them
+    pub swipe_action: Option<ListSwipeAction>,
+    /// Item that Shift ranges start from
+    anchor: Option<usize>,
+    selected_indices: Vec<usize>,
+    selected_keys: Vec<String>,
+    drag: Option<ListDrag>,
+    swipe: Option<ListSwipe>,
 }
 
 impl MaterialList {
     /// Create a new list
     pub fn new() -> Self {
-        Self {
-            selection_mode: ListSelectionMode::None,
-        }
+        Self::default()
     }
 
     pub fn with_selection_mode(mut self, mode: ListSelectionMode) -> Self {
         self.selection_mode = mode;
         self
     }
+
+    /// Let items be dragged by their handle to reorder them
+    pub fn with_reorder(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Let items be swiped sideways to dismiss them
+    pub fn with_swipe_to_dismiss(mut self, action: ListSwipeAction) -> Self {
+        self.swipe_action = Some(action);
+        self
+    }
+
+    /// Whether an item is being dragged
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Positions of the selected items (row indices in virtual lists), as of the last
+    /// [`ListSelectionChangedEvent`]
+    pub fn selected_indices(&self) -> &[usize] {
+        &self.selected_indices
+    }
+
+    /// Keys of the selected items, as of the last [`ListSelectionChangedEvent`]
+    pub fn selected_keys(&self) -> &[String] {
+        &self.selected_keys
+    }
 }
 
 /// Material list item component
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct MaterialListItem {
     /// Item variant
     pub variant: ListItemVariant,
@@ -122,6 +179,8 @@ pub struct MaterialListItem {
     pub leading_avatar: Option<String>,
     /// Leading video thumbnail URL
     pub leading_video: Option<String>,
+    /// Key identifying the item in selection events and [`SetListSelection`]
+    pub key: Option<String>,
     /// Interaction states
     pub pressed: bool,
     pub hovered: bool,
@@ -141,6 +200,7 @@ impl MaterialListItem {
             trailing_icon: None,
             leading_avatar: None,
             leading_video: None,
+            key: None,
             pressed: false,
             hovered: false,
         }
@@ -182,6 +242,12 @@ impl MaterialListItem {
         self
     }
 
+    /// Set the selection key
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
     /// Set disabled state
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -257,67 +323,416 @@ impl MaterialListItem {
     }
 }
 
+impl FocusableComponent for MaterialListItem {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event when list item is clicked
 #[derive(Event, bevy::prelude::Message)]
 pub struct ListItemClickEvent {
     pub entity: Entity,
 }
 
+/// Sent when the selection of a list changes
+#[derive(Event, bevy::prelude::Message, Debug, Clone)]
+pub struct ListSelectionChangedEvent {
+    /// The list entity
+    pub list: Entity,
+    /// Positions of all selected items (row indices in virtual lists)
+    pub indices: Vec<usize>,
+    /// Keys of all selected items that have one
+    pub keys: Vec<String>,
+}
+
+/// Replace the selection of a list with the items that have the given keys
+#[derive(Event, bevy::prelude::Message, Debug, Clone)]
+pub struct SetListSelection {
+    pub list: Entity,
+    pub keys: Vec<String>,
+}
+
+/// A change to a list's selection
+enum SelectionRequest {
+    /// The item was clicked or activated
+    Click { extend: bool, toggle: bool },
+    /// Up / Down was pressed on the focused item
+    Move { forward: bool, extend: bool },
+    /// Ctrl+A
+    SelectAll,
+    /// Select the items with these keys
+    Keys(Vec<String>),
+}
+
+/// Apply a click on `index` to the selection of a list
+fn click_selection(
+    mode: ListSelectionMode,
+    selection: &mut BTreeSet<usize>,
+    anchor: &mut Option<usize>,
+    index: usize,
+    extend: bool,
+    toggle: bool,
+    is_enabled: impl Fn(usize) -> bool,
+) {
+    match mode {
+        ListSelectionMode::None => {}
+        ListSelectionMode::Single => {
+            selection.clear();
+            selection.insert(index);
+            *anchor = Some(index);
+        }
+        ListSelectionMode::Multi => match *anchor {
+            Some(start) if extend => {
+                if !toggle {
+                    selection.clear();
+                }
+                selection.extend((start.min(index)..=start.max(index)).filter(|i| is_enabled(*i)));
+            }
+            _ => {
+                if !selection.remove(&index) {
+                    selection.insert(index);
+                }
+                *anchor = Some(index);
+            }
+        },
+    }
+}
+
+/// Apply a keyboard move to `index` to the selection of a list
+fn move_selection(
+    mode: ListSelectionMode,
+    selection: &mut BTreeSet<usize>,
+    anchor: &mut Option<usize>,
+    from: usize,
+    index: usize,
+    extend: bool,
+    is_enabled: impl Fn(usize) -> bool,
+) {
+    match mode {
+        ListSelectionMode::None => {}
+        ListSelectionMode::Single => {
+            selection.clear();
+            selection.insert(index);
+            *anchor = Some(index);
+        }
+        ListSelectionMode::Multi if extend => {
+            let start = *anchor.get_or_insert(from);
+            selection.clear();
+            selection.extend((start.min(index)..=start.max(index)).filter(|i| is_enabled(*i)));
+        }
+        ListSelectionMode::Multi => *anchor = Some(index),
+    }
+}
+
+/// Find the list an item belongs to
+fn owning_list(
+    item: Entity,
+    rows: &Query<&VirtualListRow>,
+    parents: &Query<&ChildOf>,
+    is_list: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    if let Ok(row) = rows.get(item) {
+        return Some(row.list);
+    }
+    // Walk up to the nearest list, through wrappers such as scroll content.
+    let mut current = Some(item);
+    for _ in 0..32 {
+        let entity = current?;
+        if is_list(entity) {
+            return Some(entity);
+        }
+        current = parents.get(entity).ok().map(ChildOf::parent);
+    }
+    None
+}
+
+/// Items of a (non-virtual) list, in layout order
+fn list_item_entities(
+    list: Entity,
+    children: &Query<&Children>,
+    is_item: impl Fn(Entity) -> bool,
+) -> Vec<Entity> {
+    fn collect(
+        node: Entity,
+        children: &Query<&Children>,
+        is_item: &impl Fn(Entity) -> bool,
+        out: &mut Vec<Entity>,
+    ) {
+        let Ok(node_children) = children.get(node) else {
+            return;
+        };
+        for child in node_children.iter() {
+            if is_item(child) {
+                out.push(child);
+            } else {
+                collect(child, children, is_item, out);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    collect(list, children, &is_item, &mut out);
+    out
+}
+
+/// Next enabled position after (or before) `from`, without wrapping
+fn step_enabled(
+    from: usize,
+    len: usize,
+    forward: bool,
+    is_enabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if forward {
+        (from + 1..len).find(|i| is_enabled(*i))
+    } else {
+        (0..from.min(len)).rev().find(|i| is_enabled(*i))
+    }
+}
+
+/// System to select list items from clicks, the keyboard and [`SetListSelection`]
+///
+/// Up / Down move focus between items (Shift extends the selection), and Ctrl/Cmd+A
+/// selects every item of a multi-select list.
 fn list_selection_system(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut focus: ResMut<FocusManager>,
     mut click_events: MessageReader<ListItemClickEvent>,
+    mut set_events: MessageReader<SetListSelection>,
+    mut changed_events: MessageWriter<ListSelectionChangedEvent>,
     parents: Query<&ChildOf>,
-    lists: Query<&MaterialList>,
-    children_query: Query<&Children>,
+    children: Query<&Children>,
+    rows: Query<&VirtualListRow>,
+    mut lists: Query<(
+        &mut MaterialList,
+        Option<&mut VirtualList>,
+        Option<(&mut ScrollPosition, &ScrollContainer, &Node)>,
+    )>,
     mut items: Query<&mut MaterialListItem>,
 ) {
+    let pressed = |codes: [KeyCode; 2]| keys.as_ref().is_some_and(|keys| keys.any_pressed(codes));
+    let extend = pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let toggle = pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+        || pressed([KeyCode::SuperLeft, KeyCode::SuperRight]);
+
+    // Focus virtual rows that were scrolled into view for the keyboard.
+    for (_, virtual_list, _) in lists.iter_mut() {
+        let Some(mut virtual_list) = virtual_list else {
+            continue;
+        };
+        if let Some(index) = virtual_list.pending_focus {
+            if let Some(row) = virtual_list.row_entity(index) {
+                focus.focus_visible(row);
+                virtual_list.pending_focus = None;
+            }
+        }
+    }
+
+    let is_list = |entity| lists.contains(entity);
+    let mut requests: Vec<(Entity, Entity, SelectionRequest)> = Vec::new();
+
     for event in click_events.read() {
-        // Find the nearest ancestor that is a MaterialList.
-        let mut current = Some(event.entity);
-        let mut list_entity = None;
-        for _ in 0..32 {
-            let Some(e) = current else { break };
-            if lists.get(e).is_ok() {
-                list_entity = Some(e);
-                break;
+        if let Some(list) = owning_list(event.entity, &rows, &parents, is_list) {
+            requests.push((
+                list,
+                event.entity,
+                SelectionRequest::Click { extend, toggle },
+            ));
+        }
+    }
+
+    if let Some(focused) = focus.focused().filter(|entity| items.contains(*entity)) {
+        if let Some(list) = owning_list(focused, &rows, &parents, is_list) {
+            let forward = keys
+                .as_ref()
+                .is_some_and(|keys| keys.just_pressed(KeyCode::ArrowDown));
+            let backward = keys
+                .as_ref()
+                .is_some_and(|keys| keys.just_pressed(KeyCode::ArrowUp));
+            if forward != backward {
+                requests.push((list, focused, SelectionRequest::Move { forward, extend }));
+            }
+            if toggle
+                && keys
+                    .as_ref()
+                    .is_some_and(|keys| keys.just_pressed(KeyCode::KeyA))
+            {
+                requests.push((list, focused, SelectionRequest::SelectAll));
             }
-            current = parents.get(e).ok().map(|p| p.0);
         }
+    }
+
+    for event in set_events.read() {
+        requests.push((
+            event.list,
+            event.list,
+            SelectionRequest::Keys(event.keys.clone()),
+        ));
+    }
 
-        let Some(list_entity) = list_entity else {
+    for (list_entity, item_entity, request) in requests {
+        let Ok((mut list, mut virtual_list, scroll)) = lists.get_mut(list_entity) else {
             continue;
         };
-        let Ok(list) = lists.get(list_entity) else {
-            continue;
+
+        // Virtual lists select by row index; other lists by item position.
+        let entities = match virtual_list {
+            Some(_) => Vec::new(),
+            None => list_item_entities(list_entity, &children, |entity| items.contains(entity)),
+        };
+        let len = match virtual_list.as_deref() {
+            Some(virtual_list) => virtual_list.row_count(),
+            None => entities.len(),
+        };
+        let build_row = virtual_list.as_deref().map(|list| list.build_row.clone());
+        let list_items: Vec<MaterialListItem> = entities
+            .iter()
+            .filter_map(|entity| items.get(*entity).ok().cloned())
+            .collect();
+        let item_at = |index: usize| -> Option<MaterialListItem> {
+            match &build_row {
+                Some(build_row) => Some(build_row(index).item).filter(|_| index < len),
+                None => list_items.get(index).cloned(),
+            }
+        };
+        let is_enabled = |index: usize| item_at(index).is_some_and(|item| !item.disabled);
+        let position = match virtual_list.as_deref() {
+            Some(_) => rows.get(item_entity).ok().map(|row| row.index),
+            None => entities.iter().position(|entity| *entity == item_entity),
         };
 
-        match list.selection_mode {
-            ListSelectionMode::None => {}
-            ListSelectionMode::Multi => {
-                if let Ok(mut clicked) = items.get_mut(event.entity) {
-                    clicked.selected = !clicked.selected;
-                }
+        let before: BTreeSet<usize> = match virtual_list.as_deref() {
+            Some(virtual_list) => virtual_list.selected.clone(),
+            None => (0..len)
+                .filter(|index| list_items[*index].selected)
+                .collect(),
+        };
+        let mut selection = before.clone();
+        let mut anchor = list.anchor;
+        let mode = list.selection_mode;
+
+        match request {
+            SelectionRequest::Click { extend, toggle } => {
+                let Some(index) = position else { continue };
+                click_selection(
+                    mode,
+                    &mut selection,
+                    &mut anchor,
+                    index,
+                    extend,
+                    toggle,
+                    is_enabled,
+                );
             }
-            ListSelectionMode::Single => {
-                // Select the clicked item and clear any other selected items in this list.
-                // Traverse the list subtree to support wrappers (e.g., scroll content).
-                let mut stack: Vec<Entity> = vec![list_entity];
-                while let Some(node) = stack.pop() {
-                    if let Ok(children) = children_query.get(node) {
-                        for child in children.iter() {
-                            if let Ok(mut item) = items.get_mut(child) {
-                                item.selected = child == event.entity;
+            SelectionRequest::Move { forward, extend } => {
+                let Some(from) = position else { continue };
+                let Some(target) = step_enabled(from, len, forward, is_enabled) else {
+                    continue;
+                };
+                move_selection(
+                    mode,
+                    &mut selection,
+                    &mut anchor,
+                    from,
+                    target,
+                    extend,
+                    is_enabled,
+                );
+
+                match virtual_list.as_deref_mut() {
+                    Some(virtual_list) => {
+                        match virtual_list.row_entity(target) {
+                            Some(row) => focus.focus_visible(row),
+                            None => virtual_list.pending_focus = Some(target),
+                        }
+                        // Keep the focused row in view.
+                        if let Some((mut scroll, container, node)) = scroll {
+                            let top = virtual_list.row_offset(target) + Spacing::SMALL;
+                            let bottom = top + virtual_list.row_height;
+                            let viewport = virtual_list_viewport(container, node);
+                            if top < scroll.y {
+                                scroll.y = top;
+                            } else if viewport > 0.0 && bottom > scroll.y + viewport {
+                                scroll.y = bottom - viewport;
                             }
-                            stack.push(child);
                         }
                     }
+                    None => focus.focus_visible(entities[target]),
+                }
+            }
+            SelectionRequest::SelectAll => {
+                if mode == ListSelectionMode::Multi {
+                    selection = (0..len).filter(|index| is_enabled(*index)).collect();
                 }
+            }
+            SelectionRequest::Keys(keys) => {
+                if mode == ListSelectionMode::None {
+                    continue;
+                }
+                selection = (0..len)
+                    .filter(|index| {
+                        item_at(*index)
+                            .and_then(|item| item.key)
+                            .is_some_and(|key| keys.contains(&key))
+                    })
+                    .collect();
+                if mode == ListSelectionMode::Single {
+                    selection = selection.into_iter().take(1).collect();
+                }
+            }
+        }
+
+        list.anchor = anchor;
+        if selection == before {
+            continue;
+        }
 
-                // If the clicked entity isn't under the list (unexpected), still force it selected.
-                if let Ok(mut clicked) = items.get_mut(event.entity) {
-                    clicked.selected = true;
+        let selected_keys: Vec<String> = selection
+            .iter()
+            .filter_map(|index| item_at(*index).and_then(|item| item.key))
+            .collect();
+
+        // The rows of virtual lists pick the change up from the model.
+        match virtual_list.as_deref_mut() {
+            Some(virtual_list) => virtual_list.selected = selection.clone(),
+            None => {
+                for (index, entity) in entities.iter().enumerate() {
+                    if let Ok(mut item) = items.get_mut(*entity) {
+                        let selected = selection.contains(&index);
+                        if item.selected != selected {
+                            item.selected = selected;
+                        }
+                    }
                 }
             }
         }
+
+        list.selected_indices = selection.into_iter().collect();
+        list.selected_keys = selected_keys.clone();
+        changed_events.write(ListSelectionChangedEvent {
+            list: list_entity,
+            indices: list.selected_indices.clone(),
+            keys: selected_keys,
+        });
+    }
+}
+
+/// System to activate the focused list item with Space / Enter
+fn list_item_keyboard_system(
+    focus: Res<FocusManager>,
+    items: Query<&MaterialListItem>,
+    mut click_events: MessageWriter<ListItemClickEvent>,
+) {
+    if !focus.activation_requested() {
+        return;
+    }
+
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+
+    if items.get(entity).is_ok_and(|item| !item.disabled) {
+        click_events.write(ListItemClickEvent { entity });
     }
 }
 
@@ -413,6 +828,10 @@ pub struct ListBuilder {
     show_scrollbar: bool,
     /// Selection behavior
     selection_mode: ListSelectionMode,
+    /// Whether items can be reordered by dragging
+    reorderable: bool,
+    /// Swipe-to-dismiss action
+    swipe_action: Option<ListSwipeAction>,
 }
 
 impl ListBuilder {
@@ -422,6 +841,8 @@ impl ListBuilder {
             max_height: None,
             show_scrollbar: true,
             selection_mode: ListSelectionMode::None,
+            reorderable: false,
+            swipe_action: None,
         }
     }
 
@@ -431,6 +852,27 @@ impl ListBuilder {
         self
     }
 
+    /// Give items a drag handle to reorder them
+    pub fn reorderable(mut self) -> Self {
+        self.reorderable = true;
+        self
+    }
+
+    /// Let items be swiped sideways to dismiss them
+    pub fn swipe_to_dismiss(mut self, action: ListSwipeAction) -> Self {
+        self.swipe_action = Some(action);
+        self
+    }
+
+    fn material_list(&self) -> MaterialList {
+        MaterialList {
+            selection_mode: self.selection_mode,
+            reorderable: self.reorderable,
+            swipe_action: self.swipe_action.clone(),
+            ..default()
+        }
+    }
+
     /// Set maximum height (enables scrolling)
     pub fn max_height(mut self, height: f32) -> Self {
         self.max_height = Some(height);
@@ -459,7 +901,7 @@ impl ListBuilder {
     /// Build the list bundle (non-scrollable)
     pub fn build(self) -> impl Bundle {
         (
-            MaterialList::new().with_selection_mode(self.selection_mode),
+            self.material_list(),
             Node {
                 flex_direction: FlexDirection::Column,
                 width: Val::Percent(100.0),
@@ -474,7 +916,7 @@ impl ListBuilder {
     pub fn build_scrollable(self) -> impl Bundle {
         let height = self.max_height.map(Val::Px).unwrap_or(Val::Auto);
         (
-            MaterialList::new().with_selection_mode(self.selection_mode),
+            self.material_list(),
             ScrollableList,
             ScrollContainerBuilder::new()
                 .vertical()
@@ -492,12 +934,347 @@ impl ListBuilder {
             },
         )
     }
+
+    /// Build a scrollable list that renders its rows from `rows`
+    ///
+    /// Only the rows in view (plus overscan) are spawned, so the list can hold any number
+    /// of rows. Without a max height the list shows eight rows.
+    pub fn build_virtual(mut self, rows: VirtualList) -> impl Bundle {
+        if self.max_height.is_none() {
+            self.max_height = Some(rows.row_height * 8.0);
+        }
+        (self.build_scrollable(), rows)
+    }
 }
 
 /// Marker for scrollable lists
 #[derive(Component)]
 pub struct ScrollableList;
 
+/// Describes the row at an index of a [`VirtualList`]
+pub type VirtualRowFn = Arc<dyn Fn(usize) -> ListItemBuilder + Send + Sync>;
+
+/// Rows of a virtualized scrollable list
+///
+/// The list only keeps the rows in view, plus `overscan` rows on either side, as
+/// entities. Row entities are reused while scrolling to show other rows from
+/// `build_row` (their texts are updated in place when the content layout matches), so
+/// selection is kept here by row index rather than on the items.
+#[derive(Component, Clone)]
+pub struct VirtualList {
+    /// Height of every row, in logical pixels
+    pub row_height: f32,
+    /// Rows kept alive above and below the visible ones
+    pub overscan: usize,
+    row_count: usize,
+    build_row: VirtualRowFn,
+    selected: BTreeSet<usize>,
+    spacer: Option<Entity>,
+    rows: Vec<(usize, Entity)>,
+    visible: Range<usize>,
+    dirty: bool,
+    /// Row to focus once it has an entity
+    pending_focus: Option<usize>,
+}
+
+impl VirtualList {
+    /// Default number of overscan rows
+    pub const DEFAULT_OVERSCAN: usize = 3;
+
+    /// Create rows of the given variant, described by `build_row`
+    pub fn new(
+        row_count: usize,
+        variant: ListItemVariant,
+        build_row: impl Fn(usize) -> ListItemBuilder + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            row_height: variant.height(),
+            overscan: Self::DEFAULT_OVERSCAN,
+            row_count,
+            build_row: Arc::new(build_row),
+            selected: BTreeSet::new(),
+            spacer: None,
+            rows: Vec::new(),
+            visible: 0..0,
+            dirty: true,
+            pending_focus: None,
+        }
+    }
+
+    /// Set the number of overscan rows
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Number of rows
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Change the number of rows, dropping the selection of removed rows
+    pub fn set_row_count(&mut self, row_count: usize) {
+        self.row_count = row_count;
+        self.selected.retain(|&index| index < row_count);
+        self.dirty = true;
+    }
+
+    /// Rebuild the rows in view, e.g. after the underlying data changed
+    pub fn refresh(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Whether the row at `index` is selected
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// Selected row indices, in ascending order
+    pub fn selected_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    /// Select or deselect the row at `index`
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        if selected && index < self.row_count {
+            self.selected.insert(index);
+        } else {
+            self.selected.remove(&index);
+        }
+    }
+
+    /// Select only the row at `index`
+    pub fn select_only(&mut self, index: usize) {
+        self.selected.clear();
+        self.set_selected(index, true);
+    }
+
+    /// Deselect every row
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Rows that currently have an entity
+    pub fn visible_range(&self) -> Range<usize> {
+        self.visible.clone()
+    }
+
+    /// Entity currently showing the row at `index`, if it is in view
+    pub fn row_entity(&self, index: usize) -> Option<Entity> {
+        self.rows
+            .iter()
+            .find(|(row, _)| *row == index)
+            .map(|(_, entity)| *entity)
+    }
+
+    /// Scroll offset that brings the row at `index` to the top of the list
+    pub fn row_offset(&self, index: usize) -> f32 {
+        index as f32 * self.row_height
+    }
+
+    /// Rows to keep alive for a viewport `viewport_height` tall, scrolled `offset`
+    /// pixels past the first row
+    pub fn window(&self, offset: f32, viewport_height: f32) -> Range<usize> {
+        if self.row_count == 0 || self.row_height <= 0.0 {
+            return 0..0;
+        }
+        let first = (offset.max(0.0) / self.row_height).floor() as usize;
+        let last = ((offset + viewport_height.max(0.0)) / self.row_height)
+            .ceil()
+            .max(0.0) as usize;
+        let start = first.saturating_sub(self.overscan).min(self.row_count);
+        let end = last.saturating_add(self.overscan).min(self.row_count);
+        start..end.max(start)
+    }
+}
+
+/// A row entity of a [`VirtualList`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtualListRow {
+    /// The list entity
+    pub list: Entity,
+    /// Index of the row this entity currently shows
+    pub index: usize,
+}
+
+/// Node sized to all rows of a virtual list; the row entities are its children
+#[derive(Component)]
+pub struct VirtualListSpacer;
+
+/// Height of the area showing a virtual list's rows
+fn virtual_list_viewport(container: &ScrollContainer, node: &Node) -> f32 {
+    // Before the first layout, fall back to the height the list was built with.
+    if container.container_size.y > 0.0 {
+        container.container_size.y
+    } else if let Val::Px(height) = node.max_height {
+        height
+    } else {
+        0.0
+    }
+}
+
+fn virtual_list_spacer_node(list: &VirtualList) -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Px(list.row_count as f32 * list.row_height),
+        flex_shrink: 0.0,
+        ..default()
+    }
+}
+
+/// Spawn the spacer holding the rows of new virtual lists
+fn virtual_list_setup_system(
+    mut commands: Commands,
+    mut lists: Query<(Entity, &mut VirtualList), Added<VirtualList>>,
+) {
+    for (entity, mut list) in lists.iter_mut() {
+        let spacer = commands
+            .spawn((
+                VirtualListSpacer,
+                virtual_list_spacer_node(&list),
+                ChildOf(entity),
+            ))
+            .id();
+        list.spacer = Some(spacer);
+    }
+}
+
+/// Whether a row entity showing `old` can show `new` by updating its texts in place
+fn same_row_layout(old: &MaterialListItem, new: &MaterialListItem) -> bool {
+    old.variant == new.variant
+        && old.disabled == new.disabled
+        && old.leading_icon == new.leading_icon
+        && old.supporting_text.is_some() == new.supporting_text.is_some()
+        && old.trailing_text == new.trailing_text
+        && old.trailing_icon == new.trailing_icon
+}
+
+/// Keep the rows in view of each virtual list spawned, reusing the entities of rows
+/// that scrolled out
+fn virtual_list_rows_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    mut lists: Query<(
+        Entity,
+        &mut VirtualList,
+        &ScrollPosition,
+        &ScrollContainer,
+        &Node,
+    )>,
+    mut items: Query<&mut MaterialListItem, With<VirtualListRow>>,
+    children: Query<&Children>,
+    mut texts: Query<
+        (&mut Text, Has<ListItemHeadline>),
+        Or<(With<ListItemHeadline>, With<ListItemSupportingText>)>,
+    >,
+) {
+    let Some(theme) = theme else { return };
+
+    for (list_entity, mut list, scroll, container, node) in lists.iter_mut() {
+        let Some(spacer) = list.spacer else { continue };
+
+        let viewport = virtual_list_viewport(container, node);
+        // The rows start below the list's top padding.
+        let window = list.window(scroll.y - Spacing::SMALL, viewport);
+
+        if window == list.visible && !list.dirty {
+            if list.is_changed() {
+                for &(index, entity) in &list.rows {
+                    if let Ok(mut item) = items.get_mut(entity) {
+                        let selected = list.is_selected(index);
+                        if item.selected != selected {
+                            item.selected = selected;
+                        }
+                    }
+                }
+            }
+            continue;
+        }
+
+        if list.dirty {
+            commands
+                .entity(spacer)
+                .insert(virtual_list_spacer_node(&list));
+        }
+
+        // Rows that left the window (or all of them, when the data changed) are reused.
+        let dirty = list.dirty;
+        let (mut kept, mut free): (Vec<_>, Vec<_>) = std::mem::take(&mut list.rows)
+            .into_iter()
+            .partition(|(index, _)| !dirty && window.contains(index));
+
+        for index in window.clone() {
+            if let Some(&(_, entity)) = kept.iter().find(|(row, _)| *row == index) {
+                if let Ok(mut item) = items.get_mut(entity) {
+                    let selected = list.is_selected(index);
+                    if item.selected != selected {
+                        item.selected = selected;
+                    }
+                }
+                continue;
+            }
+
+            let entity = match free.pop() {
+                Some((_, entity)) => entity,
+                None => commands.spawn(ChildOf(spacer)).id(),
+            };
+            let item = (list.build_row)(index)
+                .selected(list.is_selected(index))
+                .item;
+            let bg_color = item.background_color(&theme);
+            let mut row_node = list_item_node(list.row_height);
+            row_node.position_type = PositionType::Absolute;
+            row_node.top = Val::Px(list.row_offset(index));
+
+            let reuse = items
+                .get(entity)
+                .is_ok_and(|old| same_row_layout(old, &item));
+
+            let mut row = commands.entity(entity);
+            row.insert((
+                VirtualListRow {
+                    list: list_entity,
+                    index,
+                },
+                item.clone(),
+                Button,
+                RippleHost::new(),
+                row_node,
+                BackgroundColor(bg_color),
+            ));
+            if reuse {
+                for descendant in children.iter_descendants(entity) {
+                    let Ok((mut text, is_headline)) = texts.get_mut(descendant) else {
+                        continue;
+                    };
+                    let value = if is_headline {
+                        Some(&item.headline)
+                    } else {
+                        item.supporting_text.as_ref()
+                    };
+                    if let Some(value) = value.filter(|value| text.0 != **value) {
+                        text.0 = value.clone();
+                    }
+                }
+            } else {
+                row.despawn_children();
+                row.with_children(|children| spawn_list_item_content(children, &theme, &item));
+            }
+            kept.push((index, entity));
+        }
+
+        // Rows not needed for the new window are dropped rather than pooled.
+        for (_, entity) in free {
+            commands.entity(entity).despawn();
+        }
+
+        kept.sort_unstable_by_key(|(index, _)| *index);
+        list.rows = kept;
+        list.visible = window;
+        list.dirty = false;
+    }
+}
+
 impl Default for ListBuilder {
     fn default() -> Self {
         Self::new()
@@ -568,6 +1345,12 @@ impl ListItemBuilder {
         self
     }
 
+    /// Set the key used to read and set the selection
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.item.key = Some(key.into());
+        self
+    }
+
     /// Set disabled
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.item.disabled = disabled;
@@ -589,20 +1372,25 @@ impl ListItemBuilder {
             self.item,
             Button,
             RippleHost::new(),
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Px(height),
-                padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::SMALL)),
-                flex_direction: FlexDirection::Row,
-                align_items: AlignItems::Center,
-                column_gap: Val::Px(Spacing::LARGE),
-                ..default()
-            },
+            list_item_node(height),
             BackgroundColor(bg_color),
         )
     }
 }
 
+/// Layout shared by list items and virtual list rows
+fn list_item_node(height: f32) -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Px(height),
+        padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::SMALL)),
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(Spacing::LARGE),
+        ..default()
+    }
+}
+
 /// Marker for leading content area
 #[derive(Component)]
 pub struct ListItemLeading;
@@ -646,45 +1434,714 @@ pub fn create_list_divider(theme: &MaterialTheme, inset: bool) -> impl Bundle {
 }
 
 // ============================================================================
-// Spawn Traits for ChildSpawnerCommands
+// Reordering and swipe-to-dismiss
 // ============================================================================
 
-/// Extension trait to spawn Material lists and list items as children
-///
-/// This trait provides a clean API for spawning lists within UI hierarchies.
-///
-/// ## Example:
-/// ```ignore
-/// parent.spawn(Node::default()).with_children(|children| {
-///     children.spawn_list(&theme, |list| {
-///         list.spawn_list_item(&theme, "Item 1", None);
-///         list.spawn_list_item(&theme, "Item 2", Some("Supporting text"));
-///     });
-/// });
-/// ```
-pub trait SpawnListChild {
-    /// Spawn a list container
-    fn spawn_list(&mut self, with_children: impl FnOnce(&mut ChildSpawnerCommands));
+/// Distance from a scroll container's edge at which a dragged item scrolls it
+pub const LIST_REORDER_SCROLL_EDGE: f32 = 48.0;
 
-    /// Spawn a list item with headline and optional supporting text
-    fn spawn_list_item(
-        &mut self,
-        theme: &MaterialTheme,
-        headline: impl Into<String>,
-        supporting: Option<impl Into<String>>,
-    );
+/// Auto-scroll speed with the pointer at the very edge, in pixels per second
+pub const LIST_REORDER_SCROLL_SPEED: f32 = 600.0;
 
-    /// Spawn a list item with full builder control
-    fn spawn_list_item_with(&mut self, theme: &MaterialTheme, builder: ListItemBuilder);
+/// Fraction of its width an item must be swiped to be dismissed
+pub const LIST_SWIPE_DISMISS_THRESHOLD: f32 = 0.4;
 
-    /// Spawn a list divider
-    fn spawn_list_divider(&mut self, theme: &MaterialTheme, inset: bool);
+/// Pointer travel before a press on an item turns into a swipe
+pub const LIST_SWIPE_SLOP: f32 = 10.0;
+
+/// Action revealed behind list items that are swiped sideways
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListSwipeAction {
+    /// Icon shown on the revealed background
+    pub icon: String,
 }
 
-impl SpawnListChild for ChildSpawnerCommands<'_> {
-    fn spawn_list(&mut self, with_children: impl FnOnce(&mut ChildSpawnerCommands)) {
-        self.spawn(ListBuilder::new().build())
-            .with_children(with_children);
+impl ListSwipeAction {
+    /// Create an action showing `icon`
+    pub fn new(icon: impl Into<String>) -> Self {
+        Self { icon: icon.into() }
+    }
+}
+
+impl Default for ListSwipeAction {
+    fn default() -> Self {
+        Self::new("delete")
+    }
+}
+
+/// Event when an item of a reorderable list is dropped at a new position
+///
+/// The item has already been moved among its siblings.
+#[derive(Event, bevy::prelude::Message, Debug, Clone)]
+pub struct ListReorderEvent {
+    pub list: Entity,
+    pub item: Entity,
+    /// Position of the item before the drag
+    pub from: usize,
+    /// Position of the item after the drop
+    pub to: usize,
+}
+
+/// Event when an item is swiped away; the item is despawned right after
+#[derive(Event, bevy::prelude::Message, Debug, Clone)]
+pub struct ListItemDismissEvent {
+    pub list: Entity,
+    pub item: Entity,
+    /// Position of the item in the list
+    pub index: usize,
+    /// Key of the item, if it has one
+    pub key: Option<String>,
+}
+
+/// Drag handle of an item in a reorderable list
+#[derive(Component)]
+pub struct ListItemDragHandle;
+
+/// Background revealed behind an item being swiped
+#[derive(Component)]
+pub struct ListSwipeBackground {
+    pub item: Entity,
+}
+
+/// An item being dragged to a new position
+struct ListDrag {
+    item: Entity,
+    /// Items of the list, in order
+    entities: Vec<Entity>,
+    /// Item centers when the drag started, in content coordinates
+    centers: Vec<f32>,
+    /// Displacement of each item, animated as the drop position changes
+    shifts: Vec<AnimatedValue>,
+    from: usize,
+    to: usize,
+    height: f32,
+    start_pointer: f32,
+    pointer: f32,
+    scroll: Option<Entity>,
+    start_scroll: f32,
+}
+
+/// An item being swiped sideways
+struct ListSwipe {
+    item: Entity,
+    start: Vec2,
+    offset: f32,
+    width: f32,
+    background: Option<Entity>,
+    /// After release: the slide out (when dismissed) or back into place
+    release: Option<(AnimatedValue, bool)>,
+}
+
+/// How far the item at `index` moves aside while the item at `from` is dragged to `to`
+fn reorder_shift(index: usize, from: usize, to: usize, height: f32) -> f32 {
+    if from < to && index > from && index <= to {
+        -height
+    } else if to < from && index >= to && index < from {
+        height
+    } else {
+        0.0
+    }
+}
+
+/// Position of the item at `index` after the item at `from` moved to `to`
+fn reordered_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else {
+        let shift = reorder_shift(index, from, to, 1.0);
+        (index as f32 + shift) as usize
+    }
+}
+
+/// Current pointer position in logical pixels: the first touch, or else the cursor
+fn pointer_position(window: Option<&Window>, touches: &Touches) -> Option<Vec2> {
+    touches
+        .iter()
+        .next()
+        .map(|touch| touch.position())
+        .or_else(|| window.and_then(Window::cursor_position))
+}
+
+fn pointer_down(mouse: &ButtonInput<MouseButton>, touches: &Touches) -> bool {
+    mouse.pressed(MouseButton::Left) || touches.iter().next().is_some()
+}
+
+/// Bounds of a node in logical pixels
+fn logical_rect(node: &ComputedNode, transform: &UiGlobalTransform) -> Rect {
+    let inverse_scale = node.inverse_scale_factor();
+    Rect::from_center_size(
+        transform.translation * inverse_scale,
+        node.size() * inverse_scale,
+    )
+}
+
+/// Give the items of reorderable lists a trailing drag handle
+fn list_drag_handle_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    changed_lists: Query<(Entity, &MaterialList), Changed<MaterialList>>,
+    new_items: Query<Entity, Added<MaterialListItem>>,
+    lists: Query<&MaterialList>,
+    items: Query<(), (With<MaterialListItem>, Without<VirtualListRow>)>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    rows: Query<&VirtualListRow>,
+    mut handles: Query<&mut Node, With<ListItemDragHandle>>,
+) {
+    let Some(theme) = theme else { return };
+
+    let mut targets: Vec<(Entity, bool)> = Vec::new();
+    for (list_entity, list) in changed_lists.iter() {
+        for item in list_item_entities(list_entity, &children, |entity| items.contains(entity)) {
+            targets.push((item, list.reorderable));
+        }
+    }
+    for item in new_items.iter().filter(|item| items.contains(*item)) {
+        if targets.iter().any(|(target, _)| *target == item) {
+            continue;
+        }
+        let reorderable = owning_list(item, &rows, &parents, |entity| lists.contains(entity))
+            .and_then(|list| lists.get(list).ok())
+            .is_some_and(|list| list.reorderable);
+        targets.push((item, reorderable));
+    }
+
+    for (item, reorderable) in targets {
+        let handle = children
+            .get(item)
+            .ok()
+            .and_then(|item_children| item_children.iter().find(|child| handles.contains(*child)));
+
+        match handle {
+            Some(handle) => {
+                if let Ok(mut node) = handles.get_mut(handle) {
+                    let display = if reorderable {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    };
+                    if node.display != display {
+                        node.display = display;
+                    }
+                }
+            }
+            None if reorderable => {
+                let icon_color = theme.on_surface_variant;
+                commands.entity(item).with_children(|item| {
+                    item.spawn((
+                        ListItemDragHandle,
+                        Button,
+                        Node {
+                            width: Val::Px(40.0),
+                            height: Val::Px(40.0),
+                            flex_shrink: 0.0,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                    ))
+                    .with_children(|handle| {
+                        handle.spawn((
+                            MaterialIcon::new(ICON_DRAG_HANDLE),
+                            IconStyle::outlined().with_color(icon_color).with_size(24.0),
+                        ));
+                    });
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+/// Drag items of reorderable lists by their handle
+///
+/// The dragged item is lifted with a shadow and follows the pointer while the items
+/// between its old and new position move aside; the enclosing scroll container
+/// scrolls when the pointer nears its edges. Dropping moves the item among its
+/// siblings and sends [`ListReorderEvent`].
+fn list_reorder_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Option<Res<MaterialTheme>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    handles: Query<(&Interaction, &ChildOf), (Changed<Interaction>, With<ListItemDragHandle>)>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    rows: Query<&VirtualListRow>,
+    mut lists: Query<(Entity, &mut MaterialList)>,
+    mut items: Query<(
+        &mut MaterialListItem,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &mut UiTransform,
+        &mut BackgroundColor,
+    )>,
+    mut scrolls: Query<
+        (
+            &mut ScrollPosition,
+            &ScrollContainer,
+            &ComputedNode,
+            &UiGlobalTransform,
+        ),
+        Without<MaterialListItem>,
+    >,
+    mut reorder_events: MessageWriter<ListReorderEvent>,
+) {
+    let pointer = pointer_position(windows.single().ok(), &touches);
+
+    for (interaction, handle_parent) in handles.iter() {
+        let item = handle_parent.parent();
+        if *interaction != Interaction::Pressed || rows.contains(item) {
+            continue;
+        }
+        let Some(pointer) = pointer else { continue };
+        let Some(list_entity) = owning_list(item, &rows, &parents, |entity| lists.contains(entity))
+        else {
+            continue;
+        };
+        let entities = list_item_entities(list_entity, &children, |entity| items.contains(entity));
+        let Some(from) = entities.iter().position(|entity| *entity == item) else {
+            continue;
+        };
+
+        // Auto-scroll acts on the nearest scroll container around the item.
+        let mut scroll = None;
+        let mut current = parents.get(item).ok().map(ChildOf::parent);
+        while let Some(entity) = current {
+            if scrolls.contains(entity) {
+                scroll = Some(entity);
+                break;
+            }
+            current = parents.get(entity).ok().map(ChildOf::parent);
+        }
+        let start_scroll = scroll
+            .and_then(|entity| scrolls.get(entity).ok())
+            .map_or(0.0, |(position, ..)| position.y);
+
+        let centers = entities
+            .iter()
+            .map(|entity| {
+                items.get(*entity).map_or(0.0, |(_, node, transform, ..)| {
+                    logical_rect(node, transform).center().y + start_scroll
+                })
+            })
+            .collect();
+        let Ok((_, node, transform, _, mut background)) = items.get_mut(item) else {
+            continue;
+        };
+        let height = logical_rect(node, transform).height();
+
+        let Ok((_, mut list)) = lists.get_mut(list_entity) else {
+            continue;
+        };
+        if !list.reorderable || list.drag.is_some() {
+            continue;
+        }
+
+        // Lift the item above its neighbours.
+        if let Some(theme) = theme.as_ref() {
+            background.0 = theme.surface_container_high;
+        }
+        commands
+            .entity(item)
+            .insert((Elevation::Level2.to_box_shadow(), ZIndex(1)));
+
+        list.drag = Some(ListDrag {
+            item,
+            shifts: entities
+                .iter()
+                .map(|_| AnimatedValue::new(0.0).with_duration(Duration::SHORT4))
+                .collect(),
+            entities,
+            centers,
+            from,
+            to: from,
+            height,
+            start_pointer: pointer.y,
+            pointer: pointer.y,
+            scroll,
+            start_scroll,
+        });
+    }
+
+    let dt = time.delta_secs();
+    let released = !pointer_down(&mouse, &touches);
+
+    for (list_entity, mut list) in lists.iter_mut() {
+        // The drag state is internal: only a drop marks the list changed.
+        if list.drag.is_none() {
+            continue;
+        }
+        let Some(mut drag) = list.bypass_change_detection().drag.take() else {
+            continue;
+        };
+        if let Some(pointer) = pointer {
+            drag.pointer = pointer.y;
+        }
+
+        let mut scroll_y = drag.start_scroll;
+        if let Some((mut position, container, node, transform)) =
+            drag.scroll.and_then(|entity| scrolls.get_mut(entity).ok())
+        {
+            let rect = logical_rect(node, transform);
+            let edge = LIST_REORDER_SCROLL_EDGE;
+            let speed = if drag.pointer < rect.min.y + edge {
+                -(1.0 - ((drag.pointer - rect.min.y) / edge).clamp(0.0, 1.0))
+            } else if drag.pointer > rect.max.y - edge {
+                1.0 - ((rect.max.y - drag.pointer) / edge).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            if speed != 0.0 && !released {
+                let y = (position.y + speed * LIST_REORDER_SCROLL_SPEED * dt)
+                    .clamp(0.0, container.max_offset.y.max(0.0));
+                if y != position.y {
+                    position.y = y;
+                }
+            }
+            scroll_y = position.y;
+        }
+
+        // Content moves with the scroll offset, so the item keeps up with it.
+        let offset = drag.pointer - drag.start_pointer + scroll_y - drag.start_scroll;
+        let center = drag.centers[drag.from] + offset;
+        drag.to = drag
+            .centers
+            .iter()
+            .enumerate()
+            .filter(|(index, other)| *index != drag.from && **other < center)
+            .count();
+
+        for (index, entity) in drag.entities.iter().enumerate() {
+            let y = if index == drag.from {
+                offset
+            } else {
+                let shift = &mut drag.shifts[index];
+                shift.set_target(reorder_shift(index, drag.from, drag.to, drag.height));
+                shift.update(dt);
+                shift.value()
+            };
+            if let Ok((_, _, _, mut transform, _)) = items.get_mut(*entity) {
+                transform.translation = Val2::px(0.0, y);
+            }
+        }
+
+        if !released {
+            list.bypass_change_detection().drag = Some(drag);
+            continue;
+        }
+
+        for entity in &drag.entities {
+            if let Ok((_, _, _, mut transform, _)) = items.get_mut(*entity) {
+                transform.translation = Val2::ZERO;
+            }
+        }
+        if let Ok((mut item, ..)) = items.get_mut(drag.item) {
+            // Restyles the background.
+            item.set_changed();
+        }
+        commands.entity(drag.item).remove::<(BoxShadow, ZIndex)>();
+
+        let (from, to) = (drag.from, drag.to);
+        if from == to {
+            continue;
+        }
+
+        // Place the item next to the one it took the position of.
+        let target = drag.entities[to];
+        if let Ok(parent) = parents.get(target).map(ChildOf::parent) {
+            let index = children
+                .get(parent)
+                .map(|siblings| {
+                    let siblings: Vec<Entity> = siblings
+                        .iter()
+                        .filter(|child| *child != drag.item)
+                        .collect();
+                    let position = siblings
+                        .iter()
+                        .position(|child| *child == target)
+                        .unwrap_or(siblings.len());
+                    if from < to {
+                        position + 1
+                    } else {
+                        position
+                    }
+                })
+                .unwrap_or(0);
+            commands.entity(parent).insert_children(index, &[drag.item]);
+        }
+
+        list.selected_indices = list
+            .selected_indices
+            .iter()
+            .map(|index| reordered_index(*index, from, to))
+            .collect();
+        list.selected_indices.sort_unstable();
+        list.anchor = list.anchor.map(|index| reordered_index(index, from, to));
+        reorder_events.write(ListReorderEvent {
+            list: list_entity,
+            item: drag.item,
+            from,
+            to,
+        });
+    }
+}
+
+/// Swipe items of lists with a swipe action sideways to dismiss them
+///
+/// The action's background is revealed behind the item as it moves. Released past
+/// [`LIST_SWIPE_DISMISS_THRESHOLD`] the item slides out, [`ListItemDismissEvent`]
+/// is sent and the item is despawned; otherwise it slides back.
+fn list_swipe_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Option<Res<MaterialTheme>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    pressed: Query<(Entity, &Interaction), (Changed<Interaction>, With<MaterialListItem>)>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    rows: Query<&VirtualListRow>,
+    mut lists: Query<(Entity, &mut MaterialList)>,
+    mut items: Query<(
+        &mut MaterialListItem,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &mut UiTransform,
+        &mut BackgroundColor,
+    )>,
+    parent_nodes: Query<
+        (&ComputedNode, &UiGlobalTransform, Option<&ScrollPosition>),
+        Without<MaterialListItem>,
+    >,
+    mut backgrounds: Query<&mut Node, With<ListSwipeBackground>>,
+    mut dismiss_events: MessageWriter<ListItemDismissEvent>,
+) {
+    let Some(theme) = theme else { return };
+    let pointer = pointer_position(windows.single().ok(), &touches);
+
+    for (item, interaction) in pressed.iter() {
+        if *interaction != Interaction::Pressed || rows.contains(item) {
+            continue;
+        }
+        let Some(start) = pointer else { continue };
+        let Some(list_entity) = owning_list(item, &rows, &parents, |entity| lists.contains(entity))
+        else {
+            continue;
+        };
+        let Ok((_, node, transform, ..)) = items.get(item) else {
+            continue;
+        };
+        let width = logical_rect(node, transform).width();
+        let Ok((_, mut list)) = lists.get_mut(list_entity) else {
+            continue;
+        };
+        if list.swipe_action.is_none() || list.drag.is_some() || list.swipe.is_some() {
+            continue;
+        }
+        list.swipe = Some(ListSwipe {
+            item,
+            start,
+            offset: 0.0,
+            width,
+            background: None,
+            release: None,
+        });
+    }
+
+    let dt = time.delta_secs();
+    let released = !pointer_down(&mouse, &touches);
+
+    for (list_entity, mut list) in lists.iter_mut() {
+        // The swipe state is internal, so it does not mark the list changed.
+        if list.swipe.is_none() {
+            continue;
+        }
+        let Some(mut swipe) = list.bypass_change_detection().swipe.take() else {
+            continue;
+        };
+        let Some(action) = list.swipe_action.clone() else {
+            continue;
+        };
+
+        if let Some((mut slide, dismiss)) = swipe.release.take() {
+            slide.update(dt);
+            if let Ok((_, _, _, mut transform, _)) = items.get_mut(swipe.item) {
+                transform.translation = Val2::px(slide.value(), 0.0);
+            }
+            if !slide.complete {
+                swipe.release = Some((slide, dismiss));
+                list.bypass_change_detection().swipe = Some(swipe);
+                continue;
+            }
+
+            if let Some(background) = swipe.background {
+                commands.entity(background).despawn();
+            }
+            if dismiss {
+                let entities =
+                    list_item_entities(list_entity, &children, |entity| items.contains(entity));
+                let index = entities
+                    .iter()
+                    .position(|entity| *entity == swipe.item)
+                    .unwrap_or_default();
+                let key = items
+                    .get(swipe.item)
+                    .ok()
+                    .and_then(|(item, ..)| item.key.clone());
+                dismiss_events.write(ListItemDismissEvent {
+                    list: list_entity,
+                    item: swipe.item,
+                    index,
+                    key,
+                });
+                commands.entity(swipe.item).despawn();
+            } else if let Ok((mut item, _, _, mut transform, _)) = items.get_mut(swipe.item) {
+                transform.translation = Val2::ZERO;
+                // Restyles the background.
+                item.set_changed();
+            }
+            continue;
+        }
+
+        let pointer = pointer.unwrap_or(swipe.start + Vec2::X * swipe.offset);
+        let delta = pointer - swipe.start;
+
+        if swipe.background.is_none() {
+            if released || (delta.y.abs() > LIST_SWIPE_SLOP && delta.y.abs() > delta.x.abs()) {
+                // A tap, or a vertical drag that belongs to scrolling.
+                continue;
+            }
+            if delta.x.abs() <= LIST_SWIPE_SLOP {
+                list.bypass_change_detection().swipe = Some(swipe);
+                continue;
+            }
+
+            // Reveal the action behind the item, as an absolute sibling below it.
+            let Ok(parent) = parents.get(swipe.item).map(ChildOf::parent) else {
+                continue;
+            };
+            let (Ok((_, node, transform, _, mut background)), Ok(parent_node)) =
+                (items.get_mut(swipe.item), parent_nodes.get(parent))
+            else {
+                continue;
+            };
+            let rect = logical_rect(node, transform);
+            let (parent_node, parent_transform, parent_scroll) = parent_node;
+            let origin = logical_rect(parent_node, parent_transform).min;
+            let position = rect.min - origin + parent_scroll.map_or(Vec2::ZERO, |scroll| **scroll);
+            background.0 = theme.surface;
+
+            let icon_color = theme.on_error_container;
+            let icon = resolve_icon_codepoint(&action.icon);
+            let background = commands
+                .spawn((
+                    ListSwipeBackground { item: swipe.item },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(position.x),
+                        top: Val::Px(position.y),
+                        width: Val::Px(rect.width()),
+                        height: Val::Px(rect.height()),
+                        padding: UiRect::horizontal(Val::Px(Spacing::LARGE)),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(theme.error_container),
+                    ZIndex(-1),
+                    ChildOf(parent),
+                ))
+                .with_children(|background| {
+                    if let Some(codepoint) = icon {
+                        background.spawn((
+                            MaterialIcon::new(codepoint),
+                            IconStyle::outlined().with_color(icon_color).with_size(24.0),
+                        ));
+                    }
+                })
+                .id();
+            swipe.background = Some(background);
+        }
+
+        swipe.offset = delta.x;
+        if let Ok((_, _, _, mut transform, _)) = items.get_mut(swipe.item) {
+            transform.translation = Val2::px(swipe.offset, 0.0);
+        }
+        // The icon sits on the side the item uncovers.
+        if let Some(mut node) = swipe
+            .background
+            .and_then(|background| backgrounds.get_mut(background).ok())
+        {
+            let justify = if swipe.offset >= 0.0 {
+                JustifyContent::FlexStart
+            } else {
+                JustifyContent::FlexEnd
+            };
+            if node.justify_content != justify {
+                node.justify_content = justify;
+            }
+        }
+
+        if released {
+            let dismiss = swipe.offset.abs() >= swipe.width * LIST_SWIPE_DISMISS_THRESHOLD;
+            let target = if dismiss {
+                swipe.offset.signum() * swipe.width
+            } else {
+                0.0
+            };
+            let mut slide = AnimatedValue::new(swipe.offset)
+                .with_duration(Duration::SHORT4)
+                .with_easing(Easing::EmphasizedAccelerate);
+            slide.set_target(target);
+            swipe.release = Some((slide, dismiss));
+        }
+        list.bypass_change_detection().swipe = Some(swipe);
+    }
+}
+
+// ============================================================================
+// Spawn Traits for ChildSpawnerCommands
+// ============================================================================
+
+/// Extension trait to spawn Material lists and list items as children
+///
+/// This trait provides a clean API for spawning lists within UI hierarchies.
+///
+/// ## Example:
+/// ```ignore
+/// parent.spawn(Node::default()).with_children(|children| {
+///     children.spawn_list(&theme, |list| {
+///         list.spawn_list_item(&theme, "Item 1", None);
+///         list.spawn_list_item(&theme, "Item 2", Some("Supporting text"));
+///     });
+/// });
+/// ```
+pub trait SpawnListChild {
+    /// Spawn a list container
+    fn spawn_list(&mut self, with_children: impl FnOnce(&mut ChildSpawnerCommands));
+
+    /// Spawn a list item with headline and optional supporting text
+    fn spawn_list_item(
+        &mut self,
+        theme: &MaterialTheme,
+        headline: impl Into<String>,
+        supporting: Option<impl Into<String>>,
+    );
+
+    /// Spawn a list item with full builder control
+    fn spawn_list_item_with(&mut self, theme: &MaterialTheme, builder: ListItemBuilder);
+
+    /// Spawn a list divider
+    fn spawn_list_divider(&mut self, theme: &MaterialTheme, inset: bool);
+}
+
+impl SpawnListChild for ChildSpawnerCommands<'_> {
+    fn spawn_list(&mut self, with_children: impl FnOnce(&mut ChildSpawnerCommands)) {
+        self.spawn(ListBuilder::new().build())
+            .with_children(with_children);
     }
 
     fn spawn_list_item(
@@ -745,63 +2202,99 @@ impl SpawnListChild for ChildSpawnerCommands<'_> {
     }
 
     fn spawn_list_item_with(&mut self, theme: &MaterialTheme, builder: ListItemBuilder) {
-        let headline = builder.item.headline.clone();
-        let supporting_text = builder.item.supporting_text.clone();
-        let trailing_text = builder.item.trailing_text.clone();
-        let leading_icon = builder.item.leading_icon.clone();
-        let trailing_icon = builder.item.trailing_icon.clone();
+        let item = builder.item.clone();
+        self.spawn(builder.build(theme))
+            .with_children(|children| spawn_list_item_content(children, theme, &item));
+    }
 
-        let headline_color = builder.item.headline_color(theme);
-        let supporting_color = builder.item.supporting_text_color(theme);
-        let icon_color = builder.item.icon_color(theme);
+    fn spawn_list_divider(&mut self, theme: &MaterialTheme, inset: bool) {
+        self.spawn(create_list_divider(theme, inset));
+    }
+}
 
-        self.spawn(builder.build(theme)).with_children(|item| {
-            // Leading content
-            if let Some(icon_str) = leading_icon.as_deref() {
-                if let Some(codepoint) = resolve_icon_codepoint(icon_str) {
-                    item.spawn((
-                        ListItemLeading,
-                        Node {
-                            width: Val::Px(56.0),
-                            height: Val::Px(56.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                    ))
-                    .with_children(|leading| {
-                        leading.spawn((
-                            MaterialIcon::new(codepoint),
-                            IconStyle::outlined().with_color(icon_color).with_size(24.0),
-                        ));
-                    });
-                }
-            }
+/// Spawn the leading, body and trailing content of a list item
+fn spawn_list_item_content(
+    children: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    item: &MaterialListItem,
+) {
+    let headline_color = item.headline_color(theme);
+    let supporting_color = item.supporting_text_color(theme);
+    let icon_color = item.icon_color(theme);
+
+    // Leading content
+    if let Some(icon_str) = item.leading_icon.as_deref() {
+        if let Some(codepoint) = resolve_icon_codepoint(icon_str) {
+            children
+                .spawn((
+                    ListItemLeading,
+                    Node {
+                        width: Val::Px(56.0),
+                        height: Val::Px(56.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                ))
+                .with_children(|leading| {
+                    leading.spawn((
+                        MaterialIcon::new(codepoint),
+                        IconStyle::outlined().with_color(icon_color).with_size(24.0),
+                    ));
+                });
+        }
+    }
 
-            // Body
-            item.spawn((
-                ListItemBody,
-                Node {
-                    flex_direction: FlexDirection::Column,
-                    flex_grow: 1.0,
+    // Body
+    children
+        .spawn((
+            ListItemBody,
+            Node {
+                flex_direction: FlexDirection::Column,
+                flex_grow: 1.0,
+                ..default()
+            },
+        ))
+        .with_children(|body| {
+            body.spawn((
+                ListItemHeadline,
+                Text::new(&item.headline),
+                TextFont {
+                    font_size: 16.0,
                     ..default()
                 },
-            ))
-            .with_children(|body| {
+                TextColor(headline_color),
+            ));
+
+            if let Some(ref supporting) = item.supporting_text {
                 body.spawn((
-                    ListItemHeadline,
-                    Text::new(&headline),
+                    ListItemSupportingText,
+                    Text::new(supporting),
                     TextFont {
-                        font_size: 16.0,
+                        font_size: 14.0,
                         ..default()
                     },
-                    TextColor(headline_color),
+                    TextColor(supporting_color),
                 ));
+            }
+        });
 
-                if let Some(ref supporting) = supporting_text {
-                    body.spawn((
-                        ListItemSupportingText,
-                        Text::new(supporting),
+    // Trailing content
+    if item.trailing_text.is_some() || item.trailing_icon.is_some() {
+        children
+            .spawn((
+                ListItemTrailing,
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(Spacing::MEDIUM),
+                    ..default()
+                },
+            ))
+            .with_children(|trailing| {
+                if let Some(ref text) = item.trailing_text {
+                    trailing.spawn((
+                        Text::new(text),
                         TextFont {
                             font_size: 14.0,
                             ..default()
@@ -809,45 +2302,382 @@ impl SpawnListChild for ChildSpawnerCommands<'_> {
                         TextColor(supporting_color),
                     ));
                 }
-            });
 
-            // Trailing content
-            if trailing_text.is_some() || trailing_icon.is_some() {
-                item.spawn((
-                    ListItemTrailing,
-                    Node {
-                        flex_direction: FlexDirection::Row,
-                        align_items: AlignItems::Center,
-                        column_gap: Val::Px(Spacing::MEDIUM),
-                        ..default()
-                    },
-                ))
-                .with_children(|trailing| {
-                    if let Some(ref text) = trailing_text {
+                if let Some(icon_str) = item.trailing_icon.as_deref() {
+                    if let Some(codepoint) = resolve_icon_codepoint(icon_str) {
                         trailing.spawn((
-                            Text::new(text),
-                            TextFont {
-                                font_size: 14.0,
-                                ..default()
-                            },
-                            TextColor(supporting_color),
+                            MaterialIcon::new(codepoint),
+                            IconStyle::outlined().with_color(icon_color).with_size(24.0),
                         ));
                     }
+                }
+            });
+    }
+}
 
-                    if let Some(icon_str) = trailing_icon.as_deref() {
-                        if let Some(codepoint) = resolve_icon_codepoint(icon_str) {
-                            trailing.spawn((
-                                MaterialIcon::new(codepoint),
-                                IconStyle::outlined().with_color(icon_color).with_size(24.0),
-                            ));
-                        }
-                    }
-                });
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::focus::FocusPlugin;
+    use bevy::ecs::message::MessageCursor;
+    use bevy::input::mouse::MouseButtonInput;
+    use bevy::input::{ButtonState, InputPlugin};
+    use bevy::math::Affine2;
+
+    fn rows(count: usize) -> VirtualList {
+        VirtualList::new(count, ListItemVariant::OneLine, |index| {
+            ListItemBuilder::new(format!("Row {index}"))
+        })
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, FocusPlugin, ListPlugin))
+            .init_resource::<MaterialTheme>();
+        app
+    }
+
+    /// `(index, entity, headline)` of the row entities of a virtual list
+    fn virtual_rows(app: &mut App) -> Vec<(usize, Entity, String)> {
+        let world = app.world_mut();
+        let mut rows: Vec<_> = world
+            .query::<(Entity, &VirtualListRow, &MaterialListItem)>()
+            .iter(world)
+            .map(|(entity, row, item)| (row.index, entity, item.headline.clone()))
+            .collect();
+        rows.sort_unstable();
+        rows
+    }
+
+    /// `(entity, text)` of every list item headline
+    fn headlines(app: &mut App) -> Vec<(Entity, String)> {
+        let world = app.world_mut();
+        world
+            .query_filtered::<(Entity, &Text), With<ListItemHeadline>>()
+            .iter(world)
+            .map(|(entity, text)| (entity, text.0.clone()))
+            .collect()
+    }
+
+    /// `(indices, keys)` of the selection changes sent since the last call
+    fn selection_changes(
+        app: &App,
+        cursor: &mut MessageCursor<ListSelectionChangedEvent>,
+    ) -> Vec<(Vec<usize>, Vec<String>)> {
+        let messages = app
+            .world()
+            .resource::<Messages<ListSelectionChangedEvent>>();
+        cursor
+            .read(messages)
+            .map(|event| (event.indices.clone(), event.keys.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_selection_from_keys_and_clicks() {
+        let mut app = test_app();
+        let theme = MaterialTheme::default();
+        let list = app
+            .world_mut()
+            .spawn(
+                ListBuilder::new()
+                    .selection_mode(ListSelectionMode::Multi)
+                    .build(),
+            )
+            .id();
+        let items: Vec<Entity> = ["a", "b", "c"]
+            .into_iter()
+            .map(|key| {
+                app.world_mut()
+                    .spawn((
+                        ListItemBuilder::new(key.to_uppercase())
+                            .key(key)
+                            .build(&theme),
+                        ChildOf(list),
+                    ))
+                    .id()
+            })
+            .collect();
+        app.update();
+
+        let mut cursor = MessageCursor::default();
+        let selected = |app: &App| -> Vec<bool> {
+            items
+                .iter()
+                .map(|item| app.world().get::<MaterialListItem>(*item).unwrap().selected)
+                .collect()
+        };
+        let background =
+            |app: &App, item: Entity| app.world().get::<BackgroundColor>(item).unwrap().0;
+
+        app.world_mut().write_message(SetListSelection {
+            list,
+            keys: vec!["b".into(), "c".into()],
         });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            selection_changes(&app, &mut cursor),
+            vec![(vec![1, 2], vec!["b".to_string(), "c".to_string()])]
+        );
+        assert_eq!(selected(&app), vec![false, true, true]);
+        assert_eq!(background(&app, items[0]), Color::NONE);
+        assert_eq!(background(&app, items[1]), theme.secondary_container);
+
+        // Clicking toggles an item in a multi-select list.
+        *app.world_mut().get_mut::<Interaction>(items[0]).unwrap() = Interaction::Pressed;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(items[0]).unwrap() = Interaction::None;
+        app.update();
+
+        assert_eq!(
+            selection_changes(&app, &mut cursor),
+            vec![(
+                vec![0, 1, 2],
+                vec!["a".to_string(), "b".to_string(), "c".to_string()]
+            )]
+        );
+        assert_eq!(selected(&app), vec![true, true, true]);
+        assert_eq!(background(&app, items[0]), theme.secondary_container);
+        assert_eq!(
+            app.world().get::<MaterialList>(list).unwrap().selected_keys,
+            vec!["a", "b", "c"]
+        );
     }
 
-    fn spawn_list_divider(&mut self, theme: &MaterialTheme, inset: bool) {
-        self.spawn(create_list_divider(theme, inset));
+    #[test]
+    fn test_drag_to_reorder() {
+        let mut app = test_app();
+        let theme = MaterialTheme::default();
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        let list = app
+            .world_mut()
+            .spawn(ListBuilder::new().reorderable().build())
+            .id();
+        // Laid out by hand: 56px rows stacked from the top of the window.
+        let items: Vec<Entity> = (0..4)
+            .map(|index| {
+                app.world_mut()
+                    .spawn((
+                        ListItemBuilder::new(format!("Item {index}")).build(&theme),
+                        ComputedNode {
+                            size: Vec2::new(200.0, 56.0),
+                            inverse_scale_factor: 1.0,
+                            ..default()
+                        },
+                        UiGlobalTransform::from(Affine2::from_translation(Vec2::new(
+                            100.0,
+                            28.0 + 56.0 * index as f32,
+                        ))),
+                        ChildOf(list),
+                    ))
+                    .id()
+            })
+            .collect();
+        app.update();
+
+        let handle = app.world().get::<Children>(items[0]).unwrap()[0];
+        assert!(app.world().get::<ListItemDragHandle>(handle).is_some());
+
+        let move_pointer = |app: &mut App, y: f32| {
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .set_cursor_position(Some(Vec2::new(180.0, y)));
+        };
+        let mouse = |app: &mut App, state: ButtonState| {
+            app.world_mut().write_message(MouseButtonInput {
+                button: MouseButton::Left,
+                state,
+                window,
+            });
+        };
+
+        // Grab the first item by its handle and drag it past the third one.
+        move_pointer(&mut app, 28.0);
+        mouse(&mut app, ButtonState::Pressed);
+        *app.world_mut().get_mut::<Interaction>(handle).unwrap() = Interaction::Pressed;
+        app.update();
+        for y in [60.0, 100.0, 150.0] {
+            move_pointer(&mut app, y);
+            app.update();
+        }
+        let dragged = app.world().get::<UiTransform>(items[0]).unwrap();
+        assert_eq!(dragged.translation, Val2::px(0.0, 122.0));
+
+        mouse(&mut app, ButtonState::Released);
+        app.update();
+
+        let messages = app.world().resource::<Messages<ListReorderEvent>>();
+        let reorders: Vec<_> = messages
+            .get_cursor()
+            .read(messages)
+            .map(|event| (event.list, event.item, event.from, event.to))
+            .collect();
+        assert_eq!(reorders, vec![(list, items[0], 0, 2)]);
+
+        let order: Vec<Entity> = app.world().get::<Children>(list).unwrap().to_vec();
+        assert_eq!(order, vec![items[1], items[2], items[0], items[3]]);
+        assert_eq!(
+            app.world()
+                .get::<UiTransform>(items[0])
+                .unwrap()
+                .translation,
+            Val2::ZERO
+        );
+    }
+
+    #[test]
+    fn test_virtual_list_scrolling_reuses_rows() {
+        let mut app = test_app();
+        let list = app
+            .world_mut()
+            .spawn(
+                ListBuilder::new()
+                    .max_height(280.0)
+                    .build_virtual(rows(10_000)),
+            )
+            .id();
+        app.update();
+
+        // Five 56px rows in view, plus three overscan rows below.
+        let before = virtual_rows(&mut app);
+        let texts_before = headlines(&mut app);
+        assert_eq!(
+            before.iter().map(|(index, ..)| *index).collect::<Vec<_>>(),
+            (0..8).collect::<Vec<_>>()
+        );
+
+        app.world_mut().get_mut::<ScrollPosition>(list).unwrap().y = 100.0 * 56.0;
+        app.update();
+
+        let after = virtual_rows(&mut app);
+        let indices: Vec<usize> = after.iter().map(|(index, ..)| *index).collect();
+        assert_eq!(indices, (96..108).collect::<Vec<_>>());
+        assert!(after
+            .iter()
+            .all(|(index, _, headline)| *headline == format!("Row {index}")));
+
+        // The rows that scrolled out show other rows, with their texts updated in place.
+        assert!(before
+            .iter()
+            .all(|(_, entity, _)| after.iter().any(|(_, reused, _)| reused == entity)));
+        let texts_after = headlines(&mut app);
+        assert!(texts_before
+            .iter()
+            .all(|(entity, _)| texts_after.iter().any(|(kept, _)| kept == entity)));
+        let mut shown: Vec<String> = texts_after.into_iter().map(|(_, text)| text).collect();
+        shown.sort_unstable();
+        let mut expected: Vec<String> = (96..108).map(|index| format!("Row {index}")).collect();
+        expected.sort_unstable();
+        assert_eq!(shown, expected);
+    }
+
+    #[test]
+    fn test_virtual_list_window() {
+        let list = rows(10_000).with_overscan(2);
+
+        // 56px rows in a 280px viewport: five rows in view, plus overscan below.
+        assert_eq!(list.window(0.0, 280.0), 0..7);
+        // Scrolled half a row: six rows are partly visible.
+        assert_eq!(list.window(28.0, 280.0), 0..8);
+        assert_eq!(list.window(560.0, 280.0), 8..17);
+        // The window never runs past the last row.
+        assert_eq!(list.window(559_720.0, 280.0), 9_993..10_000);
+        assert_eq!(rows(0).window(0.0, 280.0), 0..0);
+    }
+
+    #[test]
+    fn test_multi_select_clicks() {
+        let mode = ListSelectionMode::Multi;
+        let enabled = |index: usize| index != 4;
+        let mut selection = BTreeSet::new();
+        let mut anchor = None;
+
+        click_selection(mode, &mut selection, &mut anchor, 2, false, false, enabled);
+        // Shift+click selects the range from the anchor, skipping disabled items.
+        click_selection(mode, &mut selection, &mut anchor, 5, true, false, enabled);
+        assert_eq!(selection, BTreeSet::from([2, 3, 5]));
+        assert_eq!(anchor, Some(2));
+
+        // Ctrl+click toggles and moves the anchor.
+        click_selection(mode, &mut selection, &mut anchor, 3, false, true, enabled);
+        assert_eq!(selection, BTreeSet::from([2, 5]));
+        assert_eq!(anchor, Some(3));
+
+        // Ctrl+Shift+click adds the range to the selection.
+        click_selection(mode, &mut selection, &mut anchor, 0, true, true, enabled);
+        assert_eq!(selection, BTreeSet::from([0, 1, 2, 3, 5]));
+
+        // Single mode ignores modifiers.
+        click_selection(
+            ListSelectionMode::Single,
+            &mut selection,
+            &mut anchor,
+            7,
+            true,
+            true,
+            enabled,
+        );
+        assert_eq!(selection, BTreeSet::from([7]));
+    }
+
+    #[test]
+    fn test_keyboard_move_selection() {
+        let mode = ListSelectionMode::Multi;
+        let enabled = |_: usize| true;
+        let mut selection = BTreeSet::new();
+        let mut anchor = None;
+
+        move_selection(mode, &mut selection, &mut anchor, 3, 4, true, enabled);
+        move_selection(mode, &mut selection, &mut anchor, 4, 5, true, enabled);
+        assert_eq!(selection, BTreeSet::from([3, 4, 5]));
+
+        // Extending back past the anchor shrinks and flips the range.
+        move_selection(mode, &mut selection, &mut anchor, 5, 2, true, enabled);
+        assert_eq!(selection, BTreeSet::from([2, 3]));
+
+        // Moving without Shift keeps the selection and moves the anchor.
+        move_selection(mode, &mut selection, &mut anchor, 2, 1, false, enabled);
+        assert_eq!(selection, BTreeSet::from([2, 3]));
+        assert_eq!(anchor, Some(1));
+
+        assert_eq!(step_enabled(1, 6, true, |i| i != 2), Some(3));
+        assert_eq!(step_enabled(0, 6, false, enabled), None);
+    }
+
+    #[test]
+    fn test_reorder_positions() {
+        // Dragging the first item to the third position moves the two after it up.
+        let shifts: Vec<f32> = (0..4).map(|i| reorder_shift(i, 0, 2, 56.0)).collect();
+        assert_eq!(shifts, vec![0.0, -56.0, -56.0, 0.0]);
+        let order: Vec<usize> = (0..4).map(|i| reordered_index(i, 0, 2)).collect();
+        assert_eq!(order, vec![2, 0, 1, 3]);
+
+        // Dragging up moves the items in between down.
+        let shifts: Vec<f32> = (0..4).map(|i| reorder_shift(i, 3, 1, 56.0)).collect();
+        assert_eq!(shifts, vec![0.0, 56.0, 56.0, 0.0]);
+        let order: Vec<usize> = (0..4).map(|i| reordered_index(i, 3, 1)).collect();
+        assert_eq!(order, vec![0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_virtual_list_selection_model() {
+        let mut list = rows(100);
+        list.select_only(5);
+        list.set_selected(42, true);
+        list.set_selected(500, true);
+        assert_eq!(list.selected_rows().collect::<Vec<_>>(), vec![5, 42]);
+
+        list.select_only(7);
+        assert_eq!(list.selected_rows().collect::<Vec<_>>(), vec![7]);
+
+        list.set_selected(90, true);
+        list.set_row_count(50);
+        assert_eq!(list.selected_rows().collect::<Vec<_>>(), vec![7]);
     }
 }
@@ -6,13 +6,19 @@
 //! Reference: <https://m3.material.io/components/menus/overview>
 
 use bevy::prelude::*;
-use bevy::ui::BoxShadow;
+use bevy::ui::{BoxShadow, RelativeCursorPosition};
+use bevy::window::PrimaryWindow;
 
 use crate::{
     elevation::Elevation,
+    focus::{FocusAppExt, FocusManager, FocusableComponent, OverlayComponent},
+    fuzzy::{fuzzy_match, MatchHighlight},
+    icons::{IconStyle, MaterialIcon},
+    motion::OpenTransition,
+    popover::{Popover, PopoverAlign, PopoverAnchor, PopoverPlacement, PopoverSide},
     ripple::RippleHost,
     theme::MaterialTheme,
-    tokens::{CornerRadius, Spacing},
+    tokens::{CornerRadius, Duration, Easing, Spacing},
 };
 
 /// Plugin for the menu component
@@ -23,13 +29,27 @@ impl Plugin for MenuPlugin {
         app.add_message::<MenuOpenEvent>()
             .add_message::<MenuCloseEvent>()
             .add_message::<MenuItemSelectEvent>()
+            .register_focusable::<MaterialMenuItem>()
+            .register_overlay::<MaterialMenu>()
             .add_systems(
                 Update,
                 (
-                    menu_visibility_system,
-                    menu_shadow_system,
+                    (menu_transition_setup_system, menu_visibility_system).chain(),
+                    menu_popover_system,
                     menu_item_interaction_system,
+                    menu_item_keyboard_system,
                     menu_item_style_system,
+                    menu_filter_system,
+                    submenu_hover_system,
+                    submenu_keyboard_system,
+                    context_menu_setup_system,
+                    (
+                        menu_outside_click_system,
+                        context_menu_open_system,
+                        menu_chain_close_system,
+                    )
+                        .chain(),
+                    submenu_position_system,
                 ),
             );
     }
@@ -40,10 +60,18 @@ impl Plugin for MenuPlugin {
 pub struct MaterialMenu {
     /// Whether the menu is currently open
     pub open: bool,
-    /// Anchor corner for positioning
+    /// Where the menu opens relative to `popover_anchor`
     pub anchor: MenuAnchor,
+    /// Entity or point the menu is placed against (see [`MaterialMenu::anchored_to`]
+    /// and [`MaterialMenu::open_at`])
+    pub popover_anchor: Option<PopoverAnchor>,
+    /// Entity the menu is open for as a [`ContextMenu`]; cleared when it closes
+    pub context_target: Option<Entity>,
     /// Whether clicking outside closes the menu
     pub close_on_click_outside: bool,
+    /// Filter text: items whose label does not fuzzy-match it are hidden and the rest
+    /// are ordered best first (e.g. for a command palette)
+    pub filter: String,
 }
 
 impl MaterialMenu {
@@ -52,7 +80,10 @@ impl MaterialMenu {
         Self {
             open: false,
             anchor: MenuAnchor::default(),
+            popover_anchor: None,
+            context_target: None,
             close_on_click_outside: true,
+            filter: String::new(),
         }
     }
 
@@ -62,6 +93,20 @@ impl MaterialMenu {
         self
     }
 
+    /// Place the menu next to `entity` (e.g. the button opening it), flipping and
+    /// shifting it to stay inside the window
+    pub fn anchored_to(mut self, entity: Entity) -> Self {
+        self.popover_anchor = Some(PopoverAnchor::Entity(entity));
+        self
+    }
+
+    /// Open at `point` (window coordinates in logical pixels), e.g. the pointer
+    /// location
+    pub fn open_at(&mut self, point: Vec2) {
+        self.popover_anchor = Some(PopoverAnchor::Point(point));
+        self.open = true;
+    }
+
     /// Start open
     pub fn open(mut self) -> Self {
         self.open = true;
@@ -74,6 +119,14 @@ impl MaterialMenu {
         self
     }
 
+    /// Set the filter text
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        let filter = filter.into();
+        if self.filter != filter {
+            self.filter = filter;
+        }
+    }
+
     /// Get the surface color
     pub fn surface_color(&self, theme: &MaterialTheme) -> Color {
         theme.surface_container
@@ -85,6 +138,12 @@ impl MaterialMenu {
     }
 }
 
+impl OverlayComponent for MaterialMenu {
+    fn is_overlay_open(&self) -> bool {
+        self.open
+    }
+}
+
 impl Default for MaterialMenu {
     fn default() -> Self {
         Self::new()
@@ -94,17 +153,30 @@ impl Default for MaterialMenu {
 /// Menu anchor position
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum MenuAnchor {
-    /// Top-left corner
+    /// Above the anchor, left edges aligned
     TopLeft,
-    /// Top-right corner
+    /// Above the anchor, right edges aligned
     TopRight,
-    /// Bottom-left corner (default dropdown position)
+    /// Below the anchor, left edges aligned (default dropdown position)
     #[default]
     BottomLeft,
-    /// Bottom-right corner
+    /// Below the anchor, right edges aligned
     BottomRight,
 }
 
+impl MenuAnchor {
+    /// The preferred [`PopoverPlacement`] for this anchor
+    pub fn placement(self) -> PopoverPlacement {
+        let (side, align) = match self {
+            Self::TopLeft => (PopoverSide::Above, PopoverAlign::Start),
+            Self::TopRight => (PopoverSide::Above, PopoverAlign::End),
+            Self::BottomLeft => (PopoverSide::Below, PopoverAlign::Start),
+            Self::BottomRight => (PopoverSide::Below, PopoverAlign::End),
+        };
+        PopoverPlacement::new(side, align)
+    }
+}
+
 /// Material menu item
 #[derive(Component)]
 pub struct MaterialMenuItem {
@@ -208,6 +280,12 @@ impl MaterialMenuItem {
     }
 }
 
+impl FocusableComponent for MaterialMenuItem {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event to open a menu
 #[derive(Event, bevy::prelude::Message)]
 pub struct MenuOpenEvent {
@@ -225,46 +303,119 @@ pub struct MenuCloseEvent {
 pub struct MenuItemSelectEvent {
     pub menu_entity: Entity,
     pub item_entity: Entity,
+    /// Items leading to the selected one, from the root menu down (the selected
+    /// item last); a single entry for items of a root menu
+    pub path: Vec<Entity>,
+    /// Labels of the `path` items, e.g. `["File", "Export", "PNG"]`
+    pub labels: Vec<String>,
+    /// Entity the menu was opened for as a [`ContextMenu`]
+    pub target: Option<Entity>,
 }
 
 /// Menu dimensions
 pub const MENU_MIN_WIDTH: f32 = 112.0;
 pub const MENU_MAX_WIDTH: f32 = 280.0;
 pub const MENU_ITEM_HEIGHT: f32 = 48.0;
+/// Seconds the pointer rests on an item before its submenu opens (or a sibling's closes)
+pub const SUBMENU_HOVER_DELAY: f32 = 0.2;
+/// `GlobalZIndex` of submenus (plus their nesting depth), above dialog scrims
+pub const SUBMENU_Z_INDEX: i32 = 1100;
+
+/// Give every menu an [`OpenTransition`] with the short menu enter/exit timings.
+fn menu_transition_setup_system(
+    mut commands: Commands,
+    menus: Query<Entity, (With<MaterialMenu>, Without<OpenTransition>)>,
+) {
+    for entity in menus.iter() {
+        commands.entity(entity).insert(
+            OpenTransition::new(false)
+                .with_durations(Duration::SHORT4, Duration::SHORT2)
+                .with_easing(Easing::EmphasizedDecelerate, Easing::StandardAccelerate),
+        );
+    }
+}
+
+/// Run the menu enter/exit transition: the surface is revealed vertically from its
+/// top edge while fading in. The menu stays displayed until the exit finishes.
+///
+/// Menus spawned without a `UiTransform`, `BackgroundColor` or `BoxShadow` are still
+/// shown and hidden, just without that part of the animation.
+fn menu_visibility_system(
+    time: Res<Time>,
+    theme: Option<Res<MaterialTheme>>,
+    mut menus: Query<(
+        &MaterialMenu,
+        &mut OpenTransition,
+        &mut Node,
+        &ComputedNode,
+        Option<&mut UiTransform>,
+        Option<&mut BackgroundColor>,
+        Option<&mut BoxShadow>,
+    )>,
+) {
+    for (menu, mut transition, mut node, computed, transform, bg, shadow) in menus.iter_mut() {
+        if transition.open != menu.open {
+            transition.set_open(menu.open);
+        }
+        let animating = transition.is_animating();
+        if animating {
+            transition.update(time.delta_secs());
+        }
 
-/// System to handle menu visibility
-fn menu_visibility_system(mut menus: Query<(&MaterialMenu, &mut Node), Changed<MaterialMenu>>) {
-    for (menu, mut node) in menus.iter_mut() {
-        node.display = if menu.open {
+        let display = if transition.is_visible() {
             Display::Flex
         } else {
             Display::None
         };
-    }
-}
+        if node.display != display {
+            node.display = display;
+        }
+        if !animating {
+            continue;
+        }
 
-/// System to update menu shadows using native BoxShadow
-fn menu_shadow_system(mut menus: Query<(&MaterialMenu, &mut BoxShadow), Changed<MaterialMenu>>) {
-    for (menu, mut shadow) in menus.iter_mut() {
-        // Only show shadow when menu is open
-        if menu.open {
-            *shadow = menu.elevation().to_box_shadow();
-        } else {
-            *shadow = BoxShadow::default();
+        let value = transition.value();
+        if let Some(mut transform) = transform {
+            // Scale about the top edge rather than the center.
+            let height = computed.size().y * computed.inverse_scale_factor();
+            transform.scale = Vec2::new(1.0, value);
+            transform.translation = Val2::px(0.0, -(1.0 - value) * height / 2.0);
+        }
+        if let (Some(theme), Some(mut bg)) = (&theme, bg) {
+            let surface = menu.surface_color(theme);
+            *bg = BackgroundColor(surface.with_alpha(surface.alpha() * value));
+        }
+        if let Some(mut shadow) = shadow {
+            *shadow = if transition.is_visible() {
+                menu.elevation().to_box_shadow_with_alpha(value)
+            } else {
+                BoxShadow::default()
+            };
         }
     }
 }
 
 /// System to handle menu item interactions
+///
+/// Clicking an item with a submenu opens the submenu; clicking any other item selects
+/// it and closes its whole menu chain.
 fn menu_item_interaction_system(
-    mut interaction_query: Query<
-        (Entity, &Interaction, &mut MaterialMenuItem, &ChildOf),
-        (Changed<Interaction>, With<MaterialMenuItem>),
-    >,
-    menus: Query<Entity, With<MaterialMenu>>,
+    mut item_queries: ParamSet<(
+        Query<
+            (Entity, &Interaction, &mut MaterialMenuItem, &ChildOf),
+            (Changed<Interaction>, With<MaterialMenuItem>),
+        >,
+        Query<&MaterialMenuItem>,
+    )>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    mut menus: Query<&mut MaterialMenu>,
     mut select_events: MessageWriter<MenuItemSelectEvent>,
+    mut close_events: MessageWriter<MenuCloseEvent>,
 ) {
-    for (entity, interaction, mut item, parent) in interaction_query.iter_mut() {
+    let mut selected = Vec::new();
+
+    for (entity, interaction, mut item, parent) in item_queries.p0().iter_mut() {
         if item.disabled {
             continue;
         }
@@ -274,14 +425,11 @@ fn menu_item_interaction_system(
                 item.pressed = true;
                 item.hovered = false;
 
-                if !item.has_submenu {
-                    // Find the menu ancestor
-                    if let Ok(menu_entity) = menus.get(parent.parent()) {
-                        select_events.write(MenuItemSelectEvent {
-                            menu_entity,
-                            item_entity: entity,
-                        });
-                    }
+                let menu_entity = parent.parent();
+                if item.has_submenu {
+                    show_submenu(menu_entity, Some(entity), &children, &mut menus);
+                } else if menus.contains(menu_entity) {
+                    selected.push((menu_entity, entity));
                 }
             }
             Interaction::Hovered => {
@@ -294,6 +442,70 @@ fn menu_item_interaction_system(
             }
         }
     }
+
+    let items = item_queries.p1();
+    for (menu_entity, item_entity) in selected {
+        select_menu_item(
+            menu_entity,
+            item_entity,
+            &items,
+            &parents,
+            &mut menus,
+            &mut select_events,
+            &mut close_events,
+        );
+    }
+}
+
+/// System to select the focused menu item with Space / Enter
+///
+/// An item with a submenu opens it and moves focus to its first item instead.
+fn menu_item_keyboard_system(
+    mut focus: ResMut<FocusManager>,
+    items: Query<&MaterialMenuItem>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    mut menus: Query<&mut MaterialMenu>,
+    mut select_events: MessageWriter<MenuItemSelectEvent>,
+    mut close_events: MessageWriter<MenuCloseEvent>,
+) {
+    if !focus.activation_requested() {
+        return;
+    }
+
+    let Some(item_entity) = focus.focused() else {
+        return;
+    };
+
+    let (Ok(item), Ok(parent)) = (items.get(item_entity), parents.get(item_entity)) else {
+        return;
+    };
+
+    if item.disabled {
+        return;
+    }
+
+    let menu_entity = parent.parent();
+    if item.has_submenu {
+        if let Some(submenu) = show_submenu(menu_entity, Some(item_entity), &children, &mut menus) {
+            if let Some(first) = first_enabled_item(submenu, &children, &items) {
+                focus.focus_visible(first);
+            }
+        }
+        return;
+    }
+
+    if menus.contains(menu_entity) {
+        select_menu_item(
+            menu_entity,
+            item_entity,
+            &items,
+            &parents,
+            &mut menus,
+            &mut select_events,
+            &mut close_events,
+        );
+    }
 }
 
 /// System to update menu item styles
@@ -308,6 +520,614 @@ fn menu_item_style_system(
     }
 }
 
+/// Hide menu items not matching the menu's filter, order the rest best first and
+/// emphasize the matched characters of their labels.
+fn menu_filter_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    mut original_orders: Local<Vec<(Entity, Vec<Entity>)>>,
+    mut menus: Query<(Entity, &MaterialMenu, &mut Children), Changed<MaterialMenu>>,
+    mut items: Query<(&MaterialMenuItem, &mut Node, Option<&Children>), Without<MaterialMenu>>,
+    mut dividers: Query<
+        &mut Node,
+        (
+            With<MenuDivider>,
+            Without<MaterialMenuItem>,
+            Without<MaterialMenu>,
+        ),
+    >,
+    mut labels: Query<Option<&mut MatchHighlight>, With<MenuItemLabel>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (menu_entity, menu, mut children) in menus.iter_mut() {
+        let original = original_orders
+            .iter()
+            .position(|(entity, _)| *entity == menu_entity);
+        let filtering = !menu.filter.trim().is_empty();
+        if !filtering && original.is_none() {
+            continue;
+        }
+
+        // Remember the authored order while filtered, to restore it afterwards.
+        let order = match original {
+            Some(i) => original_orders[i].1.clone(),
+            None => {
+                let order: Vec<Entity> = children.iter().collect();
+                original_orders.push((menu_entity, order.clone()));
+                order
+            }
+        };
+        let position =
+            |entity: &Entity| order.iter().position(|e| e == entity).unwrap_or(usize::MAX);
+
+        let mut scores: Vec<(Entity, i32)> = Vec::new();
+        for child in children.iter() {
+            if let Ok(mut node) = dividers.get_mut(child) {
+                let display = if filtering {
+                    Display::None
+                } else {
+                    Display::Flex
+                };
+                if node.display != display {
+                    node.display = display;
+                }
+                continue;
+            }
+            let Ok((item, mut node, item_children)) = items.get_mut(child) else {
+                continue;
+            };
+
+            let found = fuzzy_match(&menu.filter, &item.label);
+            if let Some(m) = &found {
+                scores.push((child, m.score));
+            }
+            let display = if found.is_some() {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            if node.display != display {
+                node.display = display;
+            }
+
+            let ranges = found.map(|m| m.ranges).unwrap_or_default();
+            for label in item_children.into_iter().flatten() {
+                match labels.get_mut(*label) {
+                    Ok(Some(mut highlight)) => {
+                        if highlight.ranges != ranges {
+                            highlight.ranges = ranges.clone();
+                        }
+                    }
+                    Ok(None) => {
+                        commands.entity(*label).insert(
+                            MatchHighlight::new(item.label.clone(), theme.primary)
+                                .with_ranges(ranges.clone()),
+                        );
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+
+        if filtering {
+            // Matches best first (ties keep the authored order), everything else after.
+            let key = |entity: &Entity| {
+                let score = scores
+                    .iter()
+                    .find(|(e, _)| e == entity)
+                    .map_or(i32::MIN, |(_, score)| *score);
+                (std::cmp::Reverse(score), position(entity))
+            };
+            if !children.iter().map(|entity| key(&entity)).is_sorted() {
+                children.sort_by_key(key);
+            }
+        } else {
+            if !children.iter().map(|entity| position(&entity)).is_sorted() {
+                children.sort_by_key(position);
+            }
+            original_orders.retain(|(entity, _)| *entity != menu_entity);
+        }
+    }
+}
+
+/// The submenu owned by a menu item (a [`MaterialMenu`] child of the item)
+fn submenu_of(
+    item: Entity,
+    children: &Query<&Children>,
+    menus: &Query<&mut MaterialMenu>,
+) -> Option<Entity> {
+    children
+        .get(item)
+        .ok()?
+        .iter()
+        .find(|child| menus.contains(*child))
+}
+
+/// The menu item owning `menu`, if it is a submenu
+fn owner_item<D: bevy::ecs::query::QueryData>(
+    menu: Entity,
+    items: &Query<D>,
+    parents: &Query<&ChildOf>,
+) -> Option<Entity> {
+    parents
+        .get(menu)
+        .ok()
+        .map(ChildOf::parent)
+        .filter(|parent| items.contains(*parent))
+}
+
+/// Open the submenu of `item` (in `menu`) and close the submenus of its siblings;
+/// `None` closes them all. Returns the opened submenu.
+fn show_submenu(
+    menu: Entity,
+    item: Option<Entity>,
+    children: &Query<&Children>,
+    menus: &mut Query<&mut MaterialMenu>,
+) -> Option<Entity> {
+    let siblings: Vec<Entity> = children.get(menu).ok()?.iter().collect();
+    let mut opened = None;
+    for sibling in siblings {
+        let Some(submenu) = submenu_of(sibling, children, menus) else {
+            continue;
+        };
+        let open = item == Some(sibling);
+        if let Ok(mut submenu_state) = menus.get_mut(submenu) {
+            if submenu_state.open != open {
+                submenu_state.open = open;
+            }
+        }
+        if open {
+            opened = Some(submenu);
+        }
+    }
+    opened
+}
+
+/// Close the root menu of the chain `menu` belongs to (its submenus follow in
+/// `menu_chain_close_system`). Returns the root if it was open.
+fn close_menu_chain(
+    menu: Entity,
+    items: &Query<&MaterialMenuItem>,
+    parents: &Query<&ChildOf>,
+    menus: &mut Query<&mut MaterialMenu>,
+) -> Option<Entity> {
+    let root = root_menu(menu, items, parents, menus);
+    let mut root_menu = menus.get_mut(root).ok()?;
+    if !root_menu.open {
+        return None;
+    }
+    root_menu.open = false;
+    Some(root)
+}
+
+/// The menu at the top of the chain `menu` belongs to
+fn root_menu(
+    menu: Entity,
+    items: &Query<&MaterialMenuItem>,
+    parents: &Query<&ChildOf>,
+    menus: &Query<&mut MaterialMenu>,
+) -> Entity {
+    let mut root = menu;
+    while let Some(parent_menu) = owner_item(root, items, parents)
+        .and_then(|item| parents.get(item).ok())
+        .map(ChildOf::parent)
+        .filter(|parent| menus.contains(*parent))
+    {
+        root = parent_menu;
+    }
+    root
+}
+
+/// Report the selection of `item` (in `menu`) and close its menu chain
+fn select_menu_item(
+    menu: Entity,
+    item: Entity,
+    items: &Query<&MaterialMenuItem>,
+    parents: &Query<&ChildOf>,
+    menus: &mut Query<&mut MaterialMenu>,
+    select_events: &mut MessageWriter<MenuItemSelectEvent>,
+    close_events: &mut MessageWriter<MenuCloseEvent>,
+) {
+    let (path, labels) = item_path(item, items, parents);
+    let target = menus
+        .get(root_menu(menu, items, parents, menus))
+        .ok()
+        .and_then(|root| root.context_target);
+    if let Some(root) = close_menu_chain(menu, items, parents, menus) {
+        close_events.write(MenuCloseEvent { entity: root });
+    }
+    select_events.write(MenuItemSelectEvent {
+        menu_entity: menu,
+        item_entity: item,
+        path,
+        labels,
+        target,
+    });
+}
+
+/// Items from the root menu down to `item`, with their labels
+fn item_path(
+    item: Entity,
+    items: &Query<&MaterialMenuItem>,
+    parents: &Query<&ChildOf>,
+) -> (Vec<Entity>, Vec<String>) {
+    let mut path = vec![item];
+    let mut current = item;
+    // item -> its menu -> the item owning that menu -> ...
+    while let Some(owner) = parents
+        .get(current)
+        .ok()
+        .and_then(|menu| owner_item(menu.parent(), items, parents))
+    {
+        path.push(owner);
+        current = owner;
+    }
+    path.reverse();
+    let labels = path
+        .iter()
+        .map(|entity| {
+            items
+                .get(*entity)
+                .map(|item| item.label.clone())
+                .unwrap_or_default()
+        })
+        .collect();
+    (path, labels)
+}
+
+/// First enabled item of a menu
+fn first_enabled_item(
+    menu: Entity,
+    children: &Query<&Children>,
+    items: &Query<&MaterialMenuItem>,
+) -> Option<Entity> {
+    children
+        .get(menu)
+        .ok()?
+        .iter()
+        .find(|child| items.get(*child).is_ok_and(|item| !item.disabled))
+}
+
+/// Pointer rest on a menu item, for [`submenu_hover_system`]
+#[derive(Default)]
+struct SubmenuHover {
+    item: Option<Entity>,
+    elapsed: f32,
+    applied: bool,
+}
+
+/// Open an item's submenu once the pointer has rested on it for
+/// [`SUBMENU_HOVER_DELAY`], closing the submenus of its siblings. The delay lets the
+/// pointer cross other items on its way into an open submenu.
+fn submenu_hover_system(
+    time: Res<Time>,
+    mut hover: Local<SubmenuHover>,
+    items: Query<(Entity, &MaterialMenuItem, &Interaction, &ChildOf)>,
+    children: Query<&Children>,
+    mut menus: Query<&mut MaterialMenu>,
+) {
+    let hovered = items
+        .iter()
+        .find(|(_, _, interaction, _)| **interaction == Interaction::Hovered);
+    let Some((entity, item, _, parent)) = hovered else {
+        *hover = SubmenuHover::default();
+        return;
+    };
+
+    if hover.item != Some(entity) {
+        *hover = SubmenuHover {
+            item: Some(entity),
+            ..default()
+        };
+    } else {
+        hover.elapsed += time.delta_secs();
+    }
+    if hover.applied || hover.elapsed < SUBMENU_HOVER_DELAY {
+        return;
+    }
+    hover.applied = true;
+
+    let menu = parent.parent();
+    if menus.get(menu).is_ok_and(|menu| menu.open) {
+        let target = (item.has_submenu && !item.disabled).then_some(entity);
+        show_submenu(menu, target, &children, &mut menus);
+    }
+}
+
+/// Right arrow opens the focused item's submenu and focuses its first item; Left
+/// arrow closes the submenu holding focus and returns focus to its item.
+fn submenu_keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<FocusManager>,
+    items: Query<&MaterialMenuItem>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    mut menus: Query<&mut MaterialMenu>,
+) {
+    let Some(focused) = focus.focused() else {
+        return;
+    };
+    let (Ok(item), Ok(parent)) = (items.get(focused), parents.get(focused)) else {
+        return;
+    };
+    let menu = parent.parent();
+
+    if keys.just_pressed(KeyCode::ArrowRight) && item.has_submenu && !item.disabled {
+        if let Some(submenu) = show_submenu(menu, Some(focused), &children, &mut menus) {
+            if let Some(first) = first_enabled_item(submenu, &children, &items) {
+                focus.focus_visible(first);
+            }
+        }
+    } else if keys.just_pressed(KeyCode::ArrowLeft) {
+        let Some(owner) = owner_item(menu, &items, &parents) else {
+            return;
+        };
+        if let Ok(mut submenu) = menus.get_mut(menu) {
+            submenu.open = false;
+        }
+        focus.focus_visible(owner);
+    }
+}
+
+/// Close open menus (with their submenus) when the pointer is pressed outside of
+/// them, unless they were opened with [`MaterialMenu::no_close_on_outside`].
+///
+/// Only menus already open in the previous frame are closed, so the click that
+/// opens a menu does not close it right away. Menus without a
+/// [`RelativeCursorPosition`] (e.g. not spawned through [`MenuBuilder`]) are left open.
+fn menu_outside_click_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut open_before: Local<Vec<Entity>>,
+    items: Query<&MaterialMenuItem>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    cursors: Query<Option<&RelativeCursorPosition>, With<MaterialMenu>>,
+    mut menus: Query<(Entity, &mut MaterialMenu)>,
+    mut close_events: MessageWriter<MenuCloseEvent>,
+) {
+    let is_root = |entity: Entity| owner_item(entity, &items, &parents).is_none();
+
+    let pressed = mouse.any_just_pressed([MouseButton::Left, MouseButton::Right])
+        || touches.any_just_pressed();
+    if pressed {
+        for (entity, mut menu) in menus.iter_mut() {
+            if !menu.open
+                || !menu.close_on_click_outside
+                || !open_before.contains(&entity)
+                || !is_root(entity)
+            {
+                continue;
+            }
+
+            // The chain: the root and every submenu inside it.
+            let mut inside = false;
+            let mut known = true;
+            for chain_menu in std::iter::once(entity).chain(children.iter_descendants(entity)) {
+                match cursors.get(chain_menu) {
+                    Ok(Some(cursor)) => inside |= cursor.cursor_over(),
+                    Ok(None) => known = false,
+                    Err(_) => {}
+                }
+            }
+            if known && !inside {
+                menu.open = false;
+                close_events.write(MenuCloseEvent { entity });
+            }
+        }
+    }
+
+    open_before.clear();
+    open_before.extend(
+        menus
+            .iter()
+            .filter(|(entity, menu)| menu.open && is_root(*entity))
+            .map(|(entity, _)| entity),
+    );
+}
+
+/// Close the submenus of menus that were closed, and forget their context target so
+/// a later plain `open` is not reported for it.
+fn menu_chain_close_system(
+    children: Query<&Children>,
+    mut menus: Query<(Entity, &mut MaterialMenu)>,
+) {
+    let mut closed = Vec::new();
+    for (entity, mut menu) in menus.iter_mut() {
+        if !menu.is_changed() || menu.open {
+            continue;
+        }
+        if menu.context_target.is_some() {
+            menu.context_target = None;
+        }
+        closed.push(entity);
+    }
+
+    for menu in closed {
+        for descendant in children.iter_descendants(menu) {
+            if let Ok((_, mut submenu)) = menus.get_mut(descendant) {
+                if submenu.open {
+                    submenu.open = false;
+                }
+            }
+        }
+    }
+}
+
+/// Place open submenus beside their item with a [`Popover`] (which flips them to stay
+/// inside the window) and stack them above their parent menus.
+fn submenu_position_system(
+    mut commands: Commands,
+    items: Query<(), With<MaterialMenuItem>>,
+    parents: Query<&ChildOf>,
+    submenus: Query<(
+        Entity,
+        &MaterialMenu,
+        &ChildOf,
+        Has<Popover>,
+        Option<&GlobalZIndex>,
+    )>,
+) {
+    for (entity, menu, parent, has_popover, z_index) in submenus.iter() {
+        let item = parent.parent();
+        if !menu.open || !items.contains(item) {
+            continue;
+        }
+
+        if !has_popover {
+            // Offset by the menu's padding so the first item lines up with the parent item
+            commands.entity(entity).insert(
+                Popover::new(item)
+                    .with_placement(PopoverPlacement::end())
+                    .with_align_offset(-Spacing::SMALL),
+            );
+        }
+
+        let depth = parents
+            .iter_ancestors(entity)
+            .filter(|ancestor| items.contains(*ancestor))
+            .count() as i32;
+        let z = GlobalZIndex(SUBMENU_Z_INDEX + depth);
+        if z_index != Some(&z) {
+            commands.entity(entity).insert(z);
+        }
+    }
+}
+
+/// Keep the [`Popover`] of menus opened from an anchor entity or point in sync with
+/// their [`MenuAnchor`].
+fn menu_popover_system(
+    mut commands: Commands,
+    mut menus: Query<(Entity, &MaterialMenu, Option<&mut Popover>), Changed<MaterialMenu>>,
+) {
+    for (entity, menu, popover) in menus.iter_mut() {
+        let Some(anchor) = menu.popover_anchor else {
+            continue;
+        };
+        let placement = menu.anchor.placement();
+        match popover {
+            Some(mut popover) => {
+                if popover.anchor != anchor || popover.placement != placement {
+                    popover.anchor = anchor;
+                    popover.placement = placement;
+                }
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(Popover::new(anchor).with_placement(placement));
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Context menus
+// ============================================================================
+
+/// Seconds a touch must be held on a [`ContextMenu`] target to open its menu
+pub const CONTEXT_MENU_LONG_PRESS: f32 = 0.5;
+/// Distance (logical pixels) a touch may move before a long press is cancelled
+pub const CONTEXT_MENU_TOUCH_SLOP: f32 = 10.0;
+
+/// Opens a [`MaterialMenu`] at the pointer when the entity is right-clicked, or
+/// long-pressed on a touch screen.
+///
+/// The menu is usually spawned once and shared by several targets; the entity it was
+/// opened for is stored in [`MaterialMenu::context_target`] and passed on in
+/// [`MenuItemSelectEvent::target`]. When targets are nested, the topmost one wins.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ContextMenu {
+    /// The menu to open
+    pub menu: Entity,
+}
+
+impl ContextMenu {
+    /// Open `menu` as the context menu of this entity
+    pub fn new(menu: Entity) -> Self {
+        Self { menu }
+    }
+}
+
+/// Touch being held on a potential context menu target
+struct LongPress {
+    id: u64,
+    start: Vec2,
+    elapsed: f32,
+}
+
+/// Track the pointer over context menu targets.
+fn context_menu_setup_system(
+    mut commands: Commands,
+    targets: Query<Entity, (Added<ContextMenu>, Without<RelativeCursorPosition>)>,
+) {
+    for entity in targets.iter() {
+        commands
+            .entity(entity)
+            .insert(RelativeCursorPosition::default());
+    }
+}
+
+/// Open context menus on right-click or long press, at the pointer location.
+fn context_menu_open_system(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut long_press: Local<Option<LongPress>>,
+    targets: Query<(Entity, &ContextMenu, &RelativeCursorPosition, &ComputedNode)>,
+    mut menus: Query<&mut MaterialMenu>,
+) {
+    let mut point = None;
+
+    if mouse.just_pressed(MouseButton::Right) {
+        point = windows
+            .single()
+            .ok()
+            .and_then(|window| window.cursor_position());
+    }
+
+    if let Some(touch) = touches.iter_just_pressed().next() {
+        *long_press = Some(LongPress {
+            id: touch.id(),
+            start: touch.position(),
+            elapsed: 0.0,
+        });
+    }
+    if let Some(press) = long_press.as_mut() {
+        let held = touches
+            .get_pressed(press.id)
+            .map(|touch| touch.position())
+            .filter(|position| position.distance(press.start) <= CONTEXT_MENU_TOUCH_SLOP);
+        press.elapsed += time.delta_secs();
+        match held {
+            Some(position) if press.elapsed >= CONTEXT_MENU_LONG_PRESS => {
+                point = Some(position);
+                *long_press = None;
+            }
+            Some(_) => {}
+            None => *long_press = None,
+        }
+    }
+
+    let Some(point) = point else {
+        return;
+    };
+    let target = targets
+        .iter()
+        .filter(|(_, _, cursor, _)| cursor.cursor_over())
+        .max_by_key(|(_, _, _, node)| node.stack_index());
+    let Some((entity, context_menu, _, _)) = target else {
+        return;
+    };
+
+    if let Ok(mut menu) = menus.get_mut(context_menu.menu) {
+        menu.context_target = Some(entity);
+        menu.open_at(point);
+    }
+}
+
 /// Builder for menus
 pub struct MenuBuilder {
     menu: MaterialMenu,
@@ -327,6 +1147,12 @@ impl MenuBuilder {
         self
     }
 
+    /// Place the menu next to `entity`
+    pub fn anchored_to(mut self, entity: Entity) -> Self {
+        self.menu.popover_anchor = Some(PopoverAnchor::Entity(entity));
+        self
+    }
+
     /// Start open
     pub fn open(mut self) -> Self {
         self.menu.open = true;
@@ -358,6 +1184,8 @@ impl MenuBuilder {
             BorderRadius::all(Val::Px(CornerRadius::EXTRA_SMALL)),
             // Native Bevy 0.17 shadow support (starts hidden since menu is closed)
             BoxShadow::default(),
+            // Tracks the pointer for closing on outside clicks
+            RelativeCursorPosition::default(),
         )
     }
 }
@@ -443,6 +1271,11 @@ impl MenuItemBuilder {
 #[derive(Component)]
 pub struct MenuDivider;
 
+/// Marker for the label text of a menu item (matched characters are emphasized
+/// while the menu is filtered)
+#[derive(Component)]
+pub struct MenuItemLabel;
+
 /// Create a menu divider
 pub fn create_menu_divider(theme: &MaterialTheme) -> impl Bundle {
     (
@@ -476,6 +1309,14 @@ pub trait SpawnMenuChild {
     /// Spawn a menu item with full builder control
     fn spawn_menu_item_with(&mut self, theme: &MaterialTheme, builder: MenuItemBuilder);
 
+    /// Spawn a menu item that opens a submenu of the items added by `with_items`
+    fn spawn_submenu_item(
+        &mut self,
+        theme: &MaterialTheme,
+        label: impl Into<String>,
+        with_items: impl FnOnce(&mut ChildSpawnerCommands),
+    );
+
     /// Spawn a menu divider
     fn spawn_menu_divider(&mut self, theme: &MaterialTheme);
 }
@@ -497,6 +1338,7 @@ impl SpawnMenuChild for ChildSpawnerCommands<'_> {
         self.spawn(MenuItemBuilder::new(&label_str).build(theme))
             .with_children(|item| {
                 item.spawn((
+                    MenuItemLabel,
                     Text::new(&label_str),
                     TextFont {
                         font_size: 14.0,
@@ -513,6 +1355,7 @@ impl SpawnMenuChild for ChildSpawnerCommands<'_> {
 
         self.spawn(builder.build(theme)).with_children(|item| {
             item.spawn((
+                MenuItemLabel,
                 Text::new(&label_str),
                 TextFont {
                     font_size: 14.0,
@@ -523,7 +1366,190 @@ impl SpawnMenuChild for ChildSpawnerCommands<'_> {
         });
     }
 
+    fn spawn_submenu_item(
+        &mut self,
+        theme: &MaterialTheme,
+        label: impl Into<String>,
+        with_items: impl FnOnce(&mut ChildSpawnerCommands),
+    ) {
+        let label_str = label.into();
+        let label_color = theme.on_surface;
+        let icon_color = theme.on_surface_variant;
+
+        self.spawn(MenuItemBuilder::new(&label_str).submenu().build(theme))
+            .with_children(|item| {
+                item.spawn((
+                    MenuItemLabel,
+                    Text::new(&label_str),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(label_color),
+                    Node {
+                        flex_grow: 1.0,
+                        ..default()
+                    },
+                ));
+                item.spawn((
+                    MaterialIcon::chevron_right(),
+                    IconStyle::outlined().with_color(icon_color).with_size(24.0),
+                ));
+                // Placed beside the item by `submenu_position_system`
+                item.spawn(MenuBuilder::new().build(theme))
+                    .with_children(with_items);
+            });
+    }
+
     fn spawn_menu_divider(&mut self, theme: &MaterialTheme) {
         self.spawn(create_menu_divider(theme));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::input::mouse::MouseButtonInput;
+    use bevy::input::touch::{TouchInput, TouchPhase};
+    use bevy::input::{ButtonState, InputPlugin};
+    use bevy::time::TimeUpdateStrategy;
+
+    use crate::focus::FocusPlugin;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, FocusPlugin, MenuPlugin))
+            .init_resource::<MaterialTheme>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(200),
+            ));
+        app
+    }
+
+    /// A window, a context menu with one item and a target under the pointer
+    fn spawn_context_menu(app: &mut App) -> (Entity, Entity, Entity, Entity) {
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        let menu = app
+            .world_mut()
+            .spawn((MaterialMenu::new(), Node::default()))
+            .id();
+        let item = app
+            .world_mut()
+            .spawn((
+                MaterialMenuItem::new("Rename"),
+                Interaction::None,
+                ChildOf(menu),
+            ))
+            .id();
+        let target = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                ContextMenu::new(menu),
+                RelativeCursorPosition {
+                    cursor_over: true,
+                    normalized: None,
+                },
+            ))
+            .id();
+        app.update();
+        (window, menu, item, target)
+    }
+
+    fn selections(app: &App) -> Vec<(Entity, Option<Entity>)> {
+        let messages = app.world().resource::<Messages<MenuItemSelectEvent>>();
+        messages
+            .get_cursor()
+            .read(messages)
+            .map(|event| (event.item_entity, event.target))
+            .collect()
+    }
+
+    #[test]
+    fn test_right_click_opens_at_pointer_for_target() {
+        let mut app = test_app();
+        let (window, menu, item, target) = spawn_context_menu(&mut app);
+
+        let point = Vec2::new(120.0, 80.0);
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .set_cursor_position(Some(point));
+        app.world_mut().write_message(MouseButtonInput {
+            button: MouseButton::Right,
+            state: ButtonState::Pressed,
+            window,
+        });
+        app.update();
+
+        let opened = app.world().get::<MaterialMenu>(menu).unwrap();
+        assert!(opened.open);
+        assert_eq!(opened.popover_anchor, Some(PopoverAnchor::Point(point)));
+        assert_eq!(opened.context_target, Some(target));
+
+        app.world_mut().write_message(MouseButtonInput {
+            button: MouseButton::Right,
+            state: ButtonState::Released,
+            window,
+        });
+        *app.world_mut().get_mut::<Interaction>(item).unwrap() = Interaction::Pressed;
+        app.update();
+
+        assert_eq!(selections(&app), vec![(item, Some(target))]);
+        let closed = app.world().get::<MaterialMenu>(menu).unwrap();
+        assert!(!closed.open);
+        assert_eq!(closed.context_target, None);
+
+        // Opened normally afterwards, selections are no longer for the target
+        app.world_mut().get_mut::<MaterialMenu>(menu).unwrap().open = true;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(item).unwrap() = Interaction::None;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(item).unwrap() = Interaction::Pressed;
+        app.update();
+        assert_eq!(selections(&app).last(), Some(&(item, None)));
+    }
+
+    #[test]
+    fn test_long_press_opens_at_touch() {
+        let mut app = test_app();
+        let (window, menu, _, target) = spawn_context_menu(&mut app);
+
+        let point = Vec2::new(60.0, 40.0);
+        app.world_mut().write_message(TouchInput {
+            phase: TouchPhase::Started,
+            position: point,
+            window,
+            force: None,
+            id: 1,
+        });
+        app.update();
+        assert!(!app.world().get::<MaterialMenu>(menu).unwrap().open);
+
+        // Held past CONTEXT_MENU_LONG_PRESS
+        for _ in 0..3 {
+            app.update();
+        }
+        let opened = app.world().get::<MaterialMenu>(menu).unwrap();
+        assert!(opened.open);
+        assert_eq!(opened.popover_anchor, Some(PopoverAnchor::Point(point)));
+        assert_eq!(opened.context_target, Some(target));
+    }
+
+    #[test]
+    fn test_menu_anchor_placement() {
+        assert_eq!(MenuAnchor::default().placement(), PopoverPlacement::below());
+        assert_eq!(
+            MenuAnchor::TopRight.placement(),
+            PopoverPlacement::above().with_align(PopoverAlign::End)
+        );
+
+        let anchor = Entity::PLACEHOLDER;
+        let menu = MaterialMenu::new().anchored_to(anchor);
+        assert_eq!(menu.popover_anchor, Some(PopoverAnchor::Entity(anchor)));
+    }
+}
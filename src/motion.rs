@@ -462,6 +462,98 @@ impl AnimatedValue {
     }
 }
 
+// ============================================================================
+// Open Transition
+// ============================================================================
+
+/// Enter/exit transition of a surface that opens and closes, such as a dialog
+/// or menu.
+///
+/// `progress` runs linearly from 0 (closed) to 1 (open); [`OpenTransition::value`]
+/// applies the enter easing while opening and the exit easing while closing.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OpenTransition {
+    /// Linear progress, 0 = closed, 1 = open
+    pub progress: f32,
+    /// Whether the surface is opening (or open)
+    pub open: bool,
+    /// Enter duration in seconds
+    pub enter_duration: f32,
+    /// Exit duration in seconds
+    pub exit_duration: f32,
+    /// Easing applied while opening
+    pub enter_easing: Easing,
+    /// Easing applied while closing
+    pub exit_easing: Easing,
+}
+
+impl OpenTransition {
+    /// Create a settled transition, fully open or fully closed
+    pub fn new(open: bool) -> Self {
+        Self {
+            progress: if open { 1.0 } else { 0.0 },
+            open,
+            enter_duration: Duration::MEDIUM2,
+            exit_duration: Duration::SHORT4,
+            enter_easing: Easing::EmphasizedDecelerate,
+            exit_easing: Easing::EmphasizedAccelerate,
+        }
+    }
+
+    /// Set the enter and exit durations
+    pub fn with_durations(mut self, enter: f32, exit: f32) -> Self {
+        self.enter_duration = enter;
+        self.exit_duration = exit;
+        self
+    }
+
+    /// Set the enter and exit easing curves
+    pub fn with_easing(mut self, enter: Easing, exit: Easing) -> Self {
+        self.enter_easing = enter;
+        self.exit_easing = exit;
+        self
+    }
+
+    /// Start opening or closing from the current progress
+    pub fn set_open(&mut self, open: bool) {
+        self.open = open;
+    }
+
+    /// Advance the transition
+    pub fn update(&mut self, dt: f32) {
+        if self.open {
+            self.progress = (self.progress + dt / self.enter_duration.max(f32::EPSILON)).min(1.0);
+        } else {
+            self.progress = (self.progress - dt / self.exit_duration.max(f32::EPSILON)).max(0.0);
+        }
+    }
+
+    /// Eased progress (0 = closed, 1 = open)
+    pub fn value(&self) -> f32 {
+        if self.progress <= 0.0 || self.progress >= 1.0 {
+            self.progress.clamp(0.0, 1.0)
+        } else if self.open {
+            ease(self.progress, self.enter_easing)
+        } else {
+            1.0 - ease(1.0 - self.progress, self.exit_easing)
+        }
+    }
+
+    /// Whether the transition is still running
+    pub fn is_animating(&self) -> bool {
+        if self.open {
+            self.progress < 1.0
+        } else {
+            self.progress > 0.0
+        }
+    }
+
+    /// Whether the surface should be displayed (open or still exiting)
+    pub fn is_visible(&self) -> bool {
+        self.open || self.progress > 0.0
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -525,6 +617,27 @@ mod tests {
         assert!((layer.target_opacity - StateLayer::PRESSED_OPACITY).abs() < 0.001);
     }
 
+    #[test]
+    fn test_open_transition() {
+        let mut transition = OpenTransition::new(false).with_durations(0.4, 0.2);
+        assert!(!transition.is_visible());
+
+        transition.set_open(true);
+        transition.update(0.2);
+        assert!(transition.is_animating());
+        assert!(transition.value() > 0.5);
+        transition.update(0.2);
+        assert_eq!(transition.value(), 1.0);
+        assert!(!transition.is_animating());
+
+        transition.set_open(false);
+        transition.update(0.1);
+        assert!(transition.is_visible());
+        transition.update(0.1);
+        assert!(!transition.is_visible());
+        assert_eq!(transition.value(), 0.0);
+    }
+
     #[test]
     fn test_animated_value() {
         let mut value = AnimatedValue::new(0.0).with_duration(0.3);
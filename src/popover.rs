@@ -0,0 +1,518 @@
+//! Anchored positioning for overlays
+//!
+//! A [`Popover`] places an absolutely positioned node (a menu, a select dropdown, a
+//! tooltip...) next to an anchor entity, using the anchor's laid-out `ComputedNode` and
+//! `UiGlobalTransform`, or at a point such as the pointer location ([`PopoverAnchor`]):
+//!
+//! - [`PopoverPlacement`] is the preferred side of the anchor (above, below, start or
+//!   end) and the alignment along that side.
+//! - When the popover does not fit on the preferred side, it flips to the opposite one
+//!   (if that has more room) and its alignment flips as well.
+//! - It then shifts along the anchor's edge so that it stays inside the window.
+//!
+//! Positions are written to the node's `left`/`top`, relative to its parent, so the
+//! popover can stay wherever it is in the hierarchy.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_material_ui::popover::{Popover, PopoverAlign, PopoverPlacement};
+//!
+//! fn attach(mut commands: Commands, overlay: Entity, button: Entity) {
+//!     commands.entity(overlay).insert(
+//!         Popover::new(button)
+//!             .with_placement(PopoverPlacement::above().with_align(PopoverAlign::Center))
+//!             .with_gap(8.0),
+//!     );
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
+use bevy::window::PrimaryWindow;
+
+/// Plugin positioning [`Popover`] nodes
+pub struct PopoverPlugin;
+
+impl Plugin for PopoverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, popover_position_system);
+    }
+}
+
+/// Side of the anchor a popover is placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopoverSide {
+    /// Above the anchor
+    Above,
+    /// Below the anchor
+    #[default]
+    Below,
+    /// Before the anchor (to its left)
+    Start,
+    /// After the anchor (to its right)
+    End,
+}
+
+impl PopoverSide {
+    /// The side across the anchor
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Above => Self::Below,
+            Self::Below => Self::Above,
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+        }
+    }
+
+    /// Whether the popover is stacked vertically with the anchor
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Above | Self::Below)
+    }
+}
+
+/// Alignment of a popover along the side of its anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopoverAlign {
+    /// Left (or top) edges aligned
+    #[default]
+    Start,
+    /// Centered on the anchor
+    Center,
+    /// Right (or bottom) edges aligned
+    End,
+}
+
+impl PopoverAlign {
+    /// The alignment on the other edge (`Center` stays centered)
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::Center => Self::Center,
+            Self::End => Self::Start,
+        }
+    }
+}
+
+/// Preferred placement of a popover relative to its anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PopoverPlacement {
+    /// Side of the anchor
+    pub side: PopoverSide,
+    /// Alignment along that side
+    pub align: PopoverAlign,
+}
+
+impl PopoverPlacement {
+    /// Create a placement
+    pub const fn new(side: PopoverSide, align: PopoverAlign) -> Self {
+        Self { side, align }
+    }
+
+    /// Above the anchor, start-aligned
+    pub const fn above() -> Self {
+        Self::new(PopoverSide::Above, PopoverAlign::Start)
+    }
+
+    /// Below the anchor, start-aligned
+    pub const fn below() -> Self {
+        Self::new(PopoverSide::Below, PopoverAlign::Start)
+    }
+
+    /// Before the anchor, top-aligned
+    pub const fn start() -> Self {
+        Self::new(PopoverSide::Start, PopoverAlign::Start)
+    }
+
+    /// After the anchor, top-aligned
+    pub const fn end() -> Self {
+        Self::new(PopoverSide::End, PopoverAlign::Start)
+    }
+
+    /// Set the alignment
+    pub const fn with_align(mut self, align: PopoverAlign) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// What a popover is placed against
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PopoverAnchor {
+    /// A laid-out UI node
+    Entity(Entity),
+    /// A point in window coordinates (logical pixels), e.g. the cursor position
+    Point(Vec2),
+}
+
+impl From<Entity> for PopoverAnchor {
+    fn from(entity: Entity) -> Self {
+        Self::Entity(entity)
+    }
+}
+
+impl From<Vec2> for PopoverAnchor {
+    fn from(point: Vec2) -> Self {
+        Self::Point(point)
+    }
+}
+
+/// Positions an absolutely positioned node next to an anchor entity or point
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Popover {
+    /// What the popover is placed against
+    pub anchor: PopoverAnchor,
+    /// Preferred placement
+    pub placement: PopoverPlacement,
+    /// Distance between the anchor and the popover (logical pixels)
+    pub gap: f32,
+    /// Offset along the anchor's edge from the aligned position (logical pixels).
+    /// Positive values move into the anchor's span, negative ones overhang the aligned
+    /// edge; mirrored when the alignment flips.
+    pub align_offset: f32,
+    /// Distance kept from the window edges (logical pixels)
+    pub margin: f32,
+    /// Move to the opposite side (and alignment) when the preferred one lacks room
+    pub flip: bool,
+    /// Slide along the anchor's edge to stay inside the window
+    pub shift: bool,
+    /// Placement in use after flipping (updated by the positioning system)
+    pub resolved: PopoverPlacement,
+}
+
+impl Popover {
+    /// Default distance kept from the window edges
+    pub const DEFAULT_MARGIN: f32 = 8.0;
+
+    /// Create a popover placed below `anchor` (an entity or a point)
+    pub fn new(anchor: impl Into<PopoverAnchor>) -> Self {
+        Self {
+            anchor: anchor.into(),
+            placement: PopoverPlacement::below(),
+            gap: 0.0,
+            align_offset: 0.0,
+            margin: Self::DEFAULT_MARGIN,
+            flip: true,
+            shift: true,
+            resolved: PopoverPlacement::below(),
+        }
+    }
+
+    /// Set the preferred placement
+    pub fn with_placement(mut self, placement: PopoverPlacement) -> Self {
+        self.placement = placement;
+        self.resolved = placement;
+        self
+    }
+
+    /// Set the distance from the anchor
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the offset along the anchor's edge
+    pub fn with_align_offset(mut self, offset: f32) -> Self {
+        self.align_offset = offset;
+        self
+    }
+
+    /// Set the distance kept from the window edges
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Always use the preferred side and alignment
+    pub fn no_flip(mut self) -> Self {
+        self.flip = false;
+        self
+    }
+
+    /// Do not slide to stay inside the window
+    pub fn no_shift(mut self) -> Self {
+        self.shift = false;
+        self
+    }
+
+    /// Compute the top-left corner of a popover of `size` next to `anchor`, kept within
+    /// `window`, and the placement used.
+    ///
+    /// All rectangles and sizes are in physical pixels; `scale` converts the logical
+    /// `gap` and `margin`.
+    pub fn place(
+        &self,
+        anchor: Rect,
+        size: Vec2,
+        window: Rect,
+        scale: f32,
+    ) -> (Vec2, PopoverPlacement) {
+        let gap = self.gap * scale;
+        let align_offset = self.align_offset * scale;
+        let margin = self.margin * scale;
+        let bounds = Rect {
+            min: window.min + margin,
+            max: window.max - margin,
+        };
+
+        // Room between the anchor and the window edge on each side
+        let room = |side: PopoverSide| match side {
+            PopoverSide::Above => anchor.min.y - gap - bounds.min.y,
+            PopoverSide::Below => bounds.max.y - anchor.max.y - gap,
+            PopoverSide::Start => anchor.min.x - gap - bounds.min.x,
+            PopoverSide::End => bounds.max.x - anchor.max.x - gap,
+        };
+        let extent = |side: PopoverSide| if side.is_vertical() { size.y } else { size.x };
+
+        let mut placement = self.placement;
+        let side = placement.side;
+        if self.flip && room(side) < extent(side) && room(side.opposite()) > room(side) {
+            placement.side = side.opposite();
+        }
+
+        let main = match placement.side {
+            PopoverSide::Above => anchor.min.y - gap - size.y,
+            PopoverSide::Below => anchor.max.y + gap,
+            PopoverSide::Start => anchor.min.x - gap - size.x,
+            PopoverSide::End => anchor.max.x + gap,
+        };
+
+        // Along the anchor's edge
+        let (anchor_min, anchor_max, length, bounds_min, bounds_max) =
+            if placement.side.is_vertical() {
+                (
+                    anchor.min.x,
+                    anchor.max.x,
+                    size.x,
+                    bounds.min.x,
+                    bounds.max.x,
+                )
+            } else {
+                (
+                    anchor.min.y,
+                    anchor.max.y,
+                    size.y,
+                    bounds.min.y,
+                    bounds.max.y,
+                )
+            };
+        let aligned = |align: PopoverAlign| match align {
+            PopoverAlign::Start => anchor_min + align_offset,
+            PopoverAlign::Center => (anchor_min + anchor_max - length) / 2.0 + align_offset,
+            PopoverAlign::End => anchor_max - length - align_offset,
+        };
+        let fits = |start: f32| start >= bounds_min && start + length <= bounds_max;
+
+        let mut cross = aligned(placement.align);
+        if self.flip && !fits(cross) {
+            let flipped = aligned(placement.align.opposite());
+            if fits(flipped) {
+                placement.align = placement.align.opposite();
+                cross = flipped;
+            }
+        }
+        if self.shift {
+            cross = cross.min(bounds_max - length).max(bounds_min);
+        }
+
+        let position = if placement.side.is_vertical() {
+            Vec2::new(cross, main)
+        } else {
+            Vec2::new(main, cross)
+        };
+        (position, placement)
+    }
+}
+
+/// Place popovers next to their anchors, within the primary window.
+///
+/// Popovers that are hidden (not laid out) or whose anchor is not laid out yet are
+/// left where they are.
+fn popover_position_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    layout: Query<(&ComputedNode, &UiGlobalTransform)>,
+    parents: Query<&ChildOf>,
+    mut popovers: Query<(Entity, &mut Popover, &mut Node, &ComputedNode)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let window_rect = Rect::from_corners(
+        Vec2::ZERO,
+        Vec2::new(
+            window.resolution.physical_width() as f32,
+            window.resolution.physical_height() as f32,
+        ),
+    );
+
+    for (entity, mut popover, mut node, computed) in popovers.iter_mut() {
+        if computed.is_empty() {
+            continue;
+        }
+        let anchor = match popover.anchor {
+            PopoverAnchor::Entity(anchor) => {
+                let Ok((anchor_node, anchor_transform)) = layout.get(anchor) else {
+                    continue;
+                };
+                if anchor_node.is_empty() {
+                    continue;
+                }
+                // Both UiGlobalTransform (the node center) and ComputedNode sizes are physical
+                Rect::from_center_size(anchor_transform.translation, anchor_node.size())
+            }
+            PopoverAnchor::Point(point) => {
+                let point = point * window.scale_factor();
+                Rect::from_corners(point, point)
+            }
+        };
+        let scale = computed.inverse_scale_factor.recip();
+        let (position, placement) = popover.place(anchor, computed.size(), window_rect, scale);
+
+        // `left`/`top` are relative to the parent's padding box, which scrolls with it
+        let origin = parents
+            .get(entity)
+            .ok()
+            .and_then(|parent| layout.get(parent.parent()).ok())
+            .map(|(parent_node, parent_transform)| {
+                let border = parent_node.border();
+                parent_transform.translation - parent_node.size() / 2.0
+                    + Vec2::new(border.left, border.top)
+                    - parent_node.scroll_position
+            })
+            .unwrap_or(Vec2::ZERO);
+        let offset = (position - origin) * computed.inverse_scale_factor;
+
+        let (left, top) = (Val::Px(offset.x), Val::Px(offset.y));
+        if node.left != left || node.top != top || node.right != Val::Auto {
+            node.left = left;
+            node.top = top;
+            node.right = Val::Auto;
+            node.bottom = Val::Auto;
+        }
+        if popover.resolved != placement {
+            popover.resolved = placement;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Rect = Rect {
+        min: Vec2::ZERO,
+        max: Vec2::new(800.0, 600.0),
+    };
+
+    fn popover(placement: PopoverPlacement) -> Popover {
+        Popover::new(Entity::PLACEHOLDER)
+            .with_placement(placement)
+            .with_margin(0.0)
+    }
+
+    #[test]
+    fn test_preferred_placement() {
+        let anchor = Rect::new(100.0, 100.0, 200.0, 140.0);
+        let size = Vec2::new(150.0, 200.0);
+
+        let (pos, placement) = popover(PopoverPlacement::below()).place(anchor, size, WINDOW, 1.0);
+        assert_eq!(pos, Vec2::new(100.0, 140.0));
+        assert_eq!(placement, PopoverPlacement::below());
+
+        let (pos, _) = popover(PopoverPlacement::end()).place(anchor, size, WINDOW, 1.0);
+        assert_eq!(pos, Vec2::new(200.0, 100.0));
+
+        let centered = PopoverPlacement::below().with_align(PopoverAlign::Center);
+        let (pos, _) = popover(centered).place(anchor, size, WINDOW, 1.0);
+        assert_eq!(pos, Vec2::new(75.0, 140.0));
+    }
+
+    #[test]
+    fn test_gap_is_scaled() {
+        let anchor = Rect::new(100.0, 100.0, 200.0, 140.0);
+        let (pos, _) = popover(PopoverPlacement::below()).with_gap(4.0).place(
+            anchor,
+            Vec2::new(50.0, 50.0),
+            WINDOW,
+            2.0,
+        );
+        assert_eq!(pos, Vec2::new(100.0, 148.0));
+    }
+
+    #[test]
+    fn test_flips_side_near_edge() {
+        // Near the bottom: opens above
+        let anchor = Rect::new(100.0, 500.0, 200.0, 540.0);
+        let (pos, placement) =
+            popover(PopoverPlacement::below()).place(anchor, Vec2::new(150.0, 200.0), WINDOW, 1.0);
+        assert_eq!(placement.side, PopoverSide::Above);
+        assert_eq!(pos.y, 300.0);
+
+        // Near the right edge: opens before the anchor
+        let anchor = Rect::new(650.0, 100.0, 750.0, 140.0);
+        let (pos, placement) =
+            popover(PopoverPlacement::end()).place(anchor, Vec2::new(150.0, 100.0), WINDOW, 1.0);
+        assert_eq!(placement.side, PopoverSide::Start);
+        assert_eq!(pos.x, 500.0);
+
+        // No flip when the other side has even less room
+        let anchor = Rect::new(100.0, 200.0, 200.0, 240.0);
+        let (_, placement) =
+            popover(PopoverPlacement::below()).place(anchor, Vec2::new(150.0, 500.0), WINDOW, 1.0);
+        assert_eq!(placement.side, PopoverSide::Below);
+    }
+
+    #[test]
+    fn test_flips_alignment_then_shifts() {
+        // Start-aligned would leave the right edge: end-aligned fits
+        let anchor = Rect::new(600.0, 100.0, 780.0, 140.0);
+        let (pos, placement) =
+            popover(PopoverPlacement::below()).place(anchor, Vec2::new(250.0, 100.0), WINDOW, 1.0);
+        assert_eq!(placement.align, PopoverAlign::End);
+        assert_eq!(pos.x, 530.0);
+
+        // Centered would leave the right edge: shifted inside the window
+        let centered = PopoverPlacement::below().with_align(PopoverAlign::Center);
+        let anchor = Rect::new(740.0, 100.0, 780.0, 140.0);
+        let (pos, _) = popover(centered).place(anchor, Vec2::new(300.0, 100.0), WINDOW, 1.0);
+        assert_eq!(pos.x, 500.0);
+
+        // ...unless shifting is disabled
+        let (pos, _) =
+            popover(centered)
+                .no_shift()
+                .place(anchor, Vec2::new(300.0, 100.0), WINDOW, 1.0);
+        assert_eq!(pos.x, 610.0);
+    }
+
+    #[test]
+    fn test_submenu_anchor_offset() {
+        // A submenu beside its item, raised so its first item lines up with the item
+        let submenu = popover(PopoverPlacement::end()).with_align_offset(-8.0);
+        let size = Vec2::new(200.0, 300.0);
+
+        let item = Rect::new(100.0, 100.0, 300.0, 148.0);
+        let (pos, placement) = submenu.place(item, size, WINDOW, 2.0);
+        assert_eq!(placement, PopoverPlacement::end());
+        assert_eq!(pos, Vec2::new(300.0, 84.0));
+
+        // Near the bottom-right corner: opens before the item, growing upwards past
+        // its bottom edge by the same offset
+        let item = Rect::new(550.0, 500.0, 750.0, 548.0);
+        let (pos, placement) = submenu.place(item, size, WINDOW, 1.0);
+        assert_eq!(
+            placement,
+            PopoverPlacement::start().with_align(PopoverAlign::End)
+        );
+        assert_eq!(pos, Vec2::new(350.0, 256.0));
+    }
+
+    #[test]
+    fn test_margin_keeps_distance_from_edges() {
+        let anchor = Rect::new(0.0, 100.0, 40.0, 140.0);
+        let (pos, _) =
+            Popover::new(Entity::PLACEHOLDER).place(anchor, Vec2::new(100.0, 100.0), WINDOW, 1.0);
+        assert_eq!(pos.x, Popover::DEFAULT_MARGIN);
+    }
+}
@@ -14,7 +14,13 @@
 
 use bevy::prelude::*;
 
-use crate::{motion::StateLayer, ripple::RippleHost, theme::MaterialTheme, tokens::CornerRadius};
+use crate::{
+    focus::{next_enabled_index, FocusAppExt, FocusManager, FocusableComponent},
+    motion::StateLayer,
+    ripple::RippleHost,
+    theme::MaterialTheme,
+    tokens::CornerRadius,
+};
 
 /// Marker component for the radio outer circle
 #[derive(Component)]
@@ -33,15 +39,18 @@ pub struct RadioPlugin;
 
 impl Plugin for RadioPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<RadioChangeEvent>().add_systems(
-            Update,
-            (
-                radio_interaction_system,
-                radio_group_system,
-                radio_style_system,
-                radio_theme_refresh_system,
-            ),
-        );
+        app.add_message::<RadioChangeEvent>()
+            .register_focusable::<MaterialRadio>()
+            .add_systems(
+                Update,
+                (
+                    radio_interaction_system,
+                    radio_keyboard_system,
+                    radio_group_system,
+                    radio_style_system,
+                    radio_theme_refresh_system,
+                ),
+            );
     }
 }
 
@@ -127,6 +136,12 @@ impl Default for MaterialRadio {
     }
 }
 
+impl FocusableComponent for MaterialRadio {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Component to define a radio group
 #[derive(Component)]
 pub struct RadioGroup {
@@ -201,6 +216,78 @@ fn radio_interaction_system(
     }
 }
 
+/// System to handle keyboard selection for the focused radio.
+///
+/// Space / Enter select the focused radio. Arrow keys move focus and selection
+/// to the previous / next enabled radio in the same group (in layout order).
+fn radio_keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<FocusManager>,
+    mut radios: Query<(Entity, &mut MaterialRadio, Option<&ComputedNode>)>,
+    mut change_events: MessageWriter<RadioChangeEvent>,
+) {
+    let Some(focused) = focus.focused() else {
+        return;
+    };
+
+    let Ok((_, radio, _)) = radios.get(focused) else {
+        return;
+    };
+
+    if radio.disabled {
+        return;
+    }
+
+    let forward = keys.any_just_pressed([KeyCode::ArrowRight, KeyCode::ArrowDown]);
+    let backward = keys.any_just_pressed([KeyCode::ArrowLeft, KeyCode::ArrowUp]);
+
+    let target = if forward || backward {
+        let Some(group) = radio.group.clone() else {
+            return;
+        };
+
+        let mut members: Vec<(u32, Entity, bool)> = radios
+            .iter()
+            .filter(|(_, radio, _)| radio.group.as_deref() == Some(group.as_str()))
+            .map(|(entity, radio, node)| {
+                (
+                    node.map(|node| node.stack_index()).unwrap_or(u32::MAX),
+                    entity,
+                    !radio.disabled,
+                )
+            })
+            .collect();
+        members.sort_by_key(|(stack_index, _, _)| *stack_index);
+
+        let Some(current) = members.iter().position(|(_, entity, _)| *entity == focused) else {
+            return;
+        };
+        let enabled: Vec<bool> = members.iter().map(|(_, _, enabled)| *enabled).collect();
+        let Some(next) = next_enabled_index(&enabled, current, forward) else {
+            return;
+        };
+
+        let next_entity = members[next].1;
+        focus.focus_visible(next_entity);
+        next_entity
+    } else if focus.activation_requested() {
+        focused
+    } else {
+        return;
+    };
+
+    if let Ok((entity, mut radio, _)) = radios.get_mut(target) {
+        if !radio.selected {
+            radio.selected = true;
+            change_events.write(RadioChangeEvent {
+                entity,
+                group: radio.group.clone(),
+                selected: true,
+            });
+        }
+    }
+}
+
 /// System to handle radio group exclusivity
 fn radio_group_system(
     mut change_events: MessageReader<RadioChangeEvent>,
@@ -73,10 +73,18 @@ impl Plugin for ScrollPlugin {
 
 fn scrollbar_theme_refresh_system(
     theme: Res<MaterialTheme>,
-    mut tracks_v: Query<&mut BackgroundColor, With<ScrollbarTrackVertical>>,
-    mut thumbs_v: Query<&mut BackgroundColor, With<ScrollbarThumbVertical>>,
-    mut tracks_h: Query<&mut BackgroundColor, With<ScrollbarTrackHorizontal>>,
-    mut thumbs_h: Query<&mut BackgroundColor, With<ScrollbarThumbHorizontal>>,
+    mut tracks: Query<
+        &mut BackgroundColor,
+        Or<(With<ScrollbarTrackVertical>, With<ScrollbarTrackHorizontal>)>,
+    >,
+    mut thumbs: Query<
+        &mut BackgroundColor,
+        (
+            Or<(With<ScrollbarThumbVertical>, With<ScrollbarThumbHorizontal>)>,
+            Without<ScrollbarTrackVertical>,
+            Without<ScrollbarTrackHorizontal>,
+        ),
+    >,
 ) {
     if !theme.is_changed() {
         return;
@@ -85,16 +93,10 @@ fn scrollbar_theme_refresh_system(
     let track_color = theme.surface_container_highest.with_alpha(0.5);
     let thumb_color = theme.primary.with_alpha(0.7);
 
-    for mut bg in tracks_v.iter_mut() {
+    for mut bg in tracks.iter_mut() {
         *bg = BackgroundColor(track_color);
     }
-    for mut bg in thumbs_v.iter_mut() {
-        *bg = BackgroundColor(thumb_color);
-    }
-    for mut bg in tracks_h.iter_mut() {
-        *bg = BackgroundColor(track_color);
-    }
-    for mut bg in thumbs_h.iter_mut() {
+    for mut bg in thumbs.iter_mut() {
         *bg = BackgroundColor(thumb_color);
     }
 }
@@ -1426,4 +1428,29 @@ mod tests {
         let thumb_pos = container.vertical_thumb_position();
         assert_eq!(thumb_pos, 0.5); // 300 / 600
     }
+
+    #[test]
+    fn test_scrollbar_theme_refresh() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, ScrollPlugin))
+            .init_resource::<MaterialTheme>()
+            .init_resource::<HoverMap>();
+        let track = app
+            .world_mut()
+            .spawn((ScrollbarTrackHorizontal, BackgroundColor::DEFAULT))
+            .id();
+        let thumb = app
+            .world_mut()
+            .spawn((ScrollbarThumbVertical, BackgroundColor::DEFAULT))
+            .id();
+        app.update();
+
+        let theme = app.world().resource::<MaterialTheme>().clone();
+        let color = |entity| app.world().get::<BackgroundColor>(entity).unwrap().0;
+        assert_eq!(
+            color(track),
+            theme.surface_container_highest.with_alpha(0.5)
+        );
+        assert_eq!(color(thumb), theme.primary.with_alpha(0.7));
+    }
 }
@@ -1,13 +1,28 @@
 //! Material Design 3 Search Bar and Search View
 //!
 //! The Search Bar represents a floating search field with affordances for search and navigation.
+//! Typing goes through an embedded [`MaterialTextField`], so the bar edits like any text
+//! field (caret, selection, clipboard, undo, IME). While focused, the bar expands into a
+//! docked search view listing suggestions for the current query, or recent queries while
+//! the query is empty.
+//!
 //! Reference: <https://m3.material.io/components/search/overview>
 
+use std::ops::Range;
+
 use bevy::prelude::*;
 
 use crate::{
-    icons::{IconStyle, MaterialIcon},
+    divider::horizontal_divider,
+    focus::{FocusAppExt, FocusGained, FocusLost, FocusManager, OverlayComponent},
+    fuzzy::{fuzzy_filter, fuzzy_match, MatchHighlight},
+    icons::{IconStyle, MaterialIcon, ICON_ARROW_BACK, ICON_CLOSE, ICON_HISTORY, ICON_SEARCH},
     ripple::RippleHost,
+    scroll::{ScrollContainer, ScrollContent},
+    text_field::{
+        spawn_embedded_text_field_input, MaterialTextField, TextFieldChangeEvent,
+        TextFieldSubmitEvent,
+    },
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
 };
@@ -19,7 +34,26 @@ impl Plugin for SearchPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<SearchBarClickEvent>()
             .add_message::<SearchQueryEvent>()
-            .add_systems(Update, search_bar_interaction_system);
+            .add_message::<SearchSubmitEvent>()
+            .add_message::<TextFieldChangeEvent>()
+            .add_message::<TextFieldSubmitEvent>()
+            .add_message::<FocusGained>()
+            .add_message::<FocusLost>()
+            .register_overlay::<MaterialSearchBar>()
+            .add_systems(
+                Update,
+                (
+                    search_bar_interaction_system,
+                    search_bar_input_system,
+                    search_bar_text_sync_system,
+                    search_view_button_system,
+                    search_view_item_system,
+                    search_view_escape_system,
+                    search_query_debounce_system,
+                    search_view_sync_system,
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -33,13 +67,23 @@ pub struct SearchBarClickEvent {
     pub search_bar: Entity,
 }
 
-/// Event fired when search query changes
+/// Event fired when the search query changes.
+///
+/// Sent once the query has been left unchanged for the bar's debounce delay
+/// (and right away when the query is cleared).
 #[derive(Event, bevy::prelude::Message, Clone)]
 pub struct SearchQueryEvent {
     pub search_bar: Entity,
     pub query: String,
 }
 
+/// Event fired when a query is submitted (Enter, or a suggestion / recent query is picked)
+#[derive(Event, bevy::prelude::Message, Clone)]
+pub struct SearchSubmitEvent {
+    pub search_bar: Entity,
+    pub query: String,
+}
+
 // ============================================================================
 // Components
 // ============================================================================
@@ -55,6 +99,27 @@ pub struct MaterialSearchBar {
     pub navigation_icon: Option<MaterialIcon>,
     /// Whether to show the trailing action icon
     pub show_action: bool,
+    /// Whether the search view is open
+    pub expanded: bool,
+    /// Suggestions or results for the current query, shown in the search view
+    pub suggestions: Vec<String>,
+    /// Entries the bar filters itself: when not empty, the view lists the entries
+    /// fuzzy-matching the query (best first) instead of `suggestions`
+    pub candidates: Vec<String>,
+    /// Maximum number of matching `candidates` listed
+    pub max_suggestions: usize,
+    /// Recently submitted queries, newest first (shown while the query is empty)
+    pub history: Vec<String>,
+    /// Maximum number of recent queries kept
+    pub max_history: usize,
+    /// Seconds the query must stay unchanged before a [`SearchQueryEvent`] is sent
+    pub debounce: f32,
+    /// Query waiting for the debounce delay, with the seconds left
+    pending_query: Option<(String, f32)>,
+    /// Last query sent in a [`SearchQueryEvent`]
+    sent_query: String,
+    /// Text last exchanged with the input, to tell code-set text from typing
+    input_text: String,
 }
 
 impl MaterialSearchBar {
@@ -64,6 +129,16 @@ impl MaterialSearchBar {
             text: String::new(),
             navigation_icon: None,
             show_action: true,
+            expanded: false,
+            suggestions: Vec::new(),
+            candidates: Vec::new(),
+            max_suggestions: SEARCH_VIEW_MAX_SUGGESTIONS,
+            history: Vec::new(),
+            max_history: SEARCH_HISTORY_MAX,
+            debounce: SEARCH_QUERY_DEBOUNCE,
+            pending_query: None,
+            sent_query: String::new(),
+            input_text: String::new(),
         }
     }
 
@@ -74,27 +149,261 @@ impl MaterialSearchBar {
 
     pub fn with_text(mut self, text: impl Into<String>) -> Self {
         self.text = text.into();
+        // The initial text is not a change.
+        self.sent_query = self.text.clone();
+        self.input_text = self.text.clone();
+        self
+    }
+
+    /// Set the suggestions shown for the current query
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Set the entries filtered (with fuzzy matching) as the query changes
+    pub fn with_candidates(mut self, candidates: Vec<String>) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    /// Set the recent queries, newest first
+    pub fn with_history(mut self, history: Vec<String>) -> Self {
+        self.history = history;
+        self.history.truncate(self.max_history);
+        self
+    }
+
+    /// Set the debounce delay of [`SearchQueryEvent`] in seconds
+    pub fn with_debounce(mut self, seconds: f32) -> Self {
+        self.debounce = seconds.max(0.0);
+        self
+    }
+
+    /// Open the search view
+    pub fn expand(&mut self) {
+        self.expanded = true;
+    }
+
+    /// Close the search view (the bar's input loses focus)
+    pub fn collapse(&mut self) {
+        self.expanded = false;
+    }
+
+    /// Record a submitted query as the most recent one (moving it up if already present)
+    pub fn push_history(&mut self, query: impl Into<String>) {
+        let query = query.into();
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        self.history.retain(|recent| recent != query);
+        self.history.insert(0, query.to_string());
+        self.history.truncate(self.max_history);
+    }
+
+    /// Forget all recent queries
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Items the search view lists: suggestions (or matching candidates) while
+    /// there is a query, recent queries otherwise
+    pub fn view_items(&self) -> Vec<SearchViewItem> {
+        if self.text.trim().is_empty() {
+            self.history
+                .iter()
+                .map(|query| SearchViewItem::recent(query.clone()))
+                .collect()
+        } else if !self.candidates.is_empty() {
+            fuzzy_filter(&self.text, &self.candidates)
+                .into_iter()
+                .take(self.max_suggestions)
+                .map(|(index, m)| {
+                    SearchViewItem::suggestion(self.candidates[index].clone())
+                        .with_matches(m.ranges)
+                })
+                .collect()
+        } else {
+            // App-provided results keep their order; matching characters are still marked.
+            self.suggestions
+                .iter()
+                .map(|query| {
+                    let matches = fuzzy_match(&self.text, query)
+                        .map(|m| m.ranges)
+                        .unwrap_or_default();
+                    SearchViewItem::suggestion(query.clone()).with_matches(matches)
+                })
+                .collect()
+        }
+    }
+
+    /// Advance the debounce timer by `delta` seconds.
+    ///
+    /// Returns the query to report once it has been stable for the debounce delay.
+    fn debounced_query(&mut self, delta: f32) -> Option<String> {
+        if self.text == self.sent_query {
+            self.pending_query = None;
+            return None;
+        }
+
+        let remaining = match &mut self.pending_query {
+            Some((query, remaining)) if *query == self.text => {
+                *remaining -= delta;
+                *remaining
+            }
+            pending => {
+                *pending = Some((self.text.clone(), self.debounce));
+                self.debounce
+            }
+        };
+        if remaining > 0.0 {
+            return None;
+        }
+
+        self.pending_query = None;
+        self.sent_query = self.text.clone();
+        Some(self.text.clone())
+    }
+
+    /// Mark `query` as reported without waiting for the debounce delay
+    fn mark_query_sent(&mut self, query: &str) {
+        self.pending_query = None;
+        self.sent_query = query.to_string();
+    }
+}
+
+impl OverlayComponent for MaterialSearchBar {
+    fn is_overlay_open(&self) -> bool {
+        self.expanded
+    }
+}
+
+/// Kind of a search view row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchViewItemKind {
+    /// Suggestion or result for the current query
+    Suggestion,
+    /// Recently submitted query
+    Recent,
+}
+
+/// A row of the search view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchViewItem {
+    pub kind: SearchViewItemKind,
+    /// Query filled in (and submitted) when the row is picked
+    pub query: String,
+    /// Byte ranges of `query` matching the typed text, drawn with emphasis
+    pub matches: Vec<Range<usize>>,
+}
+
+impl SearchViewItem {
+    pub fn suggestion(query: impl Into<String>) -> Self {
+        Self {
+            kind: SearchViewItemKind::Suggestion,
+            query: query.into(),
+            matches: Vec::new(),
+        }
+    }
+
+    pub fn recent(query: impl Into<String>) -> Self {
+        Self {
+            kind: SearchViewItemKind::Recent,
+            query: query.into(),
+            matches: Vec::new(),
+        }
+    }
+
+    /// Set the matched byte ranges of `query`
+    pub fn with_matches(mut self, matches: Vec<Range<usize>>) -> Self {
+        self.matches = matches;
         self
     }
+
+    /// Leading icon of the row
+    pub fn icon(&self) -> MaterialIcon {
+        match self.kind {
+            SearchViewItemKind::Suggestion => MaterialIcon::new(ICON_SEARCH),
+            SearchViewItemKind::Recent => MaterialIcon::new(ICON_HISTORY),
+        }
+    }
 }
 
 /// Marker for search bar navigation button
 #[derive(Component)]
 pub struct SearchBarNavigation;
 
+/// Links a navigation button to its search bar
+#[derive(Component)]
+pub struct SearchBarNavigationFor(pub Entity);
+
 /// Marker for search bar action button
 #[derive(Component)]
 pub struct SearchBarAction;
 
+/// Links an action button to its search bar
+#[derive(Component)]
+pub struct SearchBarActionFor(pub Entity);
+
+/// Marker for the button that clears the query
+#[derive(Component)]
+pub struct SearchBarClear;
+
+/// Links a clear button to its search bar
+#[derive(Component)]
+pub struct SearchBarClearFor(pub Entity);
+
 /// Marker for search bar text container
 #[derive(Component)]
 pub struct SearchBarTextContainer;
 
+/// Marker for the text field the query is typed into
+#[derive(Component)]
+pub struct SearchBarInput;
+
+/// Links a search input to its search bar
+#[derive(Component)]
+pub struct SearchBarInputFor(pub Entity);
+
+/// Marker for the search view below an expanded search bar
+#[derive(Component)]
+pub struct SearchView;
+
+/// Links a search view to its search bar
+#[derive(Component)]
+pub struct SearchViewFor(pub Entity);
+
+/// Marker for the scrollable list of a search view
+#[derive(Component)]
+pub struct SearchViewList;
+
+/// Links a search view list to its search bar
+#[derive(Component)]
+pub struct SearchViewListFor(pub Entity);
+
+/// A row in a search view list
+#[derive(Component)]
+pub struct SearchViewRow {
+    pub search_bar: Entity,
+    pub item: SearchViewItem,
+}
+
 // ============================================================================
 // Constants
 // ============================================================================
 
 pub const SEARCH_BAR_HEIGHT: f32 = 56.0;
+/// Height of a search view row
+pub const SEARCH_VIEW_ITEM_HEIGHT: f32 = 56.0;
+/// Maximum height of the search view list before it scrolls
+pub const SEARCH_VIEW_MAX_HEIGHT: f32 = 360.0;
+/// Default delay before a changed query is reported, in seconds
+pub const SEARCH_QUERY_DEBOUNCE: f32 = 0.3;
+/// Default number of recent queries kept
+pub const SEARCH_HISTORY_MAX: usize = 10;
+/// Default number of matching candidates listed
+pub const SEARCH_VIEW_MAX_SUGGESTIONS: usize = 50;
 
 // ============================================================================
 // Builder
@@ -117,15 +426,35 @@ impl SearchBarBuilder {
     }
 
     pub fn with_text(mut self, text: impl Into<String>) -> Self {
-        self.search_bar.text = text.into();
+        self.search_bar = self.search_bar.with_text(text);
         self
     }
 
-    pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
-        let _hint = self.search_bar.hint.clone();
-        let text = self.search_bar.text.clone();
-        let _has_text = !text.is_empty();
+    /// Set the suggestions shown for the current query
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.search_bar.suggestions = suggestions;
+        self
+    }
+
+    /// Set the entries filtered (with fuzzy matching) as the query changes
+    pub fn with_candidates(mut self, candidates: Vec<String>) -> Self {
+        self.search_bar.candidates = candidates;
+        self
+    }
+
+    /// Set the recent queries, newest first
+    pub fn with_history(mut self, history: Vec<String>) -> Self {
+        self.search_bar = self.search_bar.with_history(history);
+        self
+    }
+
+    /// Set the debounce delay of [`SearchQueryEvent`] in seconds
+    pub fn with_debounce(mut self, seconds: f32) -> Self {
+        self.search_bar = self.search_bar.with_debounce(seconds);
+        self
+    }
 
+    pub fn build(self, theme: &MaterialTheme) -> impl Bundle {
         (
             self.search_bar,
             Node {
@@ -165,13 +494,17 @@ impl SpawnSearchBarChild for ChildSpawnerCommands<'_> {
         let hint = builder.search_bar.hint.clone();
         let text = builder.search_bar.text.clone();
         let nav_icon = builder.search_bar.navigation_icon;
+        let show_action = builder.search_bar.show_action;
         let has_text = !text.is_empty();
 
-        self.spawn(builder.build(theme)).with_children(|bar| {
-            // Navigation icon
-            if let Some(icon) = nav_icon {
-                bar.spawn((
+        let mut bar_commands = self.spawn(builder.build(theme));
+        let bar = bar_commands.id();
+        bar_commands.with_children(|children| {
+            // Navigation icon (back arrow while the search view is open)
+            children
+                .spawn((
                     SearchBarNavigation,
+                    SearchBarNavigationFor(bar),
                     Button,
                     RippleHost::new(),
                     Node {
@@ -179,6 +512,11 @@ impl SpawnSearchBarChild for ChildSpawnerCommands<'_> {
                         height: Val::Px(48.0),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::Center,
+                        display: if nav_icon.is_some() {
+                            Display::Flex
+                        } else {
+                            Display::None
+                        },
                         ..default()
                     },
                     BackgroundColor(Color::NONE),
@@ -186,86 +524,766 @@ impl SpawnSearchBarChild for ChildSpawnerCommands<'_> {
                 ))
                 .with_children(|btn| {
                     btn.spawn((
-                        icon,
+                        nav_icon.unwrap_or(MaterialIcon::new(ICON_ARROW_BACK)),
                         IconStyle::outlined()
                             .with_color(theme.on_surface)
                             .with_size(24.0),
                     ));
                 });
-            }
 
-            // Text container
-            bar.spawn((
-                SearchBarTextContainer,
-                Node {
-                    flex_grow: 1.0,
-                    flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-            ))
-            .with_children(|container| {
-                if has_text {
-                    container.spawn((
-                        Text::new(&text),
-                        TextFont {
-                            font_size: 16.0,
+            // Text container with the query input
+            children
+                .spawn((
+                    SearchBarTextContainer,
+                    Node {
+                        flex_grow: 1.0,
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                ))
+                .with_children(|container| {
+                    let field = MaterialTextField::new()
+                        .placeholder(hint.as_str())
+                        .with_value(text.as_str());
+                    let mut input = container.spawn((
+                        SearchBarInput,
+                        SearchBarInputFor(bar),
+                        Button,
+                        Interaction::None,
+                        Node {
+                            flex_grow: 1.0,
+                            flex_direction: FlexDirection::Column,
+                            justify_content: JustifyContent::Center,
                             ..default()
                         },
-                        TextColor(theme.on_surface),
                     ));
-                } else {
-                    container.spawn((
-                        Text::new(&hint),
-                        TextFont {
-                            font_size: 16.0,
-                            ..default()
+                    let input_entity = input.id();
+                    input.with_children(|input_children| {
+                        spawn_embedded_text_field_input(
+                            input_children,
+                            theme,
+                            input_entity,
+                            &field,
+                        );
+                    });
+                    input.insert(field);
+                });
+
+            // Clear button (shown while there is a query)
+            children
+                .spawn((
+                    SearchBarClear,
+                    SearchBarClearFor(bar),
+                    Button,
+                    RippleHost::new(),
+                    Node {
+                        width: Val::Px(48.0),
+                        height: Val::Px(48.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        display: if has_text {
+                            Display::Flex
+                        } else {
+                            Display::None
                         },
-                        TextColor(theme.on_surface_variant),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        MaterialIcon::new(ICON_CLOSE),
+                        IconStyle::outlined()
+                            .with_color(theme.on_surface_variant)
+                            .with_size(24.0),
                     ));
-                }
-            });
+                });
 
             // Trailing action (search icon)
-            bar.spawn((
-                SearchBarAction,
-                Button,
-                RippleHost::new(),
-                Node {
-                    width: Val::Px(48.0),
-                    height: Val::Px(48.0),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BackgroundColor(Color::NONE),
-                BorderRadius::all(Val::Px(CornerRadius::FULL)),
-            ))
-            .with_children(|btn| {
-                if let Some(icon) = MaterialIcon::from_name("search") {
+            children
+                .spawn((
+                    SearchBarAction,
+                    SearchBarActionFor(bar),
+                    Button,
+                    RippleHost::new(),
+                    Node {
+                        width: Val::Px(48.0),
+                        height: Val::Px(48.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        display: if show_action && !has_text {
+                            Display::Flex
+                        } else {
+                            Display::None
+                        },
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    BorderRadius::all(Val::Px(CornerRadius::FULL)),
+                ))
+                .with_children(|btn| {
                     btn.spawn((
-                        icon,
+                        MaterialIcon::new(ICON_SEARCH),
                         IconStyle::outlined()
                             .with_color(theme.on_surface_variant)
                             .with_size(24.0),
                     ));
-                }
-            });
+                });
+
+            // Search view (hidden until the bar expands)
+            children
+                .spawn((
+                    SearchView,
+                    SearchViewFor(bar),
+                    // Render above later siblings and dialog scrims, like select dropdowns.
+                    GlobalZIndex(1100),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(SEARCH_BAR_HEIGHT),
+                        left: Val::Px(0.0),
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::bottom(Val::Px(Spacing::SMALL)),
+                        display: Display::None,
+                        ..default()
+                    },
+                    BackgroundColor(theme.surface_container_high),
+                    BorderRadius::bottom(Val::Px(CornerRadius::EXTRA_LARGE)),
+                ))
+                .with_children(|view| {
+                    view.spawn(horizontal_divider(theme));
+                    view.spawn((
+                        SearchViewList,
+                        SearchViewListFor(bar),
+                        ScrollContainer::vertical(),
+                        ScrollPosition::default(),
+                        Node {
+                            width: Val::Percent(100.0),
+                            max_height: Val::Px(SEARCH_VIEW_MAX_HEIGHT),
+                            flex_direction: FlexDirection::Column,
+                            overflow: Overflow::scroll_y(),
+                            ..default()
+                        },
+                    ));
+                });
         });
     }
 }
 
+/// Spawn a search view row for `item`
+fn spawn_search_view_row(
+    list: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    search_bar: Entity,
+    item: SearchViewItem,
+) {
+    let icon = item.icon();
+    let label =
+        MatchHighlight::new(item.query.clone(), theme.primary).with_ranges(item.matches.clone());
+    list.spawn((
+        SearchViewRow { search_bar, item },
+        Button,
+        Interaction::None,
+        RippleHost::new(),
+        Node {
+            height: Val::Px(SEARCH_VIEW_ITEM_HEIGHT),
+            padding: UiRect::horizontal(Val::Px(Spacing::LARGE)),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(Spacing::LARGE),
+            flex_shrink: 0.0,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+    ))
+    .with_children(|row| {
+        row.spawn((
+            icon,
+            IconStyle::outlined()
+                .with_color(theme.on_surface_variant)
+                .with_size(24.0),
+        ));
+        row.spawn((
+            Text::default(),
+            label,
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(theme.on_surface),
+        ));
+    });
+}
+
 // ============================================================================
 // Systems
 // ============================================================================
 
+/// Clicking the bar outside its buttons focuses the query input (which expands the view).
 fn search_bar_interaction_system(
     search_bars: Query<(&Interaction, Entity), (Changed<Interaction>, With<MaterialSearchBar>)>,
+    inputs: Query<(Entity, &SearchBarInputFor)>,
+    mut focus: ResMut<FocusManager>,
     mut click_events: MessageWriter<SearchBarClickEvent>,
 ) {
     for (interaction, entity) in search_bars.iter() {
         if *interaction == Interaction::Pressed {
             click_events.write(SearchBarClickEvent { search_bar: entity });
+            if let Some((input, _)) = inputs.iter().find(|(_, owner)| owner.0 == entity) {
+                focus.focus(input);
+            }
+        }
+    }
+}
+
+/// Mirror the input into the bar: typing updates `text`, Enter submits, and the
+/// search view is open while the input has focus.
+fn search_bar_input_system(
+    mut focus_gained: MessageReader<FocusGained>,
+    mut focus_lost: MessageReader<FocusLost>,
+    mut change_events: MessageReader<TextFieldChangeEvent>,
+    mut submit_events: MessageReader<TextFieldSubmitEvent>,
+    inputs: Query<&SearchBarInputFor>,
+    mut search_bars: Query<&mut MaterialSearchBar>,
+    mut search_submits: MessageWriter<SearchSubmitEvent>,
+) {
+    for ev in focus_gained.read() {
+        if let Ok(owner) = inputs.get(ev.entity) {
+            if let Ok(mut bar) = search_bars.get_mut(owner.0) {
+                if !bar.expanded {
+                    bar.expand();
+                }
+            }
+        }
+    }
+
+    // Clicking outside the bar closes the view.
+    for ev in focus_lost.read() {
+        if let Ok(owner) = inputs.get(ev.entity) {
+            if let Ok(mut bar) = search_bars.get_mut(owner.0) {
+                if bar.expanded {
+                    bar.collapse();
+                }
+            }
+        }
+    }
+
+    for ev in change_events.read() {
+        let Ok(owner) = inputs.get(ev.entity) else {
+            continue;
+        };
+        if let Ok(mut bar) = search_bars.get_mut(owner.0) {
+            if bar.text != ev.value {
+                bar.text = ev.value.clone();
+                bar.input_text = ev.value.clone();
+            }
+        }
+    }
+
+    for ev in submit_events.read() {
+        let Ok(owner) = inputs.get(ev.entity) else {
+            continue;
+        };
+        let Ok(mut bar) = search_bars.get_mut(owner.0) else {
+            continue;
+        };
+        let query = ev.value.trim().to_string();
+        if query.is_empty() {
+            continue;
+        }
+        bar.text = ev.value.clone();
+        bar.input_text = ev.value.clone();
+        bar.push_history(query.as_str());
+        search_submits.write(SearchSubmitEvent {
+            search_bar: owner.0,
+            query,
+        });
+    }
+}
+
+/// Push `text` set on the bar from code (or by its buttons) into its input.
+fn search_bar_text_sync_system(
+    mut search_bars: Query<(Entity, &mut MaterialSearchBar), Changed<MaterialSearchBar>>,
+    mut inputs: Query<(&SearchBarInputFor, &mut MaterialTextField)>,
+) {
+    for (bar_entity, mut bar) in search_bars.iter_mut() {
+        // Text that came from the input (possibly a frame behind it) is left alone.
+        if bar.text == bar.input_text {
+            continue;
+        }
+        bar.input_text = bar.text.clone();
+        for (owner, mut field) in inputs.iter_mut() {
+            if owner.0 == bar_entity && field.value != bar.text {
+                field.set_value(bar.text.as_str());
+            }
+        }
+    }
+}
+
+/// Handle the navigation (back) and clear buttons.
+fn search_view_button_system(
+    navigation: Query<(&Interaction, &SearchBarNavigationFor), Changed<Interaction>>,
+    clear: Query<(&Interaction, &SearchBarClearFor), Changed<Interaction>>,
+    inputs: Query<(Entity, &SearchBarInputFor)>,
+    mut focus: ResMut<FocusManager>,
+    mut search_bars: Query<&mut MaterialSearchBar>,
+    mut query_events: MessageWriter<SearchQueryEvent>,
+) {
+    for (interaction, owner) in navigation.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // While expanded the navigation icon is the back arrow.
+        if let Ok(mut bar) = search_bars.get_mut(owner.0) {
+            if bar.expanded {
+                bar.collapse();
+            }
+        }
+    }
+
+    for (interaction, owner) in clear.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut bar) = search_bars.get_mut(owner.0) else {
+            continue;
+        };
+        if bar.text.is_empty() {
+            continue;
+        }
+        bar.text.clear();
+        // Keep typing in the (re-opened) view.
+        if let Some((input, _)) = inputs.iter().find(|(_, for_bar)| for_bar.0 == owner.0) {
+            focus.focus(input);
+        }
+        // Clearing needs no debounce.
+        bar.mark_query_sent("");
+        query_events.write(SearchQueryEvent {
+            search_bar: owner.0,
+            query: String::new(),
+        });
+    }
+}
+
+/// Picking a suggestion or recent query fills it in and submits it.
+fn search_view_item_system(
+    rows: Query<(&Interaction, &SearchViewRow), Changed<Interaction>>,
+    mut search_bars: Query<&mut MaterialSearchBar>,
+    mut query_events: MessageWriter<SearchQueryEvent>,
+    mut search_submits: MessageWriter<SearchSubmitEvent>,
+) {
+    for (interaction, row) in rows.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut bar) = search_bars.get_mut(row.search_bar) else {
+            continue;
+        };
+
+        let query = row.item.query.clone();
+        bar.text = query.clone();
+        bar.mark_query_sent(&query);
+        bar.push_history(query.as_str());
+        bar.collapse();
+        query_events.write(SearchQueryEvent {
+            search_bar: row.search_bar,
+            query: query.clone(),
+        });
+        search_submits.write(SearchSubmitEvent {
+            search_bar: row.search_bar,
+            query,
+        });
+    }
+}
+
+/// Escape collapses the search view holding focus, or else the most recently
+/// expanded one the active focus trap allows.
+fn search_view_escape_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut expand_order: Local<Vec<Entity>>,
+    focus: Option<Res<FocusManager>>,
+    parents: Query<&ChildOf>,
+    mut search_bars: Query<(Entity, &mut MaterialSearchBar)>,
+) {
+    expand_order.retain(|entity| search_bars.get(*entity).is_ok_and(|(_, bar)| bar.expanded));
+    for (entity, bar) in search_bars.iter() {
+        if bar.expanded && !expand_order.contains(&entity) {
+            expand_order.push(entity);
+        }
+    }
+
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    let focus = focus.as_deref();
+    let focused_bar = focus.and_then(|focus| focus.focused()).and_then(|focused| {
+        std::iter::once(focused)
+            .chain(parents.iter_ancestors(focused))
+            .find(|entity| expand_order.contains(entity))
+    });
+    let target = focused_bar.or_else(|| {
+        expand_order
+            .iter()
+            .rev()
+            .copied()
+            .find(|&entity| focus.is_none_or(|focus| focus.allows_focus(entity, &parents)))
+    });
+    if let Some((_, mut bar)) = target.and_then(|entity| search_bars.get_mut(entity).ok()) {
+        bar.collapse();
+    }
+}
+
+/// Send [`SearchQueryEvent`] once a changed query has settled.
+fn search_query_debounce_system(
+    time: Res<Time>,
+    mut search_bars: Query<(Entity, &mut MaterialSearchBar)>,
+    mut query_events: MessageWriter<SearchQueryEvent>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut bar) in search_bars.iter_mut() {
+        // The timer doesn't change what the bar shows, so it stays out of change
+        // detection and the view isn't rebuilt while a query is pending.
+        if let Some(query) = bar.bypass_change_detection().debounced_query(delta) {
+            query_events.write(SearchQueryEvent {
+                search_bar: entity,
+                query,
+            });
+        }
+    }
+}
+
+/// Show or hide the search view, swap the bar's buttons and rebuild the view's rows.
+#[allow(clippy::too_many_arguments)]
+fn search_view_sync_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    mut focus: ResMut<FocusManager>,
+    mut expanded_bars: Local<Vec<Entity>>,
+    mut search_bars: Query<
+        (Entity, &MaterialSearchBar, &mut BorderRadius),
+        Changed<MaterialSearchBar>,
+    >,
+    inputs: Query<(Entity, &SearchBarInputFor)>,
+    mut buttons: Query<
+        (
+            &mut Node,
+            Option<&SearchBarNavigationFor>,
+            Option<&SearchBarClearFor>,
+            Option<&SearchBarActionFor>,
+            Option<&Children>,
+        ),
+        Or<(
+            With<SearchBarNavigationFor>,
+            With<SearchBarClearFor>,
+            With<SearchBarActionFor>,
+        )>,
+    >,
+    mut icons: Query<&mut MaterialIcon>,
+    mut views: Query<
+        (&SearchViewFor, &mut Node),
+        (
+            Without<SearchBarNavigationFor>,
+            Without<SearchBarClearFor>,
+            Without<SearchBarActionFor>,
+        ),
+    >,
+    lists: Query<(Entity, &SearchViewListFor, Option<&Children>)>,
+    rows: Query<(Entity, &SearchViewRow)>,
+    scroll_contents: Query<(), With<ScrollContent>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (bar_entity, bar, mut radius) in search_bars.iter_mut() {
+        let items = bar.view_items();
+        let show_view = bar.expanded && !items.is_empty();
+
+        // Collapsing gives up the input's focus.
+        let was_expanded = expanded_bars.contains(&bar_entity);
+        if bar.expanded && !was_expanded {
+            expanded_bars.push(bar_entity);
+        } else if !bar.expanded && was_expanded {
+            expanded_bars.retain(|entity| *entity != bar_entity);
+            let input = inputs
+                .iter()
+                .find(|(_, owner)| owner.0 == bar_entity)
+                .map(|(entity, _)| entity);
+            if input.is_some() && focus.focused() == input {
+                focus.blur();
+            }
+        }
+
+        // The bar's bottom corners join the open view.
+        let desired_radius = if show_view {
+            BorderRadius::top(Val::Px(CornerRadius::EXTRA_LARGE))
+        } else {
+            BorderRadius::all(Val::Px(CornerRadius::FULL))
+        };
+        if *radius != desired_radius {
+            *radius = desired_radius;
         }
+
+        for (mut node, navigation, clear, action, children) in buttons.iter_mut() {
+            let owner = navigation
+                .map(|n| n.0)
+                .or(clear.map(|c| c.0))
+                .or(action.map(|a| a.0));
+            if owner != Some(bar_entity) {
+                continue;
+            }
+
+            let visible = if navigation.is_some() {
+                let icon = if bar.expanded {
+                    Some(MaterialIcon::new(ICON_ARROW_BACK))
+                } else {
+                    bar.navigation_icon
+                };
+                if let Some(icon) = icon {
+                    for child in children.into_iter().flatten() {
+                        if let Ok(mut current) = icons.get_mut(*child) {
+                            if *current != icon {
+                                *current = icon;
+                            }
+                        }
+                    }
+                }
+                icon.is_some()
+            } else if clear.is_some() {
+                !bar.text.is_empty()
+            } else {
+                bar.show_action && bar.text.is_empty()
+            };
+
+            let display = if visible {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            if node.display != display {
+                node.display = display;
+            }
+        }
+
+        for (owner, mut node) in views.iter_mut() {
+            if owner.0 != bar_entity {
+                continue;
+            }
+            let display = if show_view {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            if node.display != display {
+                node.display = display;
+            }
+        }
+
+        // Rebuild the rows when the listed items change.
+        let shown: Vec<(Entity, &SearchViewItem)> = rows
+            .iter()
+            .filter(|(_, row)| row.search_bar == bar_entity)
+            .map(|(entity, row)| (entity, &row.item))
+            .collect();
+        if shown.len() == items.len() && shown.iter().zip(&items).all(|((_, a), b)| *a == b) {
+            continue;
+        }
+        for (row_entity, _) in &shown {
+            commands.entity(*row_entity).despawn();
+        }
+        let Some((list, children)) = lists
+            .iter()
+            .find(|(_, owner, _)| owner.0 == bar_entity)
+            .map(|(list, _, children)| (list, children))
+        else {
+            continue;
+        };
+        // Once rows exist, the scroll container has moved them into its `ScrollContent`.
+        let parent = children
+            .into_iter()
+            .flatten()
+            .copied()
+            .find(|child| scroll_contents.contains(*child))
+            .unwrap_or(list);
+        commands.entity(parent).with_children(|list| {
+            for item in items.iter().cloned() {
+                spawn_search_view_row(list, &theme, bar_entity, item);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::MessageCursor;
+    use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+    use bevy::input::{ButtonState, InputPlugin};
+    use bevy::time::TimeUpdateStrategy;
+
+    use crate::focus::{FocusPlugin, Focusable};
+
+    /// Frames in which a search bar was marked changed
+    #[derive(Resource, Default)]
+    struct BarChanges(usize);
+
+    fn count_bar_changes(
+        bars: Query<(), Changed<MaterialSearchBar>>,
+        mut changes: ResMut<BarChanges>,
+    ) {
+        if !bars.is_empty() {
+            changes.0 += 1;
+        }
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, FocusPlugin, SearchPlugin))
+            .init_resource::<MaterialTheme>()
+            .init_resource::<BarChanges>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(100),
+            ))
+            .add_systems(PostUpdate, count_bar_changes);
+        app
+    }
+
+    /// A bare search bar with a focusable stand-in for its input
+    fn spawn_bar(app: &mut App, bar: MaterialSearchBar) -> (Entity, Entity) {
+        let bar = app
+            .world_mut()
+            .spawn((bar, BorderRadius::default(), Node::default()))
+            .id();
+        let input = app
+            .world_mut()
+            .spawn((
+                SearchBarInputFor(bar),
+                Focusable::default(),
+                ComputedNode {
+                    size: Vec2::splat(40.0),
+                    inverse_scale_factor: 1.0,
+                    ..default()
+                },
+                ChildOf(bar),
+            ))
+            .id();
+        (bar, input)
+    }
+
+    fn press_escape(app: &mut App) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            app.world_mut().write_message(KeyboardInput {
+                key_code: KeyCode::Escape,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+        }
+    }
+
+    fn expanded(app: &App, bar: Entity) -> bool {
+        app.world().get::<MaterialSearchBar>(bar).unwrap().expanded
+    }
+
+    #[test]
+    fn test_escape_collapses_one_bar() {
+        let mut app = test_app();
+        let (first, first_input) = spawn_bar(&mut app, MaterialSearchBar::new("First"));
+        let (second, _) = spawn_bar(&mut app, MaterialSearchBar::new("Second"));
+        app.update();
+
+        app.world_mut()
+            .get_mut::<MaterialSearchBar>(first)
+            .unwrap()
+            .expand();
+        app.update();
+        app.world_mut()
+            .get_mut::<MaterialSearchBar>(second)
+            .unwrap()
+            .expand();
+        app.update();
+
+        // The focused bar goes first, even though the other one opened later.
+        app.world_mut()
+            .resource_mut::<FocusManager>()
+            .focus(first_input);
+        app.update();
+        press_escape(&mut app);
+        assert!(!expanded(&app, first));
+        assert!(expanded(&app, second));
+        assert_eq!(app.world().resource::<FocusManager>().focused(), None);
+
+        // Without focus, the most recently expanded one closes.
+        app.world_mut()
+            .get_mut::<MaterialSearchBar>(first)
+            .unwrap()
+            .expand();
+        app.update();
+        press_escape(&mut app);
+        assert!(!expanded(&app, first));
+        assert!(expanded(&app, second));
+
+        press_escape(&mut app);
+        assert!(!expanded(&app, second));
+    }
+
+    #[test]
+    fn test_pending_query_leaves_bar_unchanged() {
+        let mut app = test_app();
+        let (bar, _) = spawn_bar(
+            &mut app,
+            MaterialSearchBar::new("Search").with_debounce(0.5),
+        );
+        app.update();
+
+        app.world_mut()
+            .get_mut::<MaterialSearchBar>(bar)
+            .unwrap()
+            .text = "ada".into();
+        app.update();
+        app.world_mut().resource_mut::<BarChanges>().0 = 0;
+
+        let mut cursor = MessageCursor::<SearchQueryEvent>::default();
+        let mut queries = Vec::new();
+        for _ in 0..8 {
+            app.update();
+            let messages = app.world().resource::<Messages<SearchQueryEvent>>();
+            queries.extend(cursor.read(messages).map(|event| event.query.clone()));
+        }
+        assert_eq!(app.world().resource::<BarChanges>().0, 0);
+        assert_eq!(queries, vec!["ada".to_string()]);
+    }
+
+    #[test]
+    fn test_query_is_debounced_until_stable() {
+        let mut bar = MaterialSearchBar::new("Search").with_debounce(0.5);
+        assert_eq!(bar.debounced_query(0.25), None);
+
+        // The delay starts on the frame the query changes.
+        bar.text = "a".into();
+        assert_eq!(bar.debounced_query(0.25), None);
+        assert_eq!(bar.debounced_query(0.25), None);
+
+        // Typing restarts it.
+        bar.text = "ab".into();
+        assert_eq!(bar.debounced_query(0.25), None);
+        assert_eq!(bar.debounced_query(0.25), None);
+        assert_eq!(bar.debounced_query(0.25), Some("ab".to_string()));
+        assert_eq!(bar.debounced_query(1.0), None);
+    }
+
+    #[test]
+    fn test_initial_and_sent_queries_are_not_reported() {
+        let mut bar = MaterialSearchBar::new("Search")
+            .with_text("initial")
+            .with_debounce(0.0);
+        assert_eq!(bar.debounced_query(0.1), None);
+
+        bar.text = "picked".into();
+        bar.mark_query_sent("picked");
+        assert_eq!(bar.debounced_query(0.1), None);
     }
 }
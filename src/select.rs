@@ -3,11 +3,21 @@
 //! Select menus display a list of choices on a temporary surface and allow users to select one.
 //! Reference: <https://m3.material.io/components/menus/overview>
 
+use std::collections::BTreeSet;
+
 use bevy::prelude::*;
 
 use crate::{
+    chip::{spawn_chip, ChipBuilder, ChipDeleteEvent},
+    focus::{FocusAppExt, FocusManager, FocusableComponent, OverlayComponent},
+    fuzzy::{fold, fuzzy_filter, FuzzyMatch, MatchHighlight},
     icons::MaterialIcon,
     icons::MaterialIconFont,
+    popover::Popover,
+    text_field::{
+        spawn_embedded_text_field_input, MaterialTextField, TextFieldChangeEvent,
+        TextFieldSubmitEvent,
+    },
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
 };
@@ -17,18 +27,31 @@ pub struct SelectPlugin;
 
 impl Plugin for SelectPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<SelectChangeEvent>().add_systems(
-            Update,
-            (
-                select_interaction_system,
-                select_style_system,
-                select_content_style_system,
-                select_theme_refresh_system,
-                select_dropdown_sync_system,
-                select_option_interaction_system,
-                select_option_icon_font_system,
-            ),
-        );
+        app.add_message::<SelectChangeEvent>()
+            .add_message::<SelectMultiChangeEvent>()
+            .add_message::<SelectCustomValueEvent>()
+            .add_message::<ChipDeleteEvent>()
+            .add_message::<TextFieldChangeEvent>()
+            .add_message::<TextFieldSubmitEvent>()
+            .register_focusable::<MaterialSelect>()
+            .register_overlay::<MaterialSelect>()
+            .add_systems(
+                Update,
+                (
+                    select_interaction_system,
+                    // The activation key must not reopen a dropdown it just closed.
+                    select_activation_system.before(select_keyboard_system),
+                    select_style_system,
+                    select_content_style_system,
+                    select_theme_refresh_system,
+                    select_dropdown_sync_system,
+                    select_option_interaction_system,
+                    select_keyboard_system,
+                    select_option_icon_font_system,
+                    (select_filter_input_system, select_filter_sync_system).chain(),
+                    select_chip_sync_system,
+                ),
+            );
     }
 }
 
@@ -42,6 +65,16 @@ pub enum SelectVariant {
     Outlined,
 }
 
+/// How a multi-select shows its selection in the field
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MultiSelectDisplay {
+    /// One input chip per selected option (removable with its delete icon)
+    #[default]
+    Chips,
+    /// The option label, or a count such as "3 selected"
+    Summary,
+}
+
 /// Material select component
 #[derive(Component)]
 pub struct MaterialSelect {
@@ -63,11 +96,41 @@ pub struct MaterialSelect {
     pub error_text: Option<String>,
     /// Whether the dropdown is open
     pub open: bool,
+    /// Whether the dropdown has a filter input narrowing the options as the user types
+    pub filterable: bool,
+    /// Current filter text (cleared when the dropdown closes)
+    pub filter: String,
+    /// Option highlighted for keyboard selection while the dropdown is open
+    pub highlighted_index: Option<usize>,
+    /// Whether several options can be selected (shown with checkboxes in the dropdown)
+    pub multiple: bool,
+    /// Selected option indices of a multi-select
+    pub selected_indices: BTreeSet<usize>,
+    /// How a multi-select shows its selection
+    pub multi_display: MultiSelectDisplay,
+    /// Whether the field is a typeable combobox that filters the options
+    pub editable: bool,
+    /// Whether an editable select accepts text that matches no option
+    pub allow_custom: bool,
+    /// Free text committed in an editable select (set while no option is selected)
+    pub custom_value: Option<String>,
     /// Interaction states
     pub focused: bool,
     pub hovered: bool,
 }
 
+impl FocusableComponent for MaterialSelect {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl OverlayComponent for MaterialSelect {
+    fn is_overlay_open(&self) -> bool {
+        self.open
+    }
+}
+
 impl MaterialSelect {
     /// Create a new select
     pub fn new(options: Vec<SelectOption>) -> Self {
@@ -81,6 +144,15 @@ impl MaterialSelect {
             error: false,
             error_text: None,
             open: false,
+            filterable: false,
+            filter: String::new(),
+            highlighted_index: None,
+            multiple: false,
+            selected_indices: BTreeSet::new(),
+            multi_display: MultiSelectDisplay::default(),
+            editable: false,
+            allow_custom: false,
+            custom_value: None,
             focused: false,
             hovered: false,
         }
@@ -92,14 +164,42 @@ impl MaterialSelect {
         self
     }
 
-    /// Set initially selected option
+    /// Set initially selected option (added to the selection of a multi-select)
     pub fn selected(mut self, index: usize) -> Self {
         if index < self.options.len() {
-            self.selected_index = Some(index);
+            if self.multiple {
+                self.selected_indices.insert(index);
+            } else {
+                self.selected_index = Some(index);
+            }
         }
         self
     }
 
+    /// Allow selecting several options
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set how a multi-select shows its selection
+    pub fn multi_display(mut self, display: MultiSelectDisplay) -> Self {
+        self.multi_display = display;
+        self
+    }
+
+    /// Make the field a typeable combobox
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Let an editable select commit text that matches no option
+    pub fn allow_custom(mut self, allow_custom: bool) -> Self {
+        self.allow_custom = allow_custom;
+        self
+    }
+
     /// Set label
     pub fn label(mut self, label: impl Into<String>) -> Self {
         self.label = Some(label.into());
@@ -131,15 +231,201 @@ impl MaterialSelect {
         self
     }
 
+    /// Add a filter input to the dropdown
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Options matching the current filter, best first, as `(index, match)` pairs.
+    ///
+    /// Without a filter every option is listed in order.
+    pub fn filtered_options(&self) -> Vec<(usize, FuzzyMatch)> {
+        let labels: Vec<&str> = self.options.iter().map(|o| o.label.as_str()).collect();
+        fuzzy_filter(&self.filter, &labels)
+    }
+
+    /// Indices of the options keyboard navigation visits, in dropdown order
+    /// (enabled options matching the filter).
+    pub fn navigable_options(&self) -> Vec<usize> {
+        self.filtered_options()
+            .into_iter()
+            .map(|(index, _)| index)
+            .filter(|index| !self.options[*index].disabled)
+            .collect()
+    }
+
+    /// Highlight the option after the highlighted one (wrapping around)
+    pub fn highlight_next(&mut self) {
+        self.step_highlight(1);
+    }
+
+    /// Highlight the option before the highlighted one (wrapping around)
+    pub fn highlight_previous(&mut self) {
+        self.step_highlight(-1);
+    }
+
+    /// Highlight the first navigable option
+    pub fn highlight_first(&mut self) {
+        self.highlighted_index = self.navigable_options().first().copied();
+    }
+
+    /// Highlight the last navigable option
+    pub fn highlight_last(&mut self) {
+        self.highlighted_index = self.navigable_options().last().copied();
+    }
+
+    fn step_highlight(&mut self, offset: isize) {
+        let navigable = self.navigable_options();
+        if navigable.is_empty() {
+            self.highlighted_index = None;
+            return;
+        }
+        let current = self
+            .highlighted_index
+            .and_then(|index| navigable.iter().position(|i| *i == index));
+        let len = navigable.len() as isize;
+        let next = match current {
+            Some(position) => (position as isize + offset).rem_euclid(len),
+            None if offset >= 0 => 0,
+            None => len - 1,
+        };
+        self.highlighted_index = Some(navigable[next as usize]);
+    }
+
+    /// Jump to the next option whose label starts with the typed `prefix`
+    /// (case- and diacritic-insensitive), as native dropdowns do.
+    ///
+    /// Repeating one letter (`"bbb"`) cycles through the options starting with it;
+    /// a longer prefix keeps the highlighted option while it still matches.
+    /// Returns whether an option matched.
+    pub fn type_ahead(&mut self, prefix: &str) -> bool {
+        let prefix = fold(prefix);
+        let Some(first) = prefix.chars().next() else {
+            return false;
+        };
+        let repeated = prefix.chars().all(|c| c == first);
+        let search = if repeated { first.to_string() } else { prefix };
+
+        let navigable = self.navigable_options();
+        let current = self
+            .highlighted_index
+            .and_then(|index| navigable.iter().position(|i| *i == index));
+        let start = match current {
+            // A fresh or repeated letter moves on; a longer prefix may stay.
+            Some(position) if repeated => position + 1,
+            Some(position) => position,
+            None => 0,
+        };
+
+        let found = (0..navigable.len())
+            .map(|offset| navigable[(start + offset) % navigable.len()])
+            .find(|index| fold(&self.options[*index].label).starts_with(&search));
+        if found.is_some() {
+            self.highlighted_index = found;
+        }
+        found.is_some()
+    }
+
+    /// Select the highlighted option (see [`Self::select_option`]).
+    ///
+    /// Returns the selected index, or `None` when nothing (enabled) is highlighted.
+    pub fn commit_highlighted(&mut self) -> Option<usize> {
+        let index = self.highlighted_index?;
+        self.select_option(index).then_some(index)
+    }
+
+    /// Pick an option as the user would: a single select selects it and closes, a
+    /// multi-select toggles it and stays open.
+    ///
+    /// Returns `false` (and changes nothing) for a disabled or unknown option.
+    pub fn select_option(&mut self, index: usize) -> bool {
+        if self.options.get(index).is_none_or(|o| o.disabled) {
+            return false;
+        }
+        if self.multiple {
+            if !self.selected_indices.remove(&index) {
+                self.selected_indices.insert(index);
+            }
+        } else {
+            self.selected_index = Some(index);
+            self.custom_value = None;
+            self.open = false;
+        }
+        self.focused = true;
+        true
+    }
+
+    /// Whether an option is selected
+    pub fn is_selected(&self, index: usize) -> bool {
+        if self.multiple {
+            self.selected_indices.contains(&index)
+        } else {
+            self.selected_index == Some(index)
+        }
+    }
+
+    /// Selected options, in option order
+    pub fn selected_options(&self) -> Vec<&SelectOption> {
+        if self.multiple {
+            self.selected_indices
+                .iter()
+                .filter_map(|index| self.options.get(*index))
+                .collect()
+        } else {
+            self.selected_option().into_iter().collect()
+        }
+    }
+
+    /// Values of the selected options (the label for options without a value)
+    pub fn selected_values(&self) -> Vec<String> {
+        self.selected_options()
+            .into_iter()
+            .map(|option| option.value.clone().unwrap_or_else(|| option.label.clone()))
+            .collect()
+    }
+
+    /// Whether the selection is shown as input chips
+    fn shows_chips(&self) -> bool {
+        self.multiple && self.multi_display == MultiSelectDisplay::Chips
+    }
+
+    /// Whether typing goes to a text input (filter or combobox) rather than type-ahead
+    fn has_text_input(&self) -> bool {
+        self.filterable || self.editable
+    }
+
+    /// Background of an option row (selected, highlighted or plain)
+    pub fn option_row_color(&self, index: usize, theme: &MaterialTheme) -> Color {
+        if self.is_selected(index) {
+            theme.secondary_container
+        } else if self.open && self.highlighted_index == Some(index) {
+            theme.on_surface.with_alpha(0.08)
+        } else {
+            Color::NONE
+        }
+    }
+
     /// Get the selected option
     pub fn selected_option(&self) -> Option<&SelectOption> {
         self.selected_index.and_then(|i| self.options.get(i))
     }
 
     /// Get the display text for the current selection
+    ///
+    /// A multi-select with several options selected shows a count ("3 selected"); an
+    /// editable select without a selected option shows its custom value.
     pub fn display_text(&self) -> String {
+        if self.multiple {
+            return match self.selected_options().as_slice() {
+                [] => String::new(),
+                [option] => option.label.clone(),
+                options => format!("{} selected", options.len()),
+            };
+        }
         self.selected_option()
             .map(|o| o.label.clone())
+            .or_else(|| self.custom_value.clone())
             .unwrap_or_default()
     }
 
@@ -261,9 +547,40 @@ pub struct SelectChangeEvent {
     pub option: SelectOption,
 }
 
+/// Event when the selection of a multi-select changes
+#[derive(Event, bevy::prelude::Message)]
+pub struct SelectMultiChangeEvent {
+    pub entity: Entity,
+    /// Selected option indices, in option order
+    pub indices: Vec<usize>,
+    /// Selected options, in option order
+    pub options: Vec<SelectOption>,
+}
+
+impl SelectMultiChangeEvent {
+    fn new(entity: Entity, select: &MaterialSelect) -> Self {
+        Self {
+            entity,
+            indices: select.selected_indices.iter().copied().collect(),
+            options: select.selected_options().into_iter().cloned().collect(),
+        }
+    }
+}
+
+/// Event when an editable select commits text that matches no option
+#[derive(Event, bevy::prelude::Message)]
+pub struct SelectCustomValueEvent {
+    pub entity: Entity,
+    pub value: String,
+}
+
 /// Select dimensions
 pub const SELECT_HEIGHT: f32 = 56.0;
 pub const SELECT_OPTION_HEIGHT: f32 = 48.0;
+/// Space between the field and its dropdown
+pub const SELECT_DROPDOWN_GAP: f32 = 4.0;
+/// Seconds after the last typed character before type-ahead starts a new prefix
+pub const SELECT_TYPE_AHEAD_TIMEOUT: f32 = 1.0;
 
 /// System to handle select interactions
 fn select_interaction_system(
@@ -292,6 +609,24 @@ fn select_interaction_system(
     }
 }
 
+/// System to open the focused select with Space / Enter
+fn select_activation_system(focus: Res<FocusManager>, mut selects: Query<&mut MaterialSelect>) {
+    if !focus.activation_requested() {
+        return;
+    }
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+    let Ok(mut select) = selects.get_mut(entity) else {
+        return;
+    };
+    // An open dropdown handles Space / Enter itself.
+    if !select.disabled && !select.open {
+        select.open = true;
+        select.focused = true;
+    }
+}
+
 /// System to update select styles
 fn select_style_system(
     theme: Option<Res<MaterialTheme>>,
@@ -319,6 +654,7 @@ fn select_content_style_system(
         Query<(&ChildOf, &mut TextColor), With<SelectDropdownArrow>>,
         Query<&mut TextColor, With<SelectOptionLabelText>>,
         Query<&mut TextColor, With<SelectOptionIcon>>,
+        Query<(&mut Text, &mut TextColor), With<SelectOptionCheckbox>>,
     )>,
     mut dropdowns: Query<
         (&ChildOf, &mut BackgroundColor),
@@ -366,14 +702,7 @@ fn select_content_style_system(
             continue;
         };
 
-        let is_selected = select
-            .selected_index
-            .is_some_and(|i| i == option_item.index);
-        row_bg.0 = if is_selected {
-            theme.secondary_container
-        } else {
-            Color::NONE
-        };
+        row_bg.0 = select.option_row_color(option_item.index, &theme);
 
         let base = theme.on_surface;
         let is_disabled = select
@@ -393,6 +722,17 @@ fn select_content_style_system(
             if let Ok(mut c) = text_colors.p3().get_mut(child) {
                 c.0 = text_color;
             }
+            if let Ok((mut glyph, mut c)) = text_colors.p4().get_mut(child) {
+                let (icon, color) = option_checkbox_visual(
+                    select.is_selected(option_item.index),
+                    is_disabled,
+                    &theme,
+                );
+                if glyph.0 != icon {
+                    glyph.0 = icon;
+                }
+                c.0 = color;
+            }
         }
     }
 }
@@ -410,6 +750,7 @@ fn select_theme_refresh_system(
         Query<(&ChildOf, &mut TextColor), With<SelectDropdownArrow>>,
         Query<&mut TextColor, With<SelectOptionLabelText>>,
         Query<&mut TextColor, With<SelectOptionIcon>>,
+        Query<(&mut Text, &mut TextColor), With<SelectOptionCheckbox>>,
     )>,
     mut dropdowns: Query<
         (&ChildOf, &mut BackgroundColor),
@@ -462,14 +803,7 @@ fn select_theme_refresh_system(
             continue;
         };
 
-        let is_selected = select
-            .selected_index
-            .is_some_and(|i| i == option_item.index);
-        row_bg.0 = if is_selected {
-            theme.secondary_container
-        } else {
-            Color::NONE
-        };
+        row_bg.0 = select.option_row_color(option_item.index, &theme);
 
         let base = theme.on_surface;
         let is_disabled = select
@@ -489,6 +823,17 @@ fn select_theme_refresh_system(
             if let Ok(mut c) = text_colors.p3().get_mut(child) {
                 c.0 = text_color;
             }
+            if let Ok((mut glyph, mut c)) = text_colors.p4().get_mut(child) {
+                let (icon, color) = option_checkbox_visual(
+                    select.is_selected(option_item.index),
+                    is_disabled,
+                    &theme,
+                );
+                if glyph.0 != icon {
+                    glyph.0 = icon;
+                }
+                c.0 = color;
+            }
         }
     }
 }
@@ -524,11 +869,33 @@ impl SelectBuilder {
         self.variant(SelectVariant::Outlined)
     }
 
-    /// Set initially selected option
+    /// Set initially selected option (call repeatedly for a multi-select)
     pub fn selected(mut self, index: usize) -> Self {
-        if index < self.select.options.len() {
-            self.select.selected_index = Some(index);
-        }
+        self.select = self.select.selected(index);
+        self
+    }
+
+    /// Allow selecting several options
+    pub fn multiple(mut self) -> Self {
+        self.select.multiple = true;
+        self
+    }
+
+    /// Set how a multi-select shows its selection
+    pub fn multi_display(mut self, display: MultiSelectDisplay) -> Self {
+        self.select.multi_display = display;
+        self
+    }
+
+    /// Make the field a typeable combobox
+    pub fn editable(mut self) -> Self {
+        self.select.editable = true;
+        self
+    }
+
+    /// Let an editable select commit text that matches no option
+    pub fn allow_custom(mut self) -> Self {
+        self.select.allow_custom = true;
         self
     }
 
@@ -563,6 +930,12 @@ impl SelectBuilder {
         self
     }
 
+    /// Add a filter input to the dropdown
+    pub fn filterable(mut self) -> Self {
+        self.select.filterable = true;
+        self
+    }
+
     /// Set width
     pub fn width(mut self, width: Val) -> Self {
         self.width = width;
@@ -574,13 +947,20 @@ impl SelectBuilder {
         let bg_color = self.select.container_color(theme);
         let border_color = self.select.indicator_color(theme);
         let is_outlined = self.select.variant == SelectVariant::Outlined;
+        // Chips may wrap onto more lines, so the field grows with them.
+        let height = if self.select.shows_chips() {
+            Val::Auto
+        } else {
+            Val::Px(SELECT_HEIGHT)
+        };
 
         (
             self.select,
             Button,
             Node {
                 width: self.width,
-                height: Val::Px(SELECT_HEIGHT),
+                height,
+                min_height: Val::Px(SELECT_HEIGHT),
                 padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::MEDIUM)),
                 border: if is_outlined {
                     UiRect::all(Val::Px(1.0))
@@ -646,11 +1026,36 @@ pub struct SelectDropdownArrow;
 #[derive(Component)]
 pub struct SelectOptionLabelText;
 
+/// Marker for the filter input at the top of a filterable select's dropdown
+#[derive(Component)]
+pub struct SelectFilterInput;
+
+/// Marker for the text input replacing the display text of an editable select
+#[derive(Component)]
+pub struct SelectComboboxInput;
+
+/// Marker for the row holding a multi-select's input chips
+#[derive(Component)]
+pub struct SelectChipRow;
+
+/// Input chip standing for one selected option of a multi-select
+#[derive(Component)]
+pub struct SelectChip {
+    /// The owning select
+    pub select: Entity,
+    /// Index of the option the chip stands for
+    pub index: usize,
+}
+
+/// Marker for the checkbox icon of multi-select option rows
+#[derive(Component)]
+pub struct SelectOptionCheckbox;
+
 /// Keep dropdown visibility + displayed text in sync with `MaterialSelect`.
 fn select_dropdown_sync_system(
     mut selects: Query<(&MaterialSelect, &Children), Changed<MaterialSelect>>,
     mut dropdowns: Query<&mut Visibility, With<SelectDropdown>>,
-    mut display_texts: Query<&mut Text, With<SelectDisplayText>>,
+    mut display_texts: Query<(&mut Text, &mut Node), With<SelectDisplayText>>,
 ) {
     for (select, children) in selects.iter_mut() {
         // Update dropdown visibility
@@ -667,14 +1072,26 @@ fn select_dropdown_sync_system(
         // Update displayed text
         let placeholder = select.label.as_deref().unwrap_or("Select");
 
-        let display = select
-            .selected_option()
-            .map(|o| o.label.as_str())
-            .unwrap_or(placeholder);
+        let display = select.display_text();
+        let display = if display.is_empty() {
+            placeholder
+        } else {
+            display.as_str()
+        };
+        // Chips take the place of the text once something is selected.
+        let shows_chips = select.shows_chips() && !select.selected_indices.is_empty();
 
         for child in children.iter() {
-            if let Ok(mut text) = display_texts.get_mut(child) {
+            if let Ok((mut text, mut node)) = display_texts.get_mut(child) {
                 *text = Text::new(display);
+                let visible = if shows_chips {
+                    Display::None
+                } else {
+                    Display::Flex
+                };
+                if node.display != visible {
+                    node.display = visible;
+                }
             }
         }
     }
@@ -685,40 +1102,528 @@ fn select_option_interaction_system(
     mut interactions: Query<(&Interaction, &SelectOptionItem, &SelectOwner), Changed<Interaction>>,
     mut selects: Query<(Entity, &mut MaterialSelect)>,
     mut events: MessageWriter<SelectChangeEvent>,
+    mut multi_events: MessageWriter<SelectMultiChangeEvent>,
 ) {
     for (interaction, option_item, owner) in interactions.iter_mut() {
+        let Ok((select_entity, mut select)) = selects.get_mut(owner.0) else {
+            continue;
+        };
+
+        // Hovering moves the keyboard highlight, so both stay in agreement.
+        if *interaction == Interaction::Hovered {
+            let enabled = select
+                .options
+                .get(option_item.index)
+                .is_some_and(|o| !o.disabled);
+            if enabled && select.highlighted_index != Some(option_item.index) {
+                select.highlighted_index = Some(option_item.index);
+            }
+            continue;
+        }
         if *interaction != Interaction::Pressed {
             continue;
         }
 
-        let Ok((select_entity, mut select)) = selects.get_mut(owner.0) else {
+        // Ignore disabled options
+        if !select.select_option(option_item.index) {
             continue;
+        }
+        write_selection_event(
+            select_entity,
+            &select,
+            option_item.index,
+            &mut events,
+            &mut multi_events,
+        );
+    }
+}
+
+/// Report a picked option: `SelectChangeEvent` for a single select, the whole
+/// selection as `SelectMultiChangeEvent` for a multi-select.
+fn write_selection_event(
+    entity: Entity,
+    select: &MaterialSelect,
+    index: usize,
+    events: &mut MessageWriter<SelectChangeEvent>,
+    multi_events: &mut MessageWriter<SelectMultiChangeEvent>,
+) {
+    if select.multiple {
+        multi_events.write(SelectMultiChangeEvent::new(entity, select));
+    } else {
+        events.write(SelectChangeEvent {
+            entity,
+            index,
+            option: select.options[index].clone(),
+        });
+    }
+}
+
+/// Keyboard control of open dropdowns.
+///
+/// Up/Down (and Home/End) move the highlighted option, skipping disabled ones; Enter
+/// selects it (Enter or Space toggles it in a multi-select) and Escape closes the
+/// dropdown. Typed letters jump to the next option starting with them, building a
+/// prefix until typing pauses for [`SELECT_TYPE_AHEAD_TIMEOUT`]. Filterable and
+/// editable selects leave Home/End, Enter and typing to their text input.
+///
+/// Keys go to one dropdown only: the open select holding focus (or containing the
+/// focused element), otherwise the most recently opened one.
+fn select_keyboard_system(
+    time: Res<Time>,
+    mut keyboard_inputs: MessageReader<bevy::input::keyboard::KeyboardInput>,
+    mut type_ahead: Local<(String, f32)>,
+    mut open_order: Local<Vec<Entity>>,
+    focus: Option<Res<FocusManager>>,
+    parents: Query<&ChildOf>,
+    mut selects: Query<(Entity, &mut MaterialSelect)>,
+    mut events: MessageWriter<SelectChangeEvent>,
+    mut multi_events: MessageWriter<SelectMultiChangeEvent>,
+) {
+    // Opening highlights the selected option (or the first one); closing clears it.
+    // A combobox accepting free text only highlights what the arrows pick.
+    let mut opened_now = Vec::new();
+    for (entity, mut select) in selects.iter_mut() {
+        if !select.open {
+            open_order.retain(|open| *open != entity);
+        } else if !open_order.contains(&entity) {
+            open_order.push(entity);
+            opened_now.push(entity);
+        }
+
+        let highlight = if select.open {
+            let navigable = select.navigable_options();
+            let current = select
+                .highlighted_index
+                .filter(|index| navigable.contains(index));
+            if select.editable && select.allow_custom {
+                current
+            } else {
+                current
+                    .or_else(|| {
+                        select
+                            .selected_index
+                            .filter(|index| navigable.contains(index))
+                    })
+                    .or_else(|| {
+                        navigable
+                            .iter()
+                            .copied()
+                            .find(|index| select.selected_indices.contains(index))
+                    })
+                    .or_else(|| navigable.first().copied())
+            }
+        } else {
+            None
         };
+        if select.highlighted_index != highlight {
+            select.highlighted_index = highlight;
+        }
+    }
 
-        // Ignore disabled options
-        let Some(option) = select.options.get(option_item.index).cloned() else {
+    let now = time.elapsed_secs();
+    if now - type_ahead.1 > SELECT_TYPE_AHEAD_TIMEOUT {
+        type_ahead.0.clear();
+    }
+
+    let pressed: Vec<_> = keyboard_inputs
+        .read()
+        .filter(|ev| ev.state == bevy::input::ButtonState::Pressed)
+        .cloned()
+        .collect();
+    if pressed.is_empty() {
+        return;
+    }
+
+    open_order.retain(|entity| selects.contains(*entity));
+    let focused_select = focus.and_then(|focus| focus.focused()).and_then(|focused| {
+        std::iter::once(focused)
+            .chain(parents.iter_ancestors(focused))
+            .find(|entity| open_order.contains(entity))
+    });
+    let Some(target) = focused_select.or_else(|| open_order.last().copied()) else {
+        return;
+    };
+
+    if let Ok((select_entity, mut select)) = selects.get_mut(target) {
+        if select.disabled {
+            return;
+        }
+
+        // The key that opened the dropdown doesn't also pick an option.
+        let commits = !select.has_text_input() && !opened_now.contains(&select_entity);
+        for ev in &pressed {
+            match ev.key_code {
+                KeyCode::ArrowDown => select.highlight_next(),
+                KeyCode::ArrowUp => select.highlight_previous(),
+                KeyCode::Home if !select.has_text_input() => select.highlight_first(),
+                KeyCode::End if !select.has_text_input() => select.highlight_last(),
+                KeyCode::Enter | KeyCode::NumpadEnter if commits => {
+                    if let Some(index) = select.commit_highlighted() {
+                        write_selection_event(
+                            select_entity,
+                            &select,
+                            index,
+                            &mut events,
+                            &mut multi_events,
+                        );
+                    }
+                }
+                KeyCode::Space if select.multiple && commits && type_ahead.0.is_empty() => {
+                    if let Some(index) = select.commit_highlighted() {
+                        write_selection_event(
+                            select_entity,
+                            &select,
+                            index,
+                            &mut events,
+                            &mut multi_events,
+                        );
+                    }
+                }
+                KeyCode::Escape => {
+                    select.open = false;
+                    select.focused = true;
+                }
+                _ if !select.has_text_input() => {
+                    let text: Option<&str> = ev.text.as_deref().or_else(|| match &ev.logical_key {
+                        bevy::input::keyboard::Key::Character(s) => Some(s.as_str()),
+                        _ => None,
+                    });
+                    let Some(text) = text.filter(|t| !t.chars().any(char::is_control)) else {
+                        continue;
+                    };
+                    // A leading space is not a prefix.
+                    if type_ahead.0.is_empty() && text.trim().is_empty() {
+                        continue;
+                    }
+                    type_ahead.0.push_str(text);
+                    type_ahead.1 = now;
+                    let prefix = type_ahead.0.clone();
+                    select.type_ahead(&prefix);
+                }
+                _ => {}
+            }
+            if !select.open {
+                break;
+            }
+        }
+    }
+}
+
+/// Mirror a filter or combobox input into its select.
+///
+/// Typing filters the options (and opens a combobox); Enter picks the highlighted
+/// match, or the option named exactly by the text. A combobox allowing custom values
+/// commits unmatched text as [`SelectCustomValueEvent`].
+fn select_filter_input_system(
+    mut changes: MessageReader<TextFieldChangeEvent>,
+    mut submits: MessageReader<TextFieldSubmitEvent>,
+    presses: Query<(&Interaction, &SelectOwner), (Changed<Interaction>, With<SelectComboboxInput>)>,
+    inputs: Query<&SelectOwner, Or<(With<SelectFilterInput>, With<SelectComboboxInput>)>>,
+    mut selects: Query<&mut MaterialSelect>,
+    mut events: MessageWriter<SelectChangeEvent>,
+    mut multi_events: MessageWriter<SelectMultiChangeEvent>,
+    mut custom_events: MessageWriter<SelectCustomValueEvent>,
+) {
+    // Clicking into a combobox opens it like clicking the field does.
+    for (interaction, owner) in presses.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut select) = selects.get_mut(owner.0) {
+            if !select.open && !select.disabled {
+                select.open = true;
+                select.focused = true;
+            }
+        }
+    }
+
+    for ev in changes.read() {
+        let Ok(owner) = inputs.get(ev.entity) else {
+            continue;
+        };
+        let Ok(mut select) = selects.get_mut(owner.0) else {
             continue;
         };
-        if option.disabled {
+        if select.filter == ev.value {
             continue;
         }
+        select.filter = ev.value.clone();
+        if select.editable && select.allow_custom {
+            select.highlighted_index = None;
+        } else {
+            select.highlight_first();
+        }
+        if select.editable && !select.open && !select.disabled {
+            select.open = true;
+        }
+    }
 
-        select.selected_index = Some(option_item.index);
-        select.open = false;
-        select.focused = true;
+    for ev in submits.read() {
+        let Ok(owner) = inputs.get(ev.entity) else {
+            continue;
+        };
+        let Ok(mut select) = selects.get_mut(owner.0) else {
+            continue;
+        };
+        let navigable = select.navigable_options();
+        let text = fold(ev.value.trim());
+        let exact = navigable
+            .iter()
+            .copied()
+            .find(|index| !text.is_empty() && fold(&select.options[*index].label) == text);
+        let target = select
+            .highlighted_index
+            .filter(|index| navigable.contains(index))
+            .or(exact)
+            .or_else(|| {
+                if select.editable && select.allow_custom {
+                    None
+                } else {
+                    navigable.first().copied()
+                }
+            });
+        select.highlighted_index = target;
+
+        if let Some(index) = select.commit_highlighted() {
+            write_selection_event(owner.0, &select, index, &mut events, &mut multi_events);
+        } else if select.editable && select.allow_custom && !ev.value.trim().is_empty() {
+            let value = ev.value.trim().to_string();
+            select.selected_index = None;
+            select.custom_value = Some(value.clone());
+            select.open = false;
+            custom_events.write(SelectCustomValueEvent {
+                entity: owner.0,
+                value,
+            });
+        }
+    }
+}
 
-        events.write(SelectChangeEvent {
-            entity: select_entity,
-            index: option_item.index,
-            option,
-        });
+/// Apply the filter of filterable and editable selects: hide options that do not
+/// match, order the rest best first and emphasize the matched characters. The input
+/// takes focus while the dropdown is open; when it closes, a filter input is cleared
+/// and a combobox input shows the selection again.
+fn select_filter_sync_system(
+    theme: Option<Res<MaterialTheme>>,
+    mut commands: Commands,
+    mut focus: ResMut<FocusManager>,
+    mut open_selects: Local<Vec<Entity>>,
+    mut selects: Query<
+        (Entity, &mut MaterialSelect, &Children),
+        (Changed<MaterialSelect>, Without<SelectDropdown>),
+    >,
+    mut dropdowns: Query<&mut Children, (With<SelectDropdown>, Without<MaterialSelect>)>,
+    mut inputs: Query<
+        (Entity, &SelectOwner, &mut MaterialTextField),
+        Or<(With<SelectFilterInput>, With<SelectComboboxInput>)>,
+    >,
+    mut rows: Query<
+        (&SelectOptionItem, &mut Node, &Children),
+        (Without<SelectDropdown>, Without<MaterialSelect>),
+    >,
+    mut labels: Query<Option<&mut MatchHighlight>, With<SelectOptionLabelText>>,
+) {
+    let Some(theme) = theme else { return };
+
+    for (select_entity, mut select, children) in selects.iter_mut() {
+        if !select.has_text_input() {
+            continue;
+        }
+
+        let input = inputs
+            .iter()
+            .find(|(_, owner, _)| owner.0 == select_entity)
+            .map(|(entity, _, _)| entity);
+        let was_open = open_selects.contains(&select_entity);
+        if select.open && !was_open {
+            open_selects.push(select_entity);
+            if let Some(input) = input {
+                focus.focus(input);
+                // Typing into a combobox replaces the shown selection.
+                if let Ok((_, _, mut field)) = inputs.get_mut(input) {
+                    if select.editable && !field.value.is_empty() {
+                        field.select_all();
+                    }
+                }
+            }
+        } else if !select.open && was_open {
+            open_selects.retain(|entity| *entity != select_entity);
+            if input.is_some() && focus.focused() == input {
+                focus.blur();
+            }
+            if !select.filter.is_empty() {
+                select.filter.clear();
+            }
+        }
+        if !select.open {
+            let shown = if select.editable {
+                select.display_text()
+            } else {
+                String::new()
+            };
+            for (_, owner, mut field) in inputs.iter_mut() {
+                if owner.0 == select_entity && field.value != shown {
+                    field.set_value(shown.as_str());
+                }
+            }
+        }
+
+        let matches = select.filtered_options();
+
+        for dropdown in children.iter() {
+            let Ok(mut dropdown_children) = dropdowns.get_mut(dropdown) else {
+                continue;
+            };
+
+            // Filter input first, then matches best first, then hidden options.
+            {
+                let sort_key = |entity: &Entity| match rows.get(*entity) {
+                    Ok((item, _, _)) => matches
+                        .iter()
+                        .position(|(index, _)| *index == item.index)
+                        .map_or((2, item.index), |rank| (1, rank)),
+                    Err(_) => (0, 0),
+                };
+                let in_order = dropdown_children
+                    .iter()
+                    .map(|entity| sort_key(&entity))
+                    .is_sorted();
+                if !in_order {
+                    dropdown_children.sort_by_key(sort_key);
+                }
+            }
+
+            for row_entity in dropdown_children.iter() {
+                let Ok((item, mut node, row_children)) = rows.get_mut(row_entity) else {
+                    continue;
+                };
+                let found = matches.iter().find(|(index, _)| *index == item.index);
+                let display = if found.is_some() {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                if node.display != display {
+                    node.display = display;
+                }
+
+                let ranges = found.map(|(_, m)| m.ranges.clone()).unwrap_or_default();
+                for label in row_children.iter() {
+                    match labels.get_mut(label) {
+                        Ok(Some(mut highlight)) => {
+                            if highlight.ranges != ranges {
+                                highlight.ranges = ranges.clone();
+                            }
+                        }
+                        Ok(None) => {
+                            commands.entity(label).insert(
+                                MatchHighlight::new(item.label.clone(), theme.primary)
+                                    .with_ranges(ranges.clone()),
+                            );
+                        }
+                        Err(_) => {}
+                    }
+                }
+            }
+        }
     }
 }
 
+/// Keep the input chips of multi-selects in line with the selection, and deselect
+/// an option when its chip's delete icon is clicked.
+fn select_chip_sync_system(
+    theme: Option<Res<MaterialTheme>>,
+    mut commands: Commands,
+    mut deletes: MessageReader<ChipDeleteEvent>,
+    mut multi_events: MessageWriter<SelectMultiChangeEvent>,
+    mut selects: Query<(Entity, &mut MaterialSelect, &Children)>,
+    chip_rows: Query<Option<&Children>, With<SelectChipRow>>,
+    chips: Query<&SelectChip>,
+) {
+    let Some(theme) = theme else { return };
+
+    for ev in deletes.read() {
+        let Ok(chip) = chips.get(ev.entity) else {
+            continue;
+        };
+        let Ok((entity, mut select, _)) = selects.get_mut(chip.select) else {
+            continue;
+        };
+        if select.disabled || !select.selected_indices.contains(&chip.index) {
+            continue;
+        }
+        select.selected_indices.remove(&chip.index);
+        multi_events.write(SelectMultiChangeEvent::new(entity, &select));
+    }
+
+    for (select_entity, select, children) in selects.iter_mut() {
+        if !select.is_changed() || !select.multiple {
+            continue;
+        }
+        for row in children.iter() {
+            let Ok(row_children) = chip_rows.get(row) else {
+                continue;
+            };
+            let current: Vec<usize> = row_children
+                .into_iter()
+                .flatten()
+                .filter_map(|chip| chips.get(*chip).ok().map(|chip| chip.index))
+                .collect();
+            let wanted: Vec<usize> = select.selected_indices.iter().copied().collect();
+            if current == wanted {
+                continue;
+            }
+
+            commands.entity(row).despawn_related::<Children>();
+            for index in wanted {
+                let Some(option) = select.options.get(index) else {
+                    continue;
+                };
+                let mut builder = ChipBuilder::input(option.label.clone())
+                    .deletable(true)
+                    .disabled(select.disabled);
+                if let Some(value) = &option.value {
+                    builder = builder.value(value.clone());
+                }
+                let chip = spawn_chip(&mut commands, &theme, builder);
+                commands.entity(chip).insert(SelectChip {
+                    select: select_entity,
+                    index,
+                });
+                commands.entity(row).add_child(chip);
+            }
+        }
+    }
+}
+
+/// Checkbox glyph and color of a multi-select option row
+fn option_checkbox_visual(checked: bool, disabled: bool, theme: &MaterialTheme) -> (String, Color) {
+    let icon = if checked {
+        MaterialIcon::checkbox_checked()
+    } else {
+        MaterialIcon::checkbox_unchecked()
+    };
+    let color = if disabled {
+        theme.on_surface.with_alpha(0.38)
+    } else if checked {
+        theme.primary
+    } else {
+        theme.on_surface_variant
+    };
+    (icon.as_str(), color)
+}
+
 /// Apply the Material Symbols font to select option icon text nodes.
 fn select_option_icon_font_system(
     icon_font: Option<Res<MaterialIconFont>>,
-    mut icons: Query<&mut TextFont, Or<(With<SelectOptionIcon>, With<SelectDropdownArrow>)>>,
+    mut icons: Query<
+        &mut TextFont,
+        Or<(
+            With<SelectOptionIcon>,
+            With<SelectDropdownArrow>,
+            With<SelectOptionCheckbox>,
+        )>,
+    >,
 ) {
     let Some(icon_font) = icon_font else { return };
     for mut text_font in icons.iter_mut() {
@@ -779,6 +1684,12 @@ impl SpawnSelectChild for ChildSpawnerCommands<'_> {
         // Clone options for building the dropdown list
         let options = builder.select.options.clone();
         let selected_index = builder.select.selected_index;
+        let selected_indices = builder.select.selected_indices.clone();
+        let filterable = builder.select.filterable;
+        let multiple = builder.select.multiple;
+        let shows_chips = builder.select.shows_chips();
+        let editable = builder.select.editable;
+        let initial_text = builder.select.display_text();
         let placeholder = builder
             .select
             .label
@@ -789,25 +1700,69 @@ impl SpawnSelectChild for ChildSpawnerCommands<'_> {
         let select_entity = select_entity_commands.id();
 
         select_entity_commands.with_children(|select| {
-            // Display text
-            let display_label = selected_index
-                .and_then(|idx| options.get(idx))
-                .map(|o| o.label.as_str())
-                .unwrap_or(placeholder.as_str());
+            // Input chips of a multi-select (filled by `select_chip_sync_system`)
+            if shows_chips {
+                select.spawn((
+                    SelectChipRow,
+                    Node {
+                        flex_grow: 1.0,
+                        flex_wrap: FlexWrap::Wrap,
+                        column_gap: Val::Px(Spacing::SMALL),
+                        row_gap: Val::Px(Spacing::SMALL),
+                        ..default()
+                    },
+                ));
+            }
 
-            select.spawn((
-                SelectDisplayText,
-                Text::new(display_label),
-                TextFont {
-                    font_size: 16.0,
-                    ..default()
-                },
-                TextColor(text_color),
-                Node {
-                    flex_grow: 1.0,
-                    ..default()
-                },
-            ));
+            if editable {
+                // Combobox input in place of the display text
+                let field = MaterialTextField::new()
+                    .placeholder(placeholder.as_str())
+                    .with_value(initial_text.as_str());
+                let mut input = select.spawn((
+                    SelectComboboxInput,
+                    SelectOwner(select_entity),
+                    Button,
+                    Interaction::None,
+                    Node {
+                        flex_grow: 1.0,
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                ));
+                let input_entity = input.id();
+                input.with_children(|input_children| {
+                    spawn_embedded_text_field_input(input_children, theme, input_entity, &field);
+                });
+                input.insert(field);
+            } else {
+                // Display text
+                let display_label = if initial_text.is_empty() || shows_chips {
+                    placeholder.as_str()
+                } else {
+                    initial_text.as_str()
+                };
+
+                select.spawn((
+                    SelectDisplayText,
+                    Text::new(display_label),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(text_color),
+                    Node {
+                        flex_grow: 1.0,
+                        display: if shows_chips && !initial_text.is_empty() {
+                            Display::None
+                        } else {
+                            Display::Flex
+                        },
+                        ..default()
+                    },
+                ));
+            }
 
             // Dropdown arrow
             select.spawn((
@@ -830,9 +1785,16 @@ impl SpawnSelectChild for ChildSpawnerCommands<'_> {
                     // If the dropdown is promoted to a root node by `GlobalZIndex`, it must
                     // be above modal overlays, otherwise it will render "behind" dialogs.
                     GlobalZIndex(1100),
+                    // Opens below the field, or above it when there is no room below
+                    Popover::new(select_entity).with_gap(SELECT_DROPDOWN_GAP),
                     Node {
                         position_type: PositionType::Absolute,
-                        top: Val::Px(SELECT_HEIGHT + 4.0),
+                        // Until the popover has measured the field
+                        top: if shows_chips {
+                            Val::Percent(100.0)
+                        } else {
+                            Val::Px(SELECT_HEIGHT + SELECT_DROPDOWN_GAP)
+                        },
                         left: Val::Px(0.0),
                         width: Val::Percent(100.0),
                         flex_direction: FlexDirection::Column,
@@ -843,9 +1805,40 @@ impl SpawnSelectChild for ChildSpawnerCommands<'_> {
                     BorderRadius::all(Val::Px(8.0)),
                 ))
                 .with_children(|dropdown| {
+                    if filterable {
+                        let field = MaterialTextField::new().placeholder("Filter");
+                        let mut input = dropdown.spawn((
+                            SelectFilterInput,
+                            SelectOwner(select_entity),
+                            Button,
+                            Interaction::None,
+                            Node {
+                                height: Val::Px(SELECT_OPTION_HEIGHT),
+                                padding: UiRect::horizontal(Val::Px(Spacing::LARGE)),
+                                flex_direction: FlexDirection::Column,
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                        ));
+                        let input_entity = input.id();
+                        input.with_children(|input_children| {
+                            spawn_embedded_text_field_input(
+                                input_children,
+                                theme,
+                                input_entity,
+                                &field,
+                            );
+                        });
+                        input.insert(field);
+                    }
+
                     for (index, option) in options.iter().enumerate() {
                         let is_disabled = option.disabled;
-                        let is_selected = selected_index.is_some_and(|i| i == index);
+                        let is_selected = if multiple {
+                            selected_indices.contains(&index)
+                        } else {
+                            selected_index.is_some_and(|i| i == index)
+                        };
                         let row_bg = if is_selected {
                             theme.secondary_container
                         } else {
@@ -871,6 +1864,21 @@ impl SpawnSelectChild for ChildSpawnerCommands<'_> {
                                 BackgroundColor(row_bg),
                             ))
                             .with_children(|row| {
+                                // Checkbox of a multi-select option
+                                if multiple {
+                                    let (icon, color) =
+                                        option_checkbox_visual(is_selected, is_disabled, theme);
+                                    row.spawn((
+                                        SelectOptionCheckbox,
+                                        Text::new(icon),
+                                        TextFont {
+                                            font_size: 24.0,
+                                            ..default()
+                                        },
+                                        TextColor(color),
+                                    ));
+                                }
+
                                 // Optional leading icon
                                 if let Some(icon) = &option.icon {
                                     let icon_text = MaterialIcon::from_name(icon.as_str())
@@ -911,3 +1919,181 @@ impl SpawnSelectChild for ChildSpawnerCommands<'_> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::focus::{FocusPlugin, Focusable};
+    use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+    use bevy::input::{ButtonState, InputPlugin};
+
+    fn options(labels: &[&str]) -> Vec<SelectOption> {
+        labels
+            .iter()
+            .map(|label| SelectOption::new(*label))
+            .collect()
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, FocusPlugin, SelectPlugin))
+            .init_resource::<MaterialTheme>();
+        app
+    }
+
+    fn spawn_select(app: &mut App, builder: SelectBuilder) -> Entity {
+        let theme = app.world().resource::<MaterialTheme>().clone();
+        let root = app.world_mut().spawn(Node::default()).id();
+        app.world_mut()
+            .commands()
+            .entity(root)
+            .with_children(|parent| parent.spawn_select_with(&theme, builder));
+        app.update();
+        app.world().get::<Children>(root).unwrap()[0]
+    }
+
+    /// Press and release `key_code` within one frame.
+    fn press_key(app: &mut App, key_code: KeyCode) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            app.world_mut().write_message(KeyboardInput {
+                key_code,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+        }
+        app.update();
+    }
+
+    fn set_open(app: &mut App, select: Entity, open: bool) {
+        app.world_mut()
+            .get_mut::<MaterialSelect>(select)
+            .unwrap()
+            .open = open;
+        app.update();
+    }
+
+    fn highlighted(app: &App, select: Entity) -> Option<usize> {
+        app.world()
+            .get::<MaterialSelect>(select)
+            .unwrap()
+            .highlighted_index
+    }
+
+    #[test]
+    fn test_keys_go_to_most_recently_opened_select() {
+        let mut app = test_app();
+        let first = spawn_select(&mut app, SelectBuilder::new(options(&["a", "b", "c"])));
+        let second = spawn_select(&mut app, SelectBuilder::new(options(&["x", "y", "z"])));
+
+        set_open(&mut app, first, true);
+        set_open(&mut app, second, true);
+        assert_eq!(highlighted(&app, first), Some(0));
+        assert_eq!(highlighted(&app, second), Some(0));
+
+        press_key(&mut app, KeyCode::ArrowDown);
+        assert_eq!(highlighted(&app, first), Some(0));
+        assert_eq!(highlighted(&app, second), Some(1));
+
+        // Escape closes only the second; the next keys reach the first again.
+        press_key(&mut app, KeyCode::Escape);
+        assert!(app.world().get::<MaterialSelect>(first).unwrap().open);
+        assert!(!app.world().get::<MaterialSelect>(second).unwrap().open);
+
+        press_key(&mut app, KeyCode::ArrowDown);
+        assert_eq!(highlighted(&app, first), Some(1));
+    }
+
+    #[test]
+    fn test_select_is_focusable_and_opens_on_activation() {
+        let mut app = test_app();
+        let select = spawn_select(&mut app, SelectBuilder::new(options(&["a", "b"])));
+        app.update();
+        assert!(app.world().get::<Focusable>(select).is_some());
+
+        app.world_mut()
+            .resource_mut::<FocusManager>()
+            .focus_visible(select);
+        app.update();
+        app.world_mut()
+            .resource_mut::<FocusManager>()
+            .request_activation();
+        app.update();
+        assert!(app.world().get::<MaterialSelect>(select).unwrap().open);
+    }
+
+    #[test]
+    fn test_enter_commits_and_closes() {
+        let mut app = test_app();
+        let select = spawn_select(&mut app, SelectBuilder::new(options(&["a", "b", "c"])));
+        app.world_mut()
+            .resource_mut::<FocusManager>()
+            .focus_visible(select);
+        app.update();
+
+        // Enter opens the dropdown without picking anything.
+        press_key(&mut app, KeyCode::Enter);
+        let state = app.world().get::<MaterialSelect>(select).unwrap();
+        assert!(state.open);
+        assert_eq!(state.selected_index, None);
+
+        press_key(&mut app, KeyCode::ArrowDown);
+        press_key(&mut app, KeyCode::Enter);
+        app.update();
+        let state = app.world().get::<MaterialSelect>(select).unwrap();
+        assert!(!state.open);
+        assert_eq!(state.selected_index, Some(1));
+    }
+
+    #[test]
+    fn test_multi_select_chips_follow_toggled_rows() {
+        let mut app = test_app();
+        let select = spawn_select(
+            &mut app,
+            SelectBuilder::new(options(&["a", "b", "c"])).multiple(),
+        );
+        set_open(&mut app, select, true);
+
+        let chip_indices = |app: &mut App| -> Vec<usize> {
+            let mut chips: Vec<usize> = app
+                .world_mut()
+                .query::<&SelectChip>()
+                .iter(app.world())
+                .filter(|chip| chip.select == select)
+                .map(|chip| chip.index)
+                .collect();
+            chips.sort();
+            chips
+        };
+        let row = |app: &mut App, index: usize| -> Entity {
+            app.world_mut()
+                .query::<(Entity, &SelectOptionItem)>()
+                .iter(app.world())
+                .find(|(_, item)| item.index == index)
+                .unwrap()
+                .0
+        };
+        let toggle = |app: &mut App, index: usize| {
+            let entity = row(app, index);
+            *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Pressed;
+            app.update();
+            *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::None;
+            app.update();
+        };
+
+        toggle(&mut app, 2);
+        toggle(&mut app, 0);
+        assert_eq!(chip_indices(&mut app), vec![0, 2]);
+
+        toggle(&mut app, 2);
+        assert_eq!(chip_indices(&mut app), vec![0]);
+        let selected = &app
+            .world()
+            .get::<MaterialSelect>(select)
+            .unwrap()
+            .selected_indices;
+        assert_eq!(selected.iter().copied().collect::<Vec<_>>(), vec![0]);
+    }
+}
@@ -6,6 +6,7 @@
 use bevy::prelude::*;
 use bevy::ui::UiGlobalTransform;
 
+use crate::focus::{FocusAppExt, FocusManager, FocusableComponent};
 use crate::theme::MaterialTheme;
 
 /// Slider orientation
@@ -36,14 +37,19 @@ pub struct SliderPlugin;
 
 impl Plugin for SliderPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<SliderChangeEvent>().add_systems(
-            Update,
-            (
-                slider_interaction_system,
-                slider_visual_update_system.after(slider_interaction_system),
-                slider_theme_refresh_system.after(slider_visual_update_system),
-            ),
-        );
+        app.add_message::<SliderChangeEvent>()
+            .register_focusable::<MaterialSlider>()
+            .add_systems(
+                Update,
+                (
+                    slider_interaction_system,
+                    slider_keyboard_system,
+                    slider_visual_update_system
+                        .after(slider_interaction_system)
+                        .after(slider_keyboard_system),
+                    slider_theme_refresh_system.after(slider_visual_update_system),
+                ),
+            );
     }
 }
 
@@ -310,6 +316,38 @@ impl MaterialSlider {
         self.value = self.value.clamp(self.min, self.max);
     }
 
+    /// Amount a single arrow key press changes the value
+    ///
+    /// Uses `step` for discrete sliders and 1% of the range for continuous ones.
+    pub fn keyboard_step(&self) -> f32 {
+        self.step.unwrap_or((self.max - self.min) / 100.0)
+    }
+
+    /// Amount PageUp / PageDown changes the value (10% of the range, at least one step)
+    pub fn keyboard_page_step(&self) -> f32 {
+        ((self.max - self.min) / 10.0).max(self.keyboard_step())
+    }
+
+    /// Change the value by `delta`, snapping to `step` and clamping to the range.
+    ///
+    /// Returns `true` if the value changed.
+    pub fn step_by(&mut self, delta: f32) -> bool {
+        self.set_value(self.value + delta)
+    }
+
+    /// Set the value, snapping to `step` and clamping to the range.
+    ///
+    /// Returns `true` if the value changed.
+    pub fn set_value(&mut self, value: f32) -> bool {
+        let old_value = self.value;
+        let snapped = match self.step {
+            Some(step) if step > 0.0 => (value / step).round() * step,
+            _ => value,
+        };
+        self.value = snapped.clamp(self.min, self.max);
+        (self.value - old_value).abs() > f32::EPSILON
+    }
+
     /// Get the active track color
     pub fn active_track_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
@@ -372,6 +410,12 @@ impl Default for MaterialSlider {
     }
 }
 
+impl FocusableComponent for MaterialSlider {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event when slider value changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct SliderChangeEvent {
@@ -496,6 +540,75 @@ fn slider_interaction_system(
     }
 }
 
+/// System to adjust the focused slider from the keyboard.
+///
+/// Arrow keys move by one step (Right/Up increase, Left/Down decrease),
+/// PageUp/PageDown by a larger jump, and Home/End jump to the range limits.
+/// Key repeats are honored so holding an arrow keeps moving the handle.
+fn slider_keyboard_system(
+    mut keyboard_inputs: MessageReader<bevy::input::keyboard::KeyboardInput>,
+    focus: Res<FocusManager>,
+    mut sliders: Query<&mut MaterialSlider>,
+    mut change_events: MessageWriter<SliderChangeEvent>,
+) {
+    let Some(entity) = focus.focused() else {
+        keyboard_inputs.clear();
+        return;
+    };
+
+    let Ok(mut slider) = sliders.get_mut(entity) else {
+        keyboard_inputs.clear();
+        return;
+    };
+
+    if slider.disabled {
+        keyboard_inputs.clear();
+        return;
+    }
+
+    let mut changed = false;
+    for ev in keyboard_inputs.read() {
+        if ev.state != bevy::input::ButtonState::Pressed {
+            continue;
+        }
+
+        changed |= match ev.key_code {
+            KeyCode::ArrowRight | KeyCode::ArrowUp => {
+                let step = slider.keyboard_step();
+                slider.step_by(step)
+            }
+            KeyCode::ArrowLeft | KeyCode::ArrowDown => {
+                let step = slider.keyboard_step();
+                slider.step_by(-step)
+            }
+            KeyCode::PageUp => {
+                let step = slider.keyboard_page_step();
+                slider.step_by(step)
+            }
+            KeyCode::PageDown => {
+                let step = slider.keyboard_page_step();
+                slider.step_by(-step)
+            }
+            KeyCode::Home => {
+                let min = slider.min;
+                slider.set_value(min)
+            }
+            KeyCode::End => {
+                let max = slider.max;
+                slider.set_value(max)
+            }
+            _ => false,
+        };
+    }
+
+    if changed {
+        change_events.write(SliderChangeEvent {
+            entity,
+            value: slider.value,
+        });
+    }
+}
+
 fn slider_visual_update_system(
     theme: Option<Res<MaterialTheme>>,
     sliders: Query<(&MaterialSlider, &SliderParts), Changed<MaterialSlider>>,
@@ -1358,6 +1471,34 @@ mod tests {
         assert_eq!(slider.value, 60.0);
     }
 
+    #[test]
+    fn test_slider_keyboard_step_continuous() {
+        let slider = MaterialSlider::new(0.0, 200.0);
+        assert_eq!(slider.keyboard_step(), 2.0);
+        assert_eq!(slider.keyboard_page_step(), 20.0);
+    }
+
+    #[test]
+    fn test_slider_keyboard_step_discrete() {
+        let slider = MaterialSlider::new(0.0, 10.0).with_step(5.0);
+        assert_eq!(slider.keyboard_step(), 5.0);
+        // A page never moves less than one step.
+        assert_eq!(slider.keyboard_page_step(), 5.0);
+    }
+
+    #[test]
+    fn test_slider_step_by_clamps() {
+        let mut slider = MaterialSlider::new(0.0, 100.0)
+            .with_step(10.0)
+            .with_value(90.0);
+        assert!(slider.step_by(10.0));
+        assert_eq!(slider.value, 100.0);
+        assert!(!slider.step_by(10.0));
+        assert_eq!(slider.value, 100.0);
+        assert!(slider.set_value(0.0));
+        assert_eq!(slider.value, 0.0);
+    }
+
     #[test]
     fn test_slider_should_show_ticks_always() {
         let slider = MaterialSlider::new(0.0, 100.0)
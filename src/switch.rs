@@ -14,7 +14,12 @@
 
 use bevy::prelude::*;
 
-use crate::{ripple::RippleHost, theme::MaterialTheme, tokens::CornerRadius};
+use crate::{
+    focus::{FocusAppExt, FocusManager, FocusableComponent},
+    ripple::RippleHost,
+    theme::MaterialTheme,
+    tokens::CornerRadius,
+};
 
 /// Marker component for switch state layer
 #[derive(Component)]
@@ -25,14 +30,17 @@ pub struct SwitchPlugin;
 
 impl Plugin for SwitchPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<SwitchChangeEvent>().add_systems(
-            Update,
-            (
-                switch_interaction_system,
-                switch_style_system,
-                switch_theme_refresh_system,
-            ),
-        );
+        app.add_message::<SwitchChangeEvent>()
+            .register_focusable::<MaterialSwitch>()
+            .add_systems(
+                Update,
+                (
+                    switch_interaction_system,
+                    switch_keyboard_system,
+                    switch_style_system,
+                    switch_theme_refresh_system,
+                ),
+            );
     }
 }
 
@@ -173,6 +181,12 @@ impl Default for MaterialSwitch {
     }
 }
 
+impl FocusableComponent for MaterialSwitch {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event when switch state changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct SwitchChangeEvent {
@@ -222,6 +236,35 @@ fn switch_interaction_system(
     }
 }
 
+/// System to toggle the focused switch with Space / Enter
+fn switch_keyboard_system(
+    focus: Res<FocusManager>,
+    mut switches: Query<&mut MaterialSwitch>,
+    mut change_events: MessageWriter<SwitchChangeEvent>,
+) {
+    if !focus.activation_requested() {
+        return;
+    }
+
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+
+    let Ok(mut switch) = switches.get_mut(entity) else {
+        return;
+    };
+
+    if switch.disabled {
+        return;
+    }
+
+    switch.selected = !switch.selected;
+    change_events.write(SwitchChangeEvent {
+        entity,
+        selected: switch.selected,
+    });
+}
+
 /// System to update switch visual styles when state changes
 fn switch_style_system(
     theme: Option<Res<MaterialTheme>>,
@@ -5,7 +5,12 @@
 
 use bevy::prelude::*;
 
-use crate::{ripple::RippleHost, theme::MaterialTheme, tokens::Spacing};
+use crate::{
+    focus::{next_enabled_index, FocusAppExt, FocusManager, FocusableComponent},
+    ripple::RippleHost,
+    theme::MaterialTheme,
+    tokens::Spacing,
+};
 
 /// Plugin for the tabs component
 pub struct TabsPlugin;
@@ -13,7 +18,9 @@ pub struct TabsPlugin;
 impl Plugin for TabsPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<TabChangeEvent>()
+            .register_focusable::<MaterialTab>()
             .add_systems(Update, tab_interaction_system)
+            .add_systems(Update, tab_keyboard_system)
             .add_systems(Update, tab_style_system)
             .add_systems(Update, sync_tabs_selection_system)
             .add_systems(Update, tab_label_and_indicator_system)
@@ -143,6 +150,12 @@ impl MaterialTab {
     }
 }
 
+impl FocusableComponent for MaterialTab {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event when tab selection changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct TabChangeEvent {
@@ -240,6 +253,87 @@ fn tab_interaction_system(
     }
 }
 
+/// System to handle keyboard selection for the focused tab.
+///
+/// Left / Right move focus and selection to the previous / next enabled tab
+/// (wrapping), Home / End jump to the first / last enabled tab, and
+/// Space / Enter select the focused tab.
+fn tab_keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<FocusManager>,
+    tab_query: Query<(&MaterialTab, &ChildOf)>,
+    mut tabs_query: Query<(Entity, &mut MaterialTabs, &Children)>,
+    mut change_events: MessageWriter<TabChangeEvent>,
+) {
+    let Some(focused) = focus.focused() else {
+        return;
+    };
+
+    let Ok((focused_tab, parent)) = tab_query.get(focused) else {
+        return;
+    };
+
+    if focused_tab.disabled {
+        return;
+    }
+
+    let Ok((tabs_entity, mut tabs, children)) = tabs_query.get_mut(parent.parent()) else {
+        return;
+    };
+
+    let mut siblings: Vec<(usize, Entity, bool)> = children
+        .iter()
+        .filter_map(|child| {
+            tab_query
+                .get(child)
+                .ok()
+                .map(|(tab, _)| (tab.index, child, !tab.disabled))
+        })
+        .collect();
+    siblings.sort_by_key(|(index, _, _)| *index);
+
+    let Some(current) = siblings
+        .iter()
+        .position(|(_, entity, _)| *entity == focused)
+    else {
+        return;
+    };
+    let enabled: Vec<bool> = siblings.iter().map(|(_, _, enabled)| *enabled).collect();
+
+    let target = if keys.just_pressed(KeyCode::ArrowRight) {
+        next_enabled_index(&enabled, current, true)
+    } else if keys.just_pressed(KeyCode::ArrowLeft) {
+        next_enabled_index(&enabled, current, false)
+    } else if keys.just_pressed(KeyCode::Home) {
+        enabled.iter().position(|enabled| *enabled)
+    } else if keys.just_pressed(KeyCode::End) {
+        enabled.iter().rposition(|enabled| *enabled)
+    } else if focus.activation_requested() {
+        Some(current)
+    } else {
+        None
+    };
+
+    let Some(target) = target else {
+        return;
+    };
+
+    let (tab_index, tab_entity, _) = siblings[target];
+    if tab_entity != focused {
+        focus.focus_visible(tab_entity);
+    }
+
+    if tabs.selected != tab_index {
+        // `sync_tabs_selection_system` updates the individual tab flags.
+        tabs.selected = tab_index;
+        change_events.write(TabChangeEvent {
+            tabs_entity,
+            tab_entity,
+            index: tab_index,
+        });
+    }
+}
+
 /// System to update tab styles
 fn tab_style_system(
     theme: Option<Res<MaterialTheme>>,
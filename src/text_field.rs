@@ -3,13 +3,23 @@
 //! Text fields let users enter and edit text.
 //! Reference: <https://m3.material.io/components/text-fields/overview>
 
+use std::ops::Range;
+use std::sync::Arc;
+
 use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy::ui::{RelativeCursorPosition, UiGlobalTransform};
+use bevy::window::{Ime, PrimaryWindow};
 
 use crate::{
+    focus::{FocusAppExt, FocusGained, FocusLost, FocusManager, FocusableComponent},
     icons::{icon_by_name, IconStyle, MaterialIcon, ICON_CLOSE},
+    input_format::{FormattedText, InputFormat},
     ripple::RippleHost,
+    scroll::ScrollContainer,
     theme::MaterialTheme,
     tokens::{CornerRadius, Spacing},
+    validation::{self, ValidationTrigger, Validator},
 };
 
 fn resolve_icon_codepoint(icon: &str) -> Option<char> {
@@ -33,22 +43,34 @@ pub struct TextFieldPlugin;
 impl Plugin for TextFieldPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<TextFieldChangeEvent>()
+            .register_focusable::<MaterialTextField>()
             .add_message::<TextFieldSubmitEvent>()
+            .add_message::<TextFieldValidationEvent>()
             .init_resource::<ActiveTextField>()
             .init_resource::<TextFieldClipboard>()
             .init_resource::<TextFieldCaretBlink>()
+            .add_message::<Ime>()
             // These systems have ordering dependencies (input/focus must run before
             // placeholder/display rendering updates in the same frame).
             .add_systems(
                 Update,
                 (
                     text_field_focus_system,
+                    text_field_focus_manager_sync_system,
                     text_field_end_icon_click_system,
+                    text_field_history_system,
                     text_field_input_system,
+                    text_field_ime_system,
+                    text_field_ime_window_system,
+                    text_field_pointer_system,
+                    text_field_number_bounds_system,
+                    text_field_validation_system,
                     text_field_caret_blink_system,
                     text_field_label_system,
                     text_field_placeholder_system,
                     text_field_display_system,
+                    text_field_caret_system,
+                    text_field_scroll_to_caret_system,
                     text_field_supporting_text_system,
                     text_field_icon_system,
                     text_field_style_system,
@@ -169,6 +191,130 @@ pub enum InputType {
     Multiline,
 }
 
+/// Caret movements for [`MaterialTextField::move_caret`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaretMotion {
+    /// One character left
+    Left,
+    /// One character right
+    Right,
+    /// To the start of the previous word
+    WordLeft,
+    /// To the end of the next word
+    WordRight,
+    /// To the start of the value
+    Home,
+    /// To the end of the value
+    End,
+    /// To the same column on the previous line (multiline fields)
+    Up,
+    /// To the same column on the next line (multiline fields)
+    Down,
+}
+
+/// Default number of undo steps kept per text field
+pub const TEXT_FIELD_HISTORY_DEPTH: usize = 100;
+
+/// Kinds of edits that merge with an identical edit right before them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Typing,
+    Deleting,
+}
+
+/// Text field state captured by the undo history
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TextFieldSnapshot {
+    value: String,
+    caret: usize,
+    selection_anchor: Option<usize>,
+}
+
+/// Undo / redo history of a [`MaterialTextField`].
+///
+/// Edits made through the field's editing methods are recorded automatically, and
+/// direct assignments to `value` (e.g. from a [`TextFieldChangeEvent`] handler) are
+/// picked up as separate steps. Consecutive typed characters and single-character
+/// deletions are merged into one step.
+#[derive(Debug, Clone)]
+pub struct TextFieldHistory {
+    max_depth: usize,
+    undo: Vec<TextFieldSnapshot>,
+    redo: Vec<TextFieldSnapshot>,
+    /// The value after the last recorded change
+    recorded_value: String,
+    /// The edit kind that the next edit may merge with
+    coalescing: Option<EditKind>,
+}
+
+impl Default for TextFieldHistory {
+    fn default() -> Self {
+        Self {
+            max_depth: TEXT_FIELD_HISTORY_DEPTH,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            recorded_value: String::new(),
+            coalescing: None,
+        }
+    }
+}
+
+impl TextFieldHistory {
+    /// Maximum number of undo steps kept
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Change the maximum number of undo steps, dropping the oldest ones if needed
+    pub fn set_max_depth(&mut self, depth: usize) {
+        self.max_depth = depth;
+        let excess = self.undo.len().saturating_sub(depth);
+        self.undo.drain(..excess);
+        self.redo.truncate(depth);
+    }
+
+    /// Number of steps that can be undone
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Number of steps that can be redone
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Whether there is anything to undo
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Whether there is anything to redo
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Push a new undo step; a new edit invalidates the redo stack.
+    fn push_undo(&mut self, snapshot: TextFieldSnapshot) {
+        self.redo.clear();
+        if self.max_depth == 0 {
+            return;
+        }
+        if self.undo.len() >= self.max_depth {
+            self.undo.remove(0);
+        }
+        self.undo.push(snapshot);
+    }
+}
+
+/// Text being composed with an input method (IME) before it is committed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextFieldPreedit {
+    /// Composition text
+    pub text: String,
+    /// Cursor within the composition, as a byte range into `text` (`None` hides the caret)
+    pub cursor: Option<(usize, usize)>,
+}
+
 /// Material text field component
 ///
 /// Matches properties from Material Android TextInputLayout:
@@ -220,6 +366,13 @@ pub struct MaterialTextField {
     pub auto_focus: bool,
     /// Whether the field has content
     pub has_content: bool,
+    /// Caret position, as a character index into `value`
+    pub caret: usize,
+    /// Character index where the current selection started.
+    ///
+    /// The selection spans from here to `caret`; `None` (or equal to `caret`)
+    /// means nothing is selected.
+    pub selection_anchor: Option<usize>,
     /// Whether hint animation is enabled
     pub hint_animation_enabled: bool,
     /// Password visibility (for password toggle mode)
@@ -232,6 +385,22 @@ pub struct MaterialTextField {
     pub box_corner_radius: Option<f32>,
     /// Input type (affects keyboard and visibility)
     pub input_type: InputType,
+    /// Undo / redo history of `value`
+    pub history: TextFieldHistory,
+    /// IME composition in progress (shown underlined at the caret, not yet in `value`)
+    pub preedit: Option<TextFieldPreedit>,
+    /// Validation rules, checked in order (see [`crate::validation`])
+    pub validators: Vec<Arc<dyn Validator>>,
+    /// When the validators run
+    pub validation_trigger: ValidationTrigger,
+    /// Message of the failing rule from the last validation (`None` when valid)
+    pub validation_error: Option<String>,
+    /// Input mask or number format; `value` holds the raw (unformatted) text
+    pub format: Option<InputFormat>,
+    /// Lines a multiline field shows when (nearly) empty
+    pub min_lines: usize,
+    /// Lines a multiline field grows to before it scrolls (`None` = no limit)
+    pub max_lines: Option<usize>,
 }
 
 impl MaterialTextField {
@@ -256,12 +425,22 @@ impl MaterialTextField {
             focused: false,
             auto_focus: false,
             has_content: false,
+            caret: 0,
+            selection_anchor: None,
             hint_animation_enabled: true,
             password_visible: false,
             box_stroke_width: 1.0,
             box_stroke_width_focused: 2.0,
             box_corner_radius: None,
             input_type: InputType::default(),
+            history: TextFieldHistory::default(),
+            preedit: None,
+            validators: Vec::new(),
+            validation_trigger: ValidationTrigger::default(),
+            validation_error: None,
+            format: None,
+            min_lines: 1,
+            max_lines: None,
         }
     }
 
@@ -273,8 +452,8 @@ impl MaterialTextField {
 
     /// Set initial value
     pub fn with_value(mut self, value: impl Into<String>) -> Self {
-        self.value = value.into();
-        self.has_content = !self.value.is_empty();
+        self.set_value(value);
+        self.clear_history();
         self
     }
 
@@ -393,6 +572,520 @@ impl MaterialTextField {
         self
     }
 
+    /// Set how many undo steps are kept (0 disables undo)
+    pub fn history_depth(mut self, depth: usize) -> Self {
+        self.history.set_max_depth(depth);
+        self
+    }
+
+    /// Add a validation rule
+    pub fn validator(mut self, validator: impl Validator) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Set when validation runs
+    pub fn validate_on(mut self, trigger: ValidationTrigger) -> Self {
+        self.validation_trigger = trigger;
+        self
+    }
+
+    /// Set an input mask or number format (see [`crate::input_format`])
+    pub fn format(mut self, format: impl Into<InputFormat>) -> Self {
+        self.format = Some(format.into());
+        // Bring a value set earlier into raw form.
+        if !self.value.is_empty() {
+            let value = std::mem::take(&mut self.value);
+            self.set_value(value);
+            self.clear_history();
+        }
+        self
+    }
+
+    /// Set the number of lines a multiline field shows at least
+    pub fn min_lines(mut self, lines: usize) -> Self {
+        self.min_lines = lines.max(1);
+        self
+    }
+
+    /// Set the number of lines a multiline field grows to before it scrolls
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = Some(lines.max(1));
+        self
+    }
+
+    /// Make this a multiline field that grows from `min_lines` to `max_lines`
+    pub fn multiline(mut self, min_lines: usize, max_lines: usize) -> Self {
+        self.input_type = InputType::Multiline;
+        self.min_lines(min_lines).max_lines(max_lines)
+    }
+
+    /// Visible line range of a multiline field, `None` for single-line fields.
+    ///
+    /// The maximum is never below the minimum.
+    pub fn line_limits(&self) -> Option<(usize, Option<usize>)> {
+        if self.input_type != InputType::Multiline {
+            return None;
+        }
+        let min = self.min_lines.max(1);
+        Some((min, self.max_lines.map(|max| max.max(min))))
+    }
+
+    /// `value` with the input format applied (equal to `value` without a format)
+    pub fn formatted_value(&self) -> String {
+        self.formatted().text
+    }
+
+    /// Clamp a number-formatted value to its bounds. Returns `true` if it changed.
+    pub fn clamp_value(&mut self) -> bool {
+        let Some(InputFormat::Number(format)) = &self.format else {
+            return false;
+        };
+        let Some(clamped) = format.clamp(&self.value) else {
+            return false;
+        };
+        self.set_value(clamped);
+        true
+    }
+
+    /// Check the value against the validators without touching the error state.
+    ///
+    /// Email and URL input types are also checked for the right shape.
+    pub fn check_validity(&self) -> Result<(), String> {
+        validation::validate_all(&self.validators, &self.value)?;
+        match self.input_type {
+            InputType::Email => validation::Email::new().validate(&self.value),
+            InputType::Url => validation::Url::new().validate(&self.value),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate the value and show the result.
+    ///
+    /// A failure sets `error` and `error_text` to the rule's message; passing clears
+    /// an error that validation set earlier (errors set by the app are kept).
+    /// Returns `true` if the value is valid.
+    pub fn validate(&mut self) -> bool {
+        match self.check_validity() {
+            Ok(()) => {
+                if self.validation_error.take().is_some() {
+                    self.error = false;
+                    self.error_text = None;
+                }
+                true
+            }
+            Err(message) => {
+                self.error = true;
+                self.error_text = Some(message.clone());
+                self.validation_error = Some(message);
+                false
+            }
+        }
+    }
+
+    /// Replace the value, placing the caret at the end and clearing the selection.
+    ///
+    /// With an input format, characters the format doesn't accept are dropped (see
+    /// [`InputFormat::parse_raw`]). The change is recorded in the undo history.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        let value = value.into();
+        let value = match &self.format {
+            Some(format) => format.parse_raw(&value),
+            None => value,
+        };
+        if value != self.value {
+            self.begin_edit(None);
+        }
+        self.value = value;
+        self.has_content = !self.value.is_empty();
+        self.caret = self.value.chars().count();
+        self.selection_anchor = None;
+        self.history.recorded_value.clone_from(&self.value);
+    }
+
+    /// Caret position clamped to the current value
+    pub fn caret_position(&self) -> usize {
+        self.caret.min(self.character_count())
+    }
+
+    /// The selected character range, if any text is selected
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let caret = self.caret_position();
+        let anchor = self.selection_anchor?.min(self.character_count());
+        (anchor != caret).then(|| anchor.min(caret)..anchor.max(caret))
+    }
+
+    /// The selected text (empty when nothing is selected)
+    pub fn selected_text(&self) -> &str {
+        match self.selection() {
+            Some(range) => {
+                &self.value
+                    [byte_offset(&self.value, range.start)..byte_offset(&self.value, range.end)]
+            }
+            None => "",
+        }
+    }
+
+    /// Move the caret to a character index.
+    ///
+    /// With `extend_selection` the selection grows from its anchor (or the old caret
+    /// position); otherwise any selection is cleared.
+    pub fn set_caret(&mut self, position: usize, extend_selection: bool) {
+        if extend_selection {
+            if self.selection_anchor.is_none() {
+                self.selection_anchor = Some(self.caret_position());
+            }
+        } else {
+            self.selection_anchor = None;
+        }
+        self.caret = position.min(self.character_count());
+        self.history.coalescing = None;
+    }
+
+    /// Select a character range (the caret ends up at `range.end`)
+    pub fn select(&mut self, range: Range<usize>) {
+        let len = self.character_count();
+        self.selection_anchor = Some(range.start.min(len));
+        self.caret = range.end.min(len);
+        self.history.coalescing = None;
+    }
+
+    /// Select the whole value
+    pub fn select_all(&mut self) {
+        self.select(0..self.character_count());
+    }
+
+    /// Whether an IME composition is in progress
+    pub fn is_composing(&self) -> bool {
+        self.preedit.is_some()
+    }
+
+    /// Update the IME composition; an empty `text` cancels it.
+    ///
+    /// `cursor` is a byte range into `text`, as reported by [`Ime::Preedit`].
+    pub fn set_preedit(&mut self, text: impl Into<String>, cursor: Option<(usize, usize)>) {
+        let text = text.into();
+        self.preedit = (!text.is_empty()).then_some(TextFieldPreedit { text, cursor });
+    }
+
+    /// End the IME composition and insert the committed text at the caret.
+    ///
+    /// The text goes through the same filtering as typed input. Returns `true` if
+    /// the value changed.
+    pub fn commit_preedit(&mut self, text: &str) -> bool {
+        self.preedit = None;
+        self.insert_text(text)
+    }
+
+    /// The formatted value with the display position of each raw character
+    fn formatted(&self) -> FormattedText {
+        match &self.format {
+            Some(format) => format.layout(&self.value),
+            None => FormattedText {
+                text: self.value.clone(),
+                positions: (0..self.character_count()).collect(),
+            },
+        }
+    }
+
+    /// The text shown in the input line: the formatted value with any composition in
+    /// place of the selection.
+    fn display_value(&self) -> String {
+        let formatted = self.formatted();
+        let Some(preedit) = &self.preedit else {
+            return formatted.text;
+        };
+        let range = self.composition_range();
+        let start = byte_offset(&formatted.text, formatted.raw_to_display(range.start));
+        let end = byte_offset(&formatted.text, formatted.raw_to_display(range.end));
+        format!(
+            "{}{}{}",
+            &formatted.text[..start],
+            preedit.text,
+            &formatted.text[end..]
+        )
+    }
+
+    /// The selection within [`Self::display_value`]
+    fn display_selection(&self) -> Option<Range<usize>> {
+        let range = self.selection()?;
+        let formatted = self.formatted();
+        Some(formatted.raw_to_display(range.start)..formatted.raw_to_display(range.end))
+    }
+
+    /// Caret position in `value` for a character index of [`Self::display_value`]
+    fn display_to_raw(&self, index: usize) -> usize {
+        self.formatted().display_to_raw(index)
+    }
+
+    /// The characters of `value` the composition will replace when committed
+    fn composition_range(&self) -> Range<usize> {
+        self.selection().unwrap_or_else(|| {
+            let caret = self.caret_position();
+            caret..caret
+        })
+    }
+
+    /// Character range of the composition within [`Self::display_value`]
+    fn preedit_display_range(&self) -> Option<Range<usize>> {
+        let preedit = self.preedit.as_ref()?;
+        let start = self
+            .formatted()
+            .raw_to_display(self.composition_range().start);
+        Some(start..start + preedit.text.chars().count())
+    }
+
+    /// Caret position within [`Self::display_value`] (`None` when the IME hides it)
+    fn display_caret(&self) -> Option<usize> {
+        let formatted = self.formatted();
+        let Some(preedit) = &self.preedit else {
+            return Some(formatted.raw_to_display(self.caret_position()));
+        };
+        let (_, end) = preedit.cursor?;
+        let offset = preedit
+            .text
+            .get(..end)
+            .unwrap_or(&preedit.text)
+            .chars()
+            .count();
+        Some(formatted.raw_to_display(self.composition_range().start) + offset)
+    }
+
+    /// Move the caret, optionally extending the selection.
+    ///
+    /// [`CaretMotion::Up`] / [`CaretMotion::Down`] move between lines separated by
+    /// `'\n'`; with the keyboard they follow the wrapped lines as shown instead.
+    pub fn move_caret(&mut self, motion: CaretMotion, extend_selection: bool) {
+        let chars: Vec<char> = self.value.chars().collect();
+        let caret = self.caret_position();
+        // Don't reveal word boundaries of hidden passwords.
+        let motion = match motion {
+            CaretMotion::WordLeft if self.should_obscure_input() => CaretMotion::Home,
+            CaretMotion::WordRight if self.should_obscure_input() => CaretMotion::End,
+            motion => motion,
+        };
+
+        let target = match (motion, self.selection()) {
+            // Without Shift, Left / Right collapse an existing selection.
+            (CaretMotion::Left, Some(range)) if !extend_selection => range.start,
+            (CaretMotion::Right, Some(range)) if !extend_selection => range.end,
+            (CaretMotion::Left, _) => caret.saturating_sub(1),
+            (CaretMotion::Right, _) => (caret + 1).min(chars.len()),
+            (CaretMotion::WordLeft, _) => previous_word_start(&chars, caret),
+            (CaretMotion::WordRight, _) => next_word_end(&chars, caret),
+            (CaretMotion::Home, _) => 0,
+            (CaretMotion::End, _) => chars.len(),
+            (CaretMotion::Up, _) => line_above(&chars, caret).unwrap_or(0),
+            (CaretMotion::Down, _) => line_below(&chars, caret).unwrap_or(chars.len()),
+        };
+
+        self.set_caret(target, extend_selection);
+    }
+
+    /// Insert text at the caret, replacing the selection.
+    ///
+    /// Characters are filtered by the input format (or input type) and `max_length`;
+    /// newlines become spaces in single-line fields. Returns `true` if the value
+    /// changed.
+    pub fn insert_text(&mut self, text: &str) -> bool {
+        let mut chars: Vec<char> = self.value.chars().collect();
+        let mut caret = self.caret_position();
+        let selection = self.selection();
+        if let Some(range) = selection.clone() {
+            chars.drain(range.clone());
+            caret = range.start;
+        }
+
+        // Consecutive typed characters undo as one step; replacing a selection
+        // starts a new one.
+        if selection.is_some() {
+            self.history.coalescing = None;
+        }
+        let edit = (text.chars().count() == 1 && text != "\n").then_some(EditKind::Typing);
+
+        let mut inserted = false;
+        for ch in text.replace("\r\n", "\n").chars() {
+            let ch = match ch {
+                '\r' | '\n' if self.input_type == InputType::Multiline => '\n',
+                '\r' | '\n' => ' ',
+                ch => ch,
+            };
+
+            if ch.is_control() && ch != '\n' {
+                continue;
+            }
+
+            let ch = match &self.format {
+                Some(format) => match format.accept(&chars, caret, ch) {
+                    Some(ch) => ch,
+                    None => continue,
+                },
+                None if is_allowed_input_char(self.input_type, &chars, caret, ch) => ch,
+                None => continue,
+            };
+
+            if self.max_length.is_some_and(|max| chars.len() >= max) {
+                break;
+            }
+
+            chars.insert(caret, ch);
+            caret += 1;
+            inserted = true;
+        }
+
+        // Typing nothing valid over a selection leaves it alone.
+        if !inserted {
+            return false;
+        }
+
+        self.replace_chars(chars, caret, edit);
+        true
+    }
+
+    /// Delete the selected text. Returns `true` if anything was deleted.
+    pub fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.selection() else {
+            return false;
+        };
+
+        let mut chars: Vec<char> = self.value.chars().collect();
+        chars.drain(range.clone());
+        self.replace_chars(chars, range.start, None);
+        true
+    }
+
+    /// Backspace: delete the selection, or the character (or word) before the caret
+    pub fn delete_backward(&mut self, word: bool) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let mut chars: Vec<char> = self.value.chars().collect();
+        let caret = self.caret_position();
+        if caret == 0 {
+            return false;
+        }
+
+        let start = if word && !self.should_obscure_input() {
+            previous_word_start(&chars, caret)
+        } else if word {
+            0
+        } else {
+            caret - 1
+        };
+        chars.drain(start..caret);
+        self.replace_chars(chars, start, (!word).then_some(EditKind::Deleting));
+        true
+    }
+
+    /// Delete: delete the selection, or the character (or word) after the caret
+    pub fn delete_forward(&mut self, word: bool) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let mut chars: Vec<char> = self.value.chars().collect();
+        let caret = self.caret_position();
+        if caret >= chars.len() {
+            return false;
+        }
+
+        let end = if word && !self.should_obscure_input() {
+            next_word_end(&chars, caret)
+        } else if word {
+            chars.len()
+        } else {
+            caret + 1
+        };
+        chars.drain(caret..end);
+        self.replace_chars(chars, caret, (!word).then_some(EditKind::Deleting));
+        true
+    }
+
+    fn replace_chars(&mut self, chars: Vec<char>, caret: usize, edit: Option<EditKind>) {
+        self.begin_edit(edit);
+        self.value = chars.into_iter().collect();
+        self.has_content = !self.value.is_empty();
+        self.caret = caret;
+        self.selection_anchor = None;
+        self.history.recorded_value.clone_from(&self.value);
+    }
+
+    /// Undo the last change to the value. Returns `true` if anything was undone.
+    pub fn undo(&mut self) -> bool {
+        self.record_external_change();
+        let Some(snapshot) = self.history.undo.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.history.redo.push(current);
+        self.restore(snapshot);
+        true
+    }
+
+    /// Redo the last undone change. Returns `true` if anything was redone.
+    pub fn redo(&mut self) -> bool {
+        self.record_external_change();
+        let Some(snapshot) = self.history.redo.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.history.undo.push(current);
+        self.restore(snapshot);
+        true
+    }
+
+    /// Forget all undo / redo steps (e.g. after loading a saved value)
+    pub fn clear_history(&mut self) {
+        self.history.undo.clear();
+        self.history.redo.clear();
+        self.history.coalescing = None;
+        self.history.recorded_value.clone_from(&self.value);
+    }
+
+    /// Record the state before an edit, unless it continues the previous edit.
+    fn begin_edit(&mut self, edit: Option<EditKind>) {
+        self.record_external_change();
+        if edit.is_none() || edit != self.history.coalescing {
+            let snapshot = self.snapshot();
+            self.history.push_undo(snapshot);
+        }
+        self.history.coalescing = edit;
+    }
+
+    /// Record a change made by assigning `value` directly as its own undo step.
+    fn record_external_change(&mut self) {
+        if self.value == self.history.recorded_value {
+            return;
+        }
+
+        let previous = std::mem::replace(&mut self.history.recorded_value, self.value.clone());
+        let caret = previous.chars().count();
+        self.history.push_undo(TextFieldSnapshot {
+            value: previous,
+            caret,
+            selection_anchor: None,
+        });
+        self.history.coalescing = None;
+    }
+
+    fn snapshot(&self) -> TextFieldSnapshot {
+        TextFieldSnapshot {
+            value: self.value.clone(),
+            caret: self.caret_position(),
+            selection_anchor: self.selection_anchor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: TextFieldSnapshot) {
+        self.value = snapshot.value;
+        self.has_content = !self.value.is_empty();
+        self.caret = snapshot.caret;
+        self.selection_anchor = snapshot.selection_anchor;
+        self.history.coalescing = None;
+        self.history.recorded_value.clone_from(&self.value);
+    }
+
     /// Get current character count for counter display
     pub fn character_count(&self) -> usize {
         self.value.chars().count()
@@ -513,6 +1206,20 @@ impl MaterialTextField {
         }
     }
 
+    /// Get the caret color
+    pub fn caret_color(&self, theme: &MaterialTheme) -> Color {
+        if self.error {
+            theme.error
+        } else {
+            theme.primary
+        }
+    }
+
+    /// Get the selection highlight color
+    pub fn selection_color(&self, theme: &MaterialTheme) -> Color {
+        theme.primary.with_alpha(0.4)
+    }
+
     /// Get the placeholder text color
     pub fn placeholder_color(&self, theme: &MaterialTheme) -> Color {
         if self.disabled {
@@ -558,11 +1265,20 @@ impl Default for MaterialTextField {
     }
 }
 
+impl FocusableComponent for MaterialTextField {
+    fn is_focus_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
 /// Event when text field value changes
 #[derive(Event, bevy::prelude::Message)]
 pub struct TextFieldChangeEvent {
     pub entity: Entity,
+    /// Raw value (without mask literals or number grouping)
     pub value: String,
+    /// Value as displayed, with the field's input format applied
+    pub formatted: String,
 }
 
 /// Event when text field is submitted (Enter pressed)
@@ -572,15 +1288,35 @@ pub struct TextFieldSubmitEvent {
     pub value: String,
 }
 
+/// Event sent after a text field's value is validated
+#[derive(Event, bevy::prelude::Message)]
+pub struct TextFieldValidationEvent {
+    pub entity: Entity,
+    pub value: String,
+    /// Message of the failing rule, `None` if the value is valid
+    pub error: Option<String>,
+}
+
+impl TextFieldValidationEvent {
+    /// Whether the value passed validation
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
 /// Text field dimensions
 pub const TEXT_FIELD_HEIGHT: f32 = 56.0;
 pub const TEXT_FIELD_MIN_WIDTH: f32 = 210.0;
+/// Height of one line of multiline input text
+pub const TEXT_FIELD_LINE_HEIGHT: f32 = 24.0;
 
 /// System to handle text field focus
 fn text_field_focus_system(
     mouse: Res<ButtonInput<MouseButton>>,
     mut keyboard_inputs: MessageReader<bevy::input::keyboard::KeyboardInput>,
     mut active: ResMut<ActiveTextField>,
+    focus: Option<Res<FocusManager>>,
+    parents: Query<&ChildOf>,
     mut fields: ParamSet<(
         Query<(Entity, &Interaction), (Changed<Interaction>, With<MaterialTextField>)>,
         Query<(Entity, &mut MaterialTextField), With<MaterialTextField>>,
@@ -639,6 +1375,13 @@ fn text_field_focus_system(
                 if !field.auto_focus {
                     continue;
                 }
+                // Typing must not reach fields underneath an open modal.
+                if focus
+                    .as_ref()
+                    .is_some_and(|focus| !focus.allows_focus(entity, &parents))
+                {
+                    continue;
+                }
                 active.0 = Some(entity);
                 break;
             }
@@ -655,13 +1398,73 @@ fn text_field_focus_system(
     }
 }
 
+/// Route keyboard input to text fields reached through the focus manager
+/// (e.g. Tab navigation), and stop routing it when they lose focus or end up
+/// outside an engaged focus trap.
+fn text_field_focus_manager_sync_system(
+    mut gained: MessageReader<FocusGained>,
+    mut lost: MessageReader<FocusLost>,
+    mut active: ResMut<ActiveTextField>,
+    focus: Option<Res<FocusManager>>,
+    parents: Query<&ChildOf>,
+    mut fields: Query<(Entity, &mut MaterialTextField)>,
+) {
+    let mut changed = false;
+
+    if let (Some(entity), Some(focus)) = (active.0, focus.as_ref()) {
+        if !focus.allows_focus(entity, &parents) {
+            active.0 = None;
+            changed = true;
+        }
+    }
+
+    for ev in lost.read() {
+        if active.0 == Some(ev.entity) {
+            active.0 = None;
+            changed = true;
+        }
+    }
+
+    for ev in gained.read() {
+        if fields.contains(ev.entity) && active.0 != Some(ev.entity) {
+            active.0 = Some(ev.entity);
+            changed = true;
+        }
+    }
+
+    if changed {
+        let active_entity = active.0;
+        for (entity, mut field) in fields.iter_mut() {
+            let focused = active_entity == Some(entity);
+            if field.focused != focused {
+                field.focused = focused;
+            }
+        }
+    }
+}
+
 /// Handle keyboard input for the currently focused text field.
+///
+/// Keys are processed in event order so typing, caret movement and key repeats
+/// interleave correctly within a frame.
 fn text_field_input_system(
     active: Res<ActiveTextField>,
     mut keyboard_inputs: MessageReader<bevy::input::keyboard::KeyboardInput>,
     keys: Res<ButtonInput<KeyCode>>,
     mut clipboard: ResMut<TextFieldClipboard>,
+    mut blink: ResMut<TextFieldCaretBlink>,
     mut fields: Query<(Entity, &mut MaterialTextField)>,
+    inputs: Query<
+        (
+            &TextFieldInputFor,
+            &Text,
+            &TextFont,
+            &TextLayoutInfo,
+            &ComputedNode,
+        ),
+        With<TextFieldInput>,
+    >,
+    mut goal_x: Local<Option<(Entity, usize, f32)>>,
     mut change_events: MessageWriter<TextFieldChangeEvent>,
     mut submit_events: MessageWriter<TextFieldSubmitEvent>,
 ) {
@@ -675,201 +1478,1030 @@ fn text_field_input_system(
         return;
     };
 
-    if field.disabled {
+    // Keys belong to the input method while it is composing.
+    if field.disabled || field.is_composing() {
         keyboard_inputs.clear();
         return;
     }
 
+    // Clipboard / select-all shortcuts (desktop): Ctrl/Cmd + A/C/X/V.
+    let shortcut_down = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    // Word-wise movement: Ctrl elsewhere, Alt (Option) on macOS.
+    let word_down = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::AltLeft,
+        KeyCode::AltRight,
+    ]);
+    let shift_down = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let caret_before = (field.caret_position(), field.selection());
     let mut changed = false;
 
-    // Clipboard shortcuts (desktop): Ctrl/Cmd + C/X/V.
-    // Current text field input is append-only (caret at end), so paste appends.
-    let modifier_down = keys.pressed(KeyCode::ControlLeft)
-        || keys.pressed(KeyCode::ControlRight)
-        || keys.pressed(KeyCode::SuperLeft)
-        || keys.pressed(KeyCode::SuperRight);
-
-    if modifier_down {
-        // Copy
-        if keys.just_pressed(KeyCode::KeyC) {
-            clipboard.set_text(field.value.clone());
-        }
-
-        // Cut
-        if keys.just_pressed(KeyCode::KeyX) {
-            clipboard.set_text(field.value.clone());
-            if !field.value.is_empty() {
-                field.value.clear();
-                changed = true;
-            }
+    for ev in keyboard_inputs.read() {
+        if ev.state != bevy::input::ButtonState::Pressed {
+            continue;
         }
 
-        // Paste
-        if keys.just_pressed(KeyCode::KeyV) {
-            if let Some(text) = clipboard.get_text() {
-                for mut ch in text.chars() {
-                    // Normalize newlines for single-line inputs.
-                    if ch == '\n' || ch == '\r' {
-                        if field.input_type == InputType::Multiline {
-                            // keep
-                        } else {
-                            ch = ' ';
-                        }
-                    }
+        match ev.key_code {
+            KeyCode::ArrowLeft => {
+                let motion = if word_down {
+                    CaretMotion::WordLeft
+                } else {
+                    CaretMotion::Left
+                };
+                field.move_caret(motion, shift_down);
+            }
+            KeyCode::ArrowRight => {
+                let motion = if word_down {
+                    CaretMotion::WordRight
+                } else {
+                    CaretMotion::Right
+                };
+                field.move_caret(motion, shift_down);
+            }
+            KeyCode::ArrowUp | KeyCode::ArrowDown if field.input_type == InputType::Multiline => {
+                let (motion, offset) = if ev.key_code == KeyCode::ArrowUp {
+                    (CaretMotion::Up, -1)
+                } else {
+                    (CaretMotion::Down, 1)
+                };
+                // Wrapped lines are only known from a layout that still shows the value.
+                let display = field.display_value();
+                let layout = inputs
+                    .iter()
+                    .find(|(owner, text, ..)| owner.0 == entity && text.0 == display);
+                let Some((_, text, font, layout, node)) = layout else {
+                    field.move_caret(motion, shift_down);
+                    continue;
+                };
 
-                    if ch.is_control() {
-                        continue;
+                let line_height = text_line_height(font) / node.inverse_scale_factor();
+                let stops = caret_stops(&text.0, layout, line_height);
+                let caret = field.display_caret().unwrap_or(0);
+                // Keep the column of the first vertical move through short lines.
+                let x = match *goal_x {
+                    Some((goal_entity, goal_caret, x))
+                        if goal_entity == entity && goal_caret == field.caret_position() =>
+                    {
+                        x
                     }
-
-                    if !is_allowed_input_char(&field, ch) {
-                        continue;
+                    _ => caret_stop_at(&stops, caret).x,
+                };
+                let target = match caret_stop_on_adjacent_line(&stops, caret, x, offset) {
+                    Some(index) => field.display_to_raw(index),
+                    None if offset < 0 => 0,
+                    None => field.character_count(),
+                };
+                field.set_caret(target, shift_down);
+                *goal_x = Some((entity, field.caret_position(), x));
+            }
+            KeyCode::Home => field.move_caret(CaretMotion::Home, shift_down),
+            KeyCode::End => field.move_caret(CaretMotion::End, shift_down),
+            KeyCode::Backspace => changed |= field.delete_backward(word_down),
+            KeyCode::Delete => changed |= field.delete_forward(word_down),
+            KeyCode::Enter | KeyCode::NumpadEnter => {
+                if field.input_type == InputType::Multiline {
+                    changed |= field.insert_text("\n");
+                } else if !ev.repeat {
+                    changed |= field.clamp_value();
+                    // Report pending edits before the submit.
+                    if changed {
+                        change_events.write(TextFieldChangeEvent {
+                            entity,
+                            value: field.value.clone(),
+                            formatted: field.formatted_value(),
+                        });
+                        changed = false;
                     }
+                    submit_events.write(TextFieldSubmitEvent {
+                        entity,
+                        value: field.value.clone(),
+                    });
+                }
+            }
+            KeyCode::KeyA if shortcut_down => field.select_all(),
+            KeyCode::KeyZ if shortcut_down => {
+                changed |= if shift_down {
+                    field.redo()
+                } else {
+                    field.undo()
+                };
+            }
+            KeyCode::KeyY if shortcut_down => changed |= field.redo(),
+            KeyCode::KeyC if shortcut_down => {
+                // Hidden passwords can't be copied out.
+                if !field.should_obscure_input() && field.selection().is_some() {
+                    clipboard.set_text(field.selected_text().to_string());
+                }
+            }
+            KeyCode::KeyX if shortcut_down => {
+                if !field.should_obscure_input() && field.selection().is_some() {
+                    clipboard.set_text(field.selected_text().to_string());
+                    changed |= field.delete_selection();
+                }
+            }
+            KeyCode::KeyV if shortcut_down => {
+                if let Some(text) = clipboard.get_text() {
+                    changed |= field.insert_text(&text);
+                }
+            }
+            // Other shortcuts never type their letter.
+            _ if shortcut_down => {}
+            _ => {
+                // Primary: `KeyboardInput.text`
+                // Fallback: if `text` is None, use `logical_key == Key::Character(_)`.
+                let text: Option<&str> = ev.text.as_deref().or_else(|| match &ev.logical_key {
+                    bevy::input::keyboard::Key::Character(s) => Some(s.as_str()),
+                    _ => None,
+                });
 
-                    if let Some(max) = field.max_length {
-                        if field.value.chars().count() >= max {
-                            break;
-                        }
-                    }
+                if let Some(text) = text {
+                    changed |= field.insert_text(text);
+                }
+            }
+        }
+    }
+
+    let has_content = !field.value.is_empty();
+    if field.has_content != has_content {
+        field.has_content = has_content;
+    }
+
+    // Keep the caret solid while the user is editing or moving it.
+    if changed || caret_before != (field.caret_position(), field.selection()) {
+        blink.timer.reset();
+        blink.visible = true;
+    }
+
+    if changed {
+        change_events.write(TextFieldChangeEvent {
+            entity,
+            value: field.value.clone(),
+            formatted: field.formatted_value(),
+        });
+    }
+}
+
+/// Apply IME composition and commit messages to the focused text field.
+fn text_field_ime_system(
+    active: Res<ActiveTextField>,
+    mut ime_events: MessageReader<Ime>,
+    mut blink: ResMut<TextFieldCaretBlink>,
+    mut fields: Query<(Entity, &mut MaterialTextField)>,
+    mut change_events: MessageWriter<TextFieldChangeEvent>,
+) {
+    // A composition doesn't survive losing focus.
+    for (entity, mut field) in fields.iter_mut() {
+        if field.is_composing() && (active.0 != Some(entity) || field.disabled) {
+            field.preedit = None;
+        }
+    }
+
+    let Some((entity, mut field)) = active
+        .0
+        .and_then(|entity| fields.get_mut(entity).ok())
+        .filter(|(_, field)| !field.disabled)
+    else {
+        ime_events.clear();
+        return;
+    };
+
+    let mut changed = false;
+    for ev in ime_events.read() {
+        match ev {
+            Ime::Preedit { value, cursor, .. } => {
+                field.set_preedit(value.clone(), *cursor);
+            }
+            Ime::Commit { value, .. } => changed |= field.commit_preedit(value),
+            Ime::Disabled { .. } => field.preedit = None,
+            Ime::Enabled { .. } => {}
+        }
+        blink.timer.reset();
+        blink.visible = true;
+    }
+
+    if changed {
+        change_events.write(TextFieldChangeEvent {
+            entity,
+            value: field.value.clone(),
+            formatted: field.formatted_value(),
+        });
+    }
+}
+
+/// Enable the IME while an editable text field is focused, and place the IME
+/// candidate window below it.
+///
+/// `ime_enabled` is only written when that changes, so the app can still drive
+/// the IME itself while no text field is involved.
+fn text_field_ime_window_system(
+    active: Res<ActiveTextField>,
+    fields: Query<(
+        &MaterialTextField,
+        Option<&UiGlobalTransform>,
+        Option<&ComputedNode>,
+    )>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut previous_enabled: Local<bool>,
+) {
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+
+    let focused = active.0.and_then(|entity| fields.get(entity).ok());
+    // Passwords are typed directly, as on native platforms.
+    let ime_enabled =
+        focused.is_some_and(|(field, ..)| !field.disabled && !field.should_obscure_input());
+    if *previous_enabled != ime_enabled {
+        *previous_enabled = ime_enabled;
+        if window.ime_enabled != ime_enabled {
+            window.ime_enabled = ime_enabled;
+        }
+    }
+    if !ime_enabled {
+        return;
+    }
+
+    // `ime_position` is in logical pixels; UI transforms are in physical pixels.
+    if let Some((_, Some(transform), Some(node))) = focused {
+        let scale = node.inverse_scale_factor();
+        let bottom_left = transform.translation + Vec2::new(-0.5, 0.5) * node.size();
+        let ime_position = bottom_left * scale;
+        if window.ime_position != ime_position {
+            window.ime_position = ime_position;
+        }
+    }
+}
+
+/// Clamp number-formatted values to their bounds when the field loses focus.
+fn text_field_number_bounds_system(
+    active: Res<ActiveTextField>,
+    mut previous_active: Local<Option<Entity>>,
+    mut fields: Query<&mut MaterialTextField>,
+    mut change_events: MessageWriter<TextFieldChangeEvent>,
+) {
+    let blurred = previous_active.filter(|&entity| active.0 != Some(entity));
+    *previous_active = active.0;
+    let Some(entity) = blurred else {
+        return;
+    };
+
+    if let Ok(mut field) = fields.get_mut(entity) {
+        if field.clamp_value() {
+            change_events.write(TextFieldChangeEvent {
+                entity,
+                value: field.value.clone(),
+                formatted: field.formatted_value(),
+            });
+        }
+    }
+}
+
+/// Run validators according to each field's [`ValidationTrigger`].
+///
+/// Submitting always validates. Once a field shows a validation error it is
+/// re-checked on every edit, so the error clears as soon as the value is fixed.
+fn text_field_validation_system(
+    active: Res<ActiveTextField>,
+    mut previous_active: Local<Option<Entity>>,
+    mut change_events: MessageReader<TextFieldChangeEvent>,
+    mut submit_events: MessageReader<TextFieldSubmitEvent>,
+    mut fields: Query<&mut MaterialTextField>,
+    mut validation_events: MessageWriter<TextFieldValidationEvent>,
+) {
+    let mut pending: Vec<Entity> = Vec::new();
+    let mut queue = |entity: Entity| {
+        if !pending.contains(&entity) {
+            pending.push(entity);
+        }
+    };
+
+    for ev in change_events.read() {
+        let Ok(field) = fields.get(ev.entity) else {
+            continue;
+        };
+        if field.validation_trigger == ValidationTrigger::OnChange
+            || field.validation_error.is_some()
+        {
+            queue(ev.entity);
+        }
+    }
+
+    let blurred = previous_active.filter(|&entity| active.0 != Some(entity));
+    *previous_active = active.0;
+    if let Some(entity) = blurred {
+        if fields
+            .get(entity)
+            .is_ok_and(|field| field.validation_trigger != ValidationTrigger::OnSubmit)
+        {
+            queue(entity);
+        }
+    }
+
+    for ev in submit_events.read() {
+        queue(ev.entity);
+    }
+
+    for entity in pending {
+        let Ok(mut field) = fields.get_mut(entity) else {
+            continue;
+        };
+        field.validate();
+        validation_events.write(TextFieldValidationEvent {
+            entity,
+            value: field.value.clone(),
+            error: field.validation_error.clone(),
+        });
+    }
+}
 
-                    field.value.push(ch);
-                    changed = true;
+/// Record direct assignments to `value` (made outside the editing methods) as undo steps.
+fn text_field_history_system(
+    mut fields: Query<&mut MaterialTextField, Changed<MaterialTextField>>,
+) {
+    for mut field in fields.iter_mut() {
+        if field.value != field.history.recorded_value {
+            field.bypass_change_detection().record_external_change();
+        }
+    }
+}
+
+/// Place the caret on click and extend the selection while dragging.
+fn text_field_pointer_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    active: Res<ActiveTextField>,
+    mut dragging: Local<Option<Entity>>,
+    mut fields: Query<(&Interaction, &mut MaterialTextField)>,
+    inputs: Query<
+        (
+            &TextFieldInputFor,
+            &Text,
+            &TextFont,
+            &TextLayoutInfo,
+            &ComputedNode,
+            &RelativeCursorPosition,
+        ),
+        With<TextFieldInput>,
+    >,
+) {
+    if mouse.just_released(MouseButton::Left) {
+        if let Some(entity) = dragging.take() {
+            // A click without a drag leaves no (empty) selection behind.
+            if let Ok((_, mut field)) = fields.get_mut(entity) {
+                if field.selection_anchor.is_some() && field.selection().is_none() {
+                    field.selection_anchor = None;
                 }
             }
         }
+        return;
+    }
+
+    let Some(active_entity) = active.0 else {
+        *dragging = None;
+        return;
+    };
+
+    let pressed_now = mouse.just_pressed(MouseButton::Left);
+    if !pressed_now && *dragging != Some(active_entity) {
+        return;
     }
 
-    // Backspace
-    if keys.just_pressed(KeyCode::Backspace) && !field.value.is_empty() {
-        field.value.pop();
-        changed = true;
+    let Ok((interaction, mut field)) = fields.get_mut(active_entity) else {
+        return;
+    };
+
+    if pressed_now && *interaction != Interaction::Pressed {
+        return;
     }
 
-    // Text entry
-    // Primary: `KeyboardInput.text`
-    // Fallback: if `text` is None, use `logical_key == Key::Character(_)`.
-    for ev in keyboard_inputs.read() {
-        if ev.state != bevy::input::ButtonState::Pressed {
+    let Some(index) = inputs
+        .iter()
+        .find(|(owner, ..)| owner.0 == active_entity)
+        .and_then(|(_, text, font, layout, node, cursor)| {
+            let cursor = cursor.normalized?;
+            let point = (cursor + 0.5) * node.size();
+            let line_height = text_line_height(font) / node.inverse_scale_factor();
+            let line = (point.y / line_height).floor().max(0.0) as usize;
+            nearest_caret_stop(&caret_stops(&text.0, layout, line_height), point.x, line)
+        })
+        .map(|index| field.display_to_raw(index))
+    else {
+        return;
+    };
+
+    if pressed_now {
+        *dragging = Some(active_entity);
+        let shift_down = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        if shift_down {
+            field.set_caret(index, true);
+        } else {
+            field.set_caret(index, false);
+            field.selection_anchor = Some(field.caret_position());
+        }
+    } else if field.caret_position() != index.min(field.character_count()) {
+        field.set_caret(index, true);
+    }
+}
+
+/// Number and phone fields only accept characters that fit their format (used when
+/// the field has no [`InputFormat`]).
+///
+/// `chars` is the value being edited and `index` the insertion point.
+fn is_allowed_input_char(input_type: InputType, chars: &[char], index: usize, ch: char) -> bool {
+    match input_type {
+        InputType::Number => {
+            if ch.is_ascii_digit() {
+                return true;
+            }
+
+            let has_sign = chars.first().is_some_and(|c| matches!(c, '-' | '+'));
+            match ch {
+                // A single leading sign character.
+                '-' | '+' => index == 0 && !has_sign,
+                // A single decimal point, after the sign.
+                '.' => !chars.contains(&'.') && (index > 0 || !has_sign),
+                _ => false,
+            }
+        }
+        InputType::Phone => {
+            // Keep this permissive for typical phone number formats.
+            ch.is_ascii_digit() || matches!(ch, ' ' | '+' | '-' | '(' | ')')
+        }
+        // For other input types we currently accept any non-control characters.
+        _ => true,
+    }
+}
+
+/// Byte offset of the character at `char_index` (or the end of the string).
+fn byte_offset(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Start of the word before `index` (skipping separators first).
+fn previous_word_start(chars: &[char], index: usize) -> usize {
+    let mut i = index.min(chars.len());
+    while i > 0 && !is_word_char(chars[i - 1]) {
+        i -= 1;
+    }
+    while i > 0 && is_word_char(chars[i - 1]) {
+        i -= 1;
+    }
+    i
+}
+
+/// Start of the line (split at '\n') holding `index`.
+fn line_start(chars: &[char], index: usize) -> usize {
+    chars[..index.min(chars.len())]
+        .iter()
+        .rposition(|ch| *ch == '\n')
+        .map_or(0, |newline| newline + 1)
+}
+
+/// Same column as `index` on the previous line, `None` on the first line.
+fn line_above(chars: &[char], index: usize) -> Option<usize> {
+    let index = index.min(chars.len());
+    let start = line_start(chars, index);
+    if start == 0 {
+        return None;
+    }
+    let previous_start = line_start(chars, start - 1);
+    Some((previous_start + index - start).min(start - 1))
+}
+
+/// Same column as `index` on the next line, `None` on the last line.
+fn line_below(chars: &[char], index: usize) -> Option<usize> {
+    let index = index.min(chars.len());
+    let start = line_start(chars, index);
+    let next_start = index + chars[index..].iter().position(|ch| *ch == '\n')? + 1;
+    let next_end = chars[next_start..]
+        .iter()
+        .position(|ch| *ch == '\n')
+        .map_or(chars.len(), |offset| next_start + offset);
+    Some((next_start + index - start).min(next_end))
+}
+
+/// End of the word after `index` (skipping separators first).
+fn next_word_end(chars: &[char], index: usize) -> usize {
+    let mut i = index.min(chars.len());
+    while i < chars.len() && !is_word_char(chars[i]) {
+        i += 1;
+    }
+    while i < chars.len() && is_word_char(chars[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// A caret position in a laid-out text
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CaretStop {
+    /// Offset from the left edge
+    x: f32,
+    /// Visual (wrapped) line, counted from the top
+    line: usize,
+}
+
+/// Line height of a text font, in logical pixels
+fn text_line_height(font: &TextFont) -> f32 {
+    match font.line_height {
+        bevy::text::LineHeight::Px(px) => px,
+        bevy::text::LineHeight::RelativeToFont(scale) => scale * font.font_size,
+    }
+}
+
+/// Every caret position in a laid-out text, one per character boundary.
+///
+/// Offsets are in the layout's physical pixels, and `line_height` must be too. Each
+/// boundary sits halfway between the neighbouring glyphs' bounds, so it stays stable
+/// for glyphs without ink.
+fn caret_stops(text: &str, layout: &TextLayoutInfo, line_height: f32) -> Vec<CaretStop> {
+    // Glyph byte indices are relative to their paragraph (hard line).
+    let paragraph_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let glyphs: Vec<(usize, f32, f32, usize)> = layout
+        .glyphs
+        .iter()
+        .map(|glyph| {
+            let half = glyph.size.x / 2.0;
+            let paragraph_start = paragraph_starts
+                .get(glyph.line_index)
+                .copied()
+                .unwrap_or(text.len());
+            let line = if line_height > 0.0 {
+                (glyph.position.y / line_height).floor().max(0.0) as usize
+            } else {
+                0
+            };
+            (
+                paragraph_start + glyph.byte_index,
+                glyph.position.x - half,
+                glyph.position.x + half,
+                line,
+            )
+        })
+        .collect();
+    caret_stops_from_glyphs(text, &glyphs)
+}
+
+/// [`caret_stops`] over `(byte index, left, right, visual line)` glyph bounds.
+fn caret_stops_from_glyphs(text: &str, glyphs: &[(usize, f32, f32, usize)]) -> Vec<CaretStop> {
+    let chars: Vec<char> = text.chars().collect();
+    let char_count = chars.len();
+    let mut bounds: Vec<Option<(f32, f32, usize)>> = vec![None; char_count];
+    for (byte_index, left, right, line) in glyphs {
+        let char_index = text[..(*byte_index).min(text.len())].chars().count();
+        if let Some(slot) = bounds.get_mut(char_index) {
+            *slot = Some(match *slot {
+                Some((l, r, line)) => (l.min(*left), r.max(*right), line),
+                None => (*left, *right, *line),
+            });
+        }
+    }
+
+    let mut stops = Vec::with_capacity(char_count + 1);
+    stops.push(CaretStop { x: 0.0, line: 0 });
+    let mut line = 0;
+    let mut previous_right = 0.0_f32;
+    for i in 1..=char_count {
+        if chars[i - 1] == '\n' {
+            line += 1;
+            previous_right = 0.0;
+        } else if let Some((_, right, glyph_line)) = bounds[i - 1] {
+            if glyph_line > line {
+                line = glyph_line;
+                previous_right = 0.0;
+            }
+            previous_right = previous_right.max(right);
+        }
+
+        // The next glyph in the same paragraph.
+        let next = bounds[i..]
+            .iter()
+            .zip(&chars[i..])
+            .take_while(|(_, ch)| **ch != '\n')
+            .find_map(|(b, _)| *b);
+        let x = match next {
+            // Soft wrap: the boundary belongs to the start of the next line.
+            Some((_, _, next_line)) if next_line > line => {
+                line = next_line;
+                previous_right = 0.0;
+                0.0
+            }
+            Some((next_left, _, _)) => (previous_right + next_left.max(previous_right)) / 2.0,
+            None => previous_right,
+        };
+        let x = match stops.last() {
+            Some(last) if last.line == line => x.max(last.x),
+            _ => x,
+        };
+        stops.push(CaretStop { x, line });
+    }
+    stops
+}
+
+/// Index of the caret stop on `line` closest to `x` (the last line if `line` is past it).
+fn nearest_caret_stop(stops: &[CaretStop], x: f32, line: usize) -> Option<usize> {
+    let line = line.min(stops.iter().map(|stop| stop.line).max()?);
+    stops
+        .iter()
+        .enumerate()
+        .filter(|(_, stop)| stop.line == line)
+        .min_by(|(_, a), (_, b)| (a.x - x).abs().total_cmp(&(b.x - x).abs()))
+        .map(|(index, _)| index)
+}
+
+/// Caret stop closest to `x` on the visual line `offset` lines away from `index`'s.
+///
+/// Returns `None` when there is no such line.
+fn caret_stop_on_adjacent_line(
+    stops: &[CaretStop],
+    index: usize,
+    x: f32,
+    offset: isize,
+) -> Option<usize> {
+    let line = stops.get(index)?.line.checked_add_signed(offset)?;
+    stops
+        .iter()
+        .any(|stop| stop.line == line)
+        .then(|| nearest_caret_stop(stops, x, line))
+        .flatten()
+}
+
+/// Blink the caret for focused text fields.
+fn text_field_caret_blink_system(time: Res<Time>, mut blink: ResMut<TextFieldCaretBlink>) {
+    blink.timer.tick(time.delta());
+    if blink.timer.just_finished() {
+        blink.visible = !blink.visible;
+    }
+}
+
+/// Position the caret and selection highlight from the input text's glyph layout.
+///
+/// Selections spanning several lines of a multiline field get one highlight node per
+/// line; extra nodes are spawned next to the first one as needed.
+fn text_field_caret_system(
+    mut commands: Commands,
+    theme: Option<Res<MaterialTheme>>,
+    blink: Res<TextFieldCaretBlink>,
+    fields: Query<&MaterialTextField>,
+    inputs: Query<
+        (
+            &TextFieldInputFor,
+            &Text,
+            &TextFont,
+            &TextLayoutInfo,
+            &ComputedNode,
+        ),
+        With<TextFieldInput>,
+    >,
+    parents: Query<&ChildOf>,
+    mut carets: Query<
+        (&TextFieldCaretFor, &mut Node, &mut BackgroundColor),
+        (With<TextFieldCaret>, Without<TextFieldSelection>),
+    >,
+    mut selections: Query<
+        (
+            Entity,
+            &TextFieldSelectionFor,
+            &mut Node,
+            &mut BackgroundColor,
+        ),
+        (With<TextFieldSelection>, Without<TextFieldCaret>),
+    >,
+    mut underlines: Query<
+        (
+            &TextFieldPreeditUnderlineFor,
+            &mut Node,
+            &mut BackgroundColor,
+        ),
+        (
+            With<TextFieldPreeditUnderline>,
+            Without<TextFieldCaret>,
+            Without<TextFieldSelection>,
+        ),
+    >,
+) {
+    let Some(theme) = theme else { return };
+
+    // Caret stops and line height of a field's input text, in logical pixels.
+    let stops_for = |field_entity: Entity| -> (Vec<CaretStop>, f32) {
+        inputs
+            .iter()
+            .find(|(owner, ..)| owner.0 == field_entity)
+            .map(|(_, text, font, layout, node)| {
+                let scale = node.inverse_scale_factor();
+                let line_height = text_line_height(font);
+                let stops = caret_stops(&text.0, layout, line_height / scale)
+                    .into_iter()
+                    .map(|stop| CaretStop {
+                        x: stop.x * scale,
+                        ..stop
+                    })
+                    .collect();
+                (stops, line_height)
+            })
+            .unwrap_or_else(|| (vec![CaretStop { x: 0.0, line: 0 }], 0.0))
+    };
+
+    for (owner, mut node, mut color) in carets.iter_mut() {
+        let Ok(field) = fields.get(owner.0) else {
             continue;
+        };
+
+        // While composing, the caret sits inside the composition text.
+        let caret = field
+            .display_caret()
+            .filter(|_| field.focused && !field.disabled)
+            .filter(|_| field.is_composing() || field.selection().is_none());
+        let display = if caret.is_some() && blink.visible {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
         }
+        let Some(caret) = caret else {
+            continue;
+        };
 
-        let text: Option<&str> = ev.text.as_deref().or_else(|| match &ev.logical_key {
-            bevy::input::keyboard::Key::Character(s) => Some(s.as_str()),
-            _ => None,
-        });
+        let (stops, line_height) = stops_for(owner.0);
+        let stop = caret_stop_at(&stops, caret);
+        let left = Val::Px(stop.x - 1.0);
+        if node.left != left {
+            node.left = left;
+        }
+        if field.line_limits().is_some() {
+            set_line_box(&mut node, stop.line, line_height);
+        }
+        let caret_color = field.caret_color(&theme);
+        if color.0 != caret_color {
+            color.0 = caret_color;
+        }
+    }
 
-        let Some(text) = text else {
+    // Highlight nodes per field, in a stable order.
+    let mut segments: Vec<(Entity, Entity)> = selections
+        .iter()
+        .map(|(entity, owner, ..)| (owner.0, entity))
+        .collect();
+    segments.sort();
+    for group in segments.chunk_by(|a, b| a.0 == b.0) {
+        let field_entity = group[0].0;
+        let Ok(field) = fields.get(field_entity) else {
             continue;
         };
 
-        for ch in text.chars() {
-            if ch.is_control() {
-                continue;
-            }
+        let range = field
+            .display_selection()
+            .filter(|_| field.focused && !field.is_composing());
+        let (stops, line_height) = stops_for(field_entity);
+        let rects = range.map_or_else(Vec::new, |range| selection_rects(&stops, range));
+        let multiline = field.line_limits().is_some();
+        let selection_color = field.selection_color(&theme);
 
-            if !is_allowed_input_char(&field, ch) {
+        for (i, (_, segment)) in group.iter().enumerate() {
+            let Ok((_, _, mut node, mut color)) = selections.get_mut(*segment) else {
                 continue;
+            };
+            let display = if i < rects.len() {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            if node.display != display {
+                node.display = display;
             }
+            let Some(&(start, end, line)) = rects.get(i) else {
+                continue;
+            };
 
-            if let Some(max) = field.max_length {
-                if field.value.chars().count() >= max {
-                    break;
-                }
+            let (left, width) = (Val::Px(start), Val::Px((end - start).max(0.0)));
+            if node.left != left {
+                node.left = left;
+            }
+            if node.width != width {
+                node.width = width;
+            }
+            if multiline {
+                set_line_box(&mut node, line, line_height);
             }
+            if color.0 != selection_color {
+                color.0 = selection_color;
+            }
+        }
 
-            field.value.push(ch);
-            changed = true;
+        // More lines selected than there are highlight nodes: add some (shown next frame).
+        if rects.len() > group.len() {
+            let Ok(parent) = parents.get(group[0].1).map(ChildOf::parent) else {
+                continue;
+            };
+            let extra: Vec<Entity> = (group.len()..rects.len())
+                .map(|_| {
+                    commands
+                        .spawn((
+                            TextFieldSelection,
+                            TextFieldSelectionFor(field_entity),
+                            text_field_selection_node(),
+                            BackgroundColor(Color::NONE),
+                        ))
+                        .id()
+                })
+                .collect();
+            // Keep highlights behind the input text.
+            commands.entity(parent).insert_children(0, &extra);
         }
     }
 
-    field.has_content = !field.value.is_empty();
+    for (owner, mut node, mut color) in underlines.iter_mut() {
+        let Ok(field) = fields.get(owner.0) else {
+            continue;
+        };
 
-    if changed {
-        change_events.write(TextFieldChangeEvent {
-            entity,
-            value: field.value.clone(),
-        });
-    }
+        let range = field.preedit_display_range().filter(|_| field.focused);
+        let display = if range.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+        let Some(range) = range else {
+            continue;
+        };
 
-    // Submit / newline
-    if keys.just_pressed(KeyCode::Enter) {
-        if field.input_type == InputType::Multiline {
-            if field
-                .max_length
-                .is_none_or(|max| field.value.chars().count() < max)
-            {
-                field.value.push('\n');
-                field.has_content = !field.value.is_empty();
-                change_events.write(TextFieldChangeEvent {
-                    entity,
-                    value: field.value.clone(),
-                });
+        // Compositions are short; underline the part on their first line.
+        let (stops, line_height) = stops_for(owner.0);
+        let Some(&(start, end, line)) = selection_rects(&stops, range).first() else {
+            continue;
+        };
+        let (left, width) = (Val::Px(start), Val::Px((end - start).max(0.0)));
+        if node.left != left {
+            node.left = left;
+        }
+        if node.width != width {
+            node.width = width;
+        }
+        if field.line_limits().is_some() {
+            let top = Val::Px((line + 1) as f32 * line_height - 1.0);
+            if node.top != top {
+                node.top = top;
+                node.bottom = Val::Auto;
             }
-        } else {
-            submit_events.write(TextFieldSubmitEvent {
-                entity,
-                value: field.value.clone(),
-            });
+        }
+        let underline_color = field.input_color(&theme);
+        if color.0 != underline_color {
+            color.0 = underline_color;
         }
     }
 }
 
-fn is_allowed_input_char(field: &MaterialTextField, ch: char) -> bool {
-    match field.input_type {
-        InputType::Number => {
-            if ch.is_ascii_digit() {
-                return true;
-            }
+/// The caret stop at `index`, or the last one past the end
+fn caret_stop_at(stops: &[CaretStop], index: usize) -> CaretStop {
+    stops
+        .get(index)
+        .or(stops.last())
+        .copied()
+        .unwrap_or(CaretStop { x: 0.0, line: 0 })
+}
 
-            // Allow a leading sign character.
-            if (ch == '-' || ch == '+') && field.value.is_empty() {
-                return true;
-            }
+/// `(left, right, line)` of each line's part of a text range, top to bottom
+fn selection_rects(stops: &[CaretStop], range: Range<usize>) -> Vec<(f32, f32, usize)> {
+    let start = caret_stop_at(stops, range.start);
+    let end = caret_stop_at(stops, range.end);
+    (start.line..=end.line)
+        .map(|line| {
+            let left = if line == start.line { start.x } else { 0.0 };
+            let right = if line == end.line {
+                end.x
+            } else {
+                stops
+                    .iter()
+                    .filter(|stop| stop.line == line)
+                    .map(|stop| stop.x)
+                    .fold(left, f32::max)
+            };
+            (left, right, line)
+        })
+        .collect()
+}
 
-            false
-        }
-        InputType::Phone => {
-            // Keep this permissive for typical phone number formats.
-            ch.is_ascii_digit() || matches!(ch, ' ' | '+' | '-' | '(' | ')')
-        }
-        // For other input types we currently accept any non-control characters.
-        _ => true,
+/// Place an absolutely positioned node over one line of a multiline input.
+fn set_line_box(node: &mut Node, line: usize, line_height: f32) {
+    let (top, height) = (Val::Px(line as f32 * line_height), Val::Px(line_height));
+    if node.top != top || node.height != height {
+        node.top = top;
+        node.height = height;
+        node.bottom = Val::Auto;
     }
 }
 
-/// Blink the caret for focused text fields.
-fn text_field_caret_blink_system(
-    time: Res<Time>,
-    mut blink: ResMut<TextFieldCaretBlink>,
-    mut fields: Query<&mut MaterialTextField>,
+/// Scroll the focused multiline field so its caret line stays in view.
+///
+/// Runs once per caret move or edit, after the layout caught up with the text, so the
+/// field can still be scrolled away from the caret with the wheel.
+fn text_field_scroll_to_caret_system(
+    active: Res<ActiveTextField>,
+    mut revealed: Local<Option<(Entity, usize, usize)>>,
+    fields: Query<&MaterialTextField>,
+    inputs: Query<
+        (
+            Entity,
+            &TextFieldInputFor,
+            Ref<Text>,
+            &TextFont,
+            &TextLayoutInfo,
+            &ComputedNode,
+        ),
+        With<TextFieldInput>,
+    >,
+    parents: Query<&ChildOf>,
+    mut containers: Query<(&ScrollContainer, &mut ScrollPosition)>,
 ) {
-    blink.timer.tick(time.delta());
-    if blink.timer.just_finished() {
-        blink.visible = !blink.visible;
-        // Force the display system to re-run for focused fields so the inline caret updates.
-        for mut field in fields.iter_mut() {
-            if !field.disabled && field.focused {
-                field.set_changed();
-            }
-        }
+    let Some((entity, field)) = active
+        .0
+        .and_then(|entity| Some((entity, fields.get(entity).ok()?)))
+        .filter(|(_, field)| field.line_limits().is_some())
+    else {
+        *revealed = None;
+        return;
+    };
+    let Some(caret) = field.display_caret() else {
+        return;
+    };
+    let state = (entity, caret, field.value.len());
+    if *revealed == Some(state) {
+        return;
+    }
+
+    let Some((input, _, text, font, layout, node)) =
+        inputs.iter().find(|(_, owner, ..)| owner.0 == entity)
+    else {
+        return;
+    };
+    // The layout of a text changed this frame isn't computed yet.
+    if text.is_changed() {
+        return;
+    }
+    let Some(container) = parents
+        .iter_ancestors(input)
+        .find(|ancestor| containers.contains(*ancestor))
+    else {
+        return;
+    };
+    let Ok((scroll, mut position)) = containers.get_mut(container) else {
+        return;
+    };
+    let viewport = scroll.container_size.y;
+    if viewport <= 0.0 {
+        return;
+    }
+
+    let line_height = text_line_height(font);
+    let stops = caret_stops(&text.0, layout, line_height / node.inverse_scale_factor());
+    let top = caret_stop_at(&stops, caret).line as f32 * line_height;
+    let bottom = top + line_height;
+    let y = if top < position.y {
+        top
+    } else if bottom > position.y + viewport {
+        bottom - viewport
+    } else {
+        position.y
+    };
+    if position.y != y {
+        position.y = y;
     }
+    *revealed = Some(state);
 }
 
 /// Update the displayed input text when the text field state changes.
 fn text_field_display_system(
     theme: Option<Res<MaterialTheme>>,
-    blink: Res<TextFieldCaretBlink>,
     changed_fields: Query<(Entity, &MaterialTextField), Changed<MaterialTextField>>,
     mut input_text: Query<(&TextFieldInputFor, &mut Text, &mut TextColor), With<TextFieldInput>>,
 ) {
     let Some(theme) = theme else { return };
 
-    // Keep a stable line height even when the caret is "hidden".
+    // Keep a stable line height while the field is empty.
     // If we used an empty string, Bevy's text node can collapse, causing the
-    // floating label to move up/down as the caret blinks.
+    // floating label to move up/down.
     const ZERO_WIDTH_SPACE: &str = "\u{200B}";
 
-    let caret = if blink.visible { "|" } else { ZERO_WIDTH_SPACE };
-
     for (field_entity, field) in changed_fields.iter() {
         let has_label = field.label.is_some();
         // Expanded hint (inside field) is the label if present, otherwise the placeholder.
@@ -879,16 +2511,14 @@ fn text_field_display_system(
             field.placeholder.as_str()
         };
 
-        // Inline caret: render it as part of the input text so it appears right after the
-        // last glyph instead of being pushed to the far right by flex layout.
-        let (display, color) = if field.value.is_empty() {
+        // The caret and selection are separate nodes (see `text_field_caret_system`),
+        // so the text only ever holds the value or the hint.
+        let (display, color) = if field.is_composing() && !field.should_obscure_input() {
+            (field.display_value(), field.input_color(&theme))
+        } else if field.value.is_empty() {
             if field.is_label_floating() {
-                // Label is floating (focused or has content). If empty, show just the caret.
-                if field.focused {
-                    (caret.to_string(), field.input_color(&theme))
-                } else {
-                    (ZERO_WIDTH_SPACE.to_string(), field.input_color(&theme))
-                }
+                // Label is floating (focused or has content): keep the line height.
+                (ZERO_WIDTH_SPACE.to_string(), field.input_color(&theme))
             } else {
                 // Expanded hint inside the field.
                 let hint_color = if has_label {
@@ -898,27 +2528,21 @@ fn text_field_display_system(
                 };
                 (expanded_hint.to_string(), hint_color)
             }
-        } else {
+        } else if field.should_obscure_input() {
             // Actual value (obscure for password types if needed)
-            let shown_value = if field.should_obscure_input() {
-                "•".repeat(field.value.chars().count())
-            } else {
-                field.value.clone()
-            };
-
-            if field.focused {
-                (
-                    format!("{}{}", shown_value, caret),
-                    field.input_color(&theme),
-                )
-            } else {
-                (shown_value, field.input_color(&theme))
-            }
+            (
+                "•".repeat(field.value.chars().count()),
+                field.input_color(&theme),
+            )
+        } else {
+            (field.display_value(), field.input_color(&theme))
         };
 
         for (owner, mut text, mut text_color) in input_text.iter_mut() {
             if owner.0 == field_entity {
-                *text = Text::new(display.clone());
+                if text.0 != display {
+                    *text = Text::new(display.clone());
+                }
                 *text_color = TextColor(color);
             }
         }
@@ -1080,8 +2704,8 @@ impl TextFieldBuilder {
 
     /// Set initial value
     pub fn value(mut self, value: impl Into<String>) -> Self {
-        self.text_field.value = value.into();
-        self.text_field.has_content = !self.text_field.value.is_empty();
+        self.text_field.set_value(value);
+        self.text_field.clear_history();
         self
     }
 
@@ -1161,6 +2785,48 @@ impl TextFieldBuilder {
         self
     }
 
+    /// Add a validation rule
+    pub fn validator(mut self, validator: impl Validator) -> Self {
+        self.text_field.validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Set when validation runs
+    pub fn validate_on(mut self, trigger: ValidationTrigger) -> Self {
+        self.text_field.validation_trigger = trigger;
+        self
+    }
+
+    /// Set an input mask or number format
+    pub fn format(mut self, format: impl Into<InputFormat>) -> Self {
+        self.text_field = self.text_field.format(format);
+        self
+    }
+
+    /// Set how many undo steps are kept (0 disables undo)
+    pub fn history_depth(mut self, depth: usize) -> Self {
+        self.text_field.history.set_max_depth(depth);
+        self
+    }
+
+    /// Set the number of lines a multiline field shows at least
+    pub fn min_lines(mut self, lines: usize) -> Self {
+        self.text_field = self.text_field.min_lines(lines);
+        self
+    }
+
+    /// Set the number of lines a multiline field grows to before it scrolls
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.text_field = self.text_field.max_lines(lines);
+        self
+    }
+
+    /// Make this a multiline field that grows from `min_lines` to `max_lines`
+    pub fn multiline(mut self, min_lines: usize, max_lines: usize) -> Self {
+        self.text_field = self.text_field.multiline(min_lines, max_lines);
+        self
+    }
+
     /// Set width
     pub fn width(mut self, width: Val) -> Self {
         self.width = width;
@@ -1172,6 +2838,12 @@ impl TextFieldBuilder {
         let bg_color = self.text_field.container_color(theme);
         let border_color = self.text_field.indicator_color(theme);
         let is_outlined = self.text_field.variant == TextFieldVariant::Outlined;
+        // Multiline fields grow with their text.
+        let height = if self.text_field.line_limits().is_some() {
+            Val::Auto
+        } else {
+            Val::Px(TEXT_FIELD_HEIGHT)
+        };
 
         (
             self.text_field,
@@ -1179,7 +2851,8 @@ impl TextFieldBuilder {
             Interaction::None,
             Node {
                 width: self.width,
-                height: Val::Px(TEXT_FIELD_HEIGHT),
+                height,
+                min_height: Val::Px(TEXT_FIELD_HEIGHT),
                 padding: UiRect::axes(Val::Px(Spacing::LARGE), Val::Px(Spacing::MEDIUM)),
                 border: if is_outlined {
                     UiRect::all(Val::Px(1.0))
@@ -1219,6 +2892,30 @@ pub struct TextFieldInput;
 #[derive(Component)]
 pub struct TextFieldInputFor(pub Entity);
 
+/// Marker for the caret element
+#[derive(Component)]
+pub struct TextFieldCaret;
+
+/// Links a caret entity to its owning text field entity.
+#[derive(Component)]
+pub struct TextFieldCaretFor(pub Entity);
+
+/// Marker for the selection highlight element
+#[derive(Component)]
+pub struct TextFieldSelection;
+
+/// Links a selection highlight entity to its owning text field entity.
+#[derive(Component)]
+pub struct TextFieldSelectionFor(pub Entity);
+
+/// Marker for the underline below an IME composition
+#[derive(Component)]
+pub struct TextFieldPreeditUnderline;
+
+/// Links an IME composition underline entity to its owning text field entity.
+#[derive(Component)]
+pub struct TextFieldPreeditUnderlineFor(pub Entity);
+
 /// Marker for the placeholder element.
 #[derive(Component)]
 pub struct TextFieldPlaceholder;
@@ -1267,6 +2964,164 @@ pub struct TextFieldSupportingText;
 #[derive(Component)]
 pub struct TextFieldSupportingFor(pub Entity);
 
+/// Spawn the input line of a text field embedded in another component, without
+/// the field's own container, label, icons or supporting text.
+///
+/// `field_entity` must hold `field`; it gets the usual editing, caret and IME
+/// behavior. Leave `BackgroundColor`/`BorderColor` off it to keep the host's styling.
+pub(crate) fn spawn_embedded_text_field_input(
+    parent: &mut ChildSpawnerCommands,
+    theme: &MaterialTheme,
+    field_entity: Entity,
+    field: &MaterialTextField,
+) {
+    // Without a label the placeholder is the in-field hint.
+    let (initial_display, initial_color) = if field.value.is_empty() {
+        (field.placeholder.as_str(), field.placeholder_color(theme))
+    } else {
+        (field.value.as_str(), field.input_color(theme))
+    };
+    let line_limits = field.line_limits();
+
+    spawn_text_field_input_line(parent, line_limits, |input_line| {
+        spawn_text_field_input_text(
+            input_line,
+            field_entity,
+            initial_display,
+            initial_color,
+            line_limits.is_some(),
+        );
+    });
+}
+
+/// Node of a selection highlight (positioned by `text_field_caret_system`)
+fn text_field_selection_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        top: Val::Px(0.0),
+        bottom: Val::Px(0.0),
+        display: Display::None,
+        ..default()
+    }
+}
+
+/// Spawn the node holding a field's input line.
+///
+/// Multiline fields (`lines` is [`MaterialTextField::line_limits`]) get a full-width
+/// line inside a vertical [`ScrollContainer`] that grows from the minimum to the
+/// maximum number of lines and scrolls beyond it.
+fn spawn_text_field_input_line(
+    content: &mut ChildSpawnerCommands,
+    lines: Option<(usize, Option<usize>)>,
+    spawn_children: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    let Some((min_lines, max_lines)) = lines else {
+        content
+            .spawn(Node {
+                position_type: PositionType::Relative,
+                ..default()
+            })
+            .with_children(spawn_children);
+        return;
+    };
+
+    content
+        .spawn((
+            ScrollContainer::vertical(),
+            ScrollPosition::default(),
+            Node {
+                width: Val::Percent(100.0),
+                min_height: Val::Px(min_lines as f32 * TEXT_FIELD_LINE_HEIGHT),
+                max_height: max_lines.map_or(Val::Auto, |max| {
+                    Val::Px(max as f32 * TEXT_FIELD_LINE_HEIGHT)
+                }),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+        ))
+        .with_children(|scroll| {
+            scroll
+                .spawn(Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Percent(100.0),
+                    ..default()
+                })
+                .with_children(spawn_children);
+        });
+}
+
+/// Spawn the input text of a field's input line, with its selection highlight
+/// (behind the text), IME composition underline and caret (in front of it).
+///
+/// Multiline input text fills the line's width so it wraps at word boundaries.
+fn spawn_text_field_input_text(
+    input_line: &mut ChildSpawnerCommands,
+    field_entity: Entity,
+    initial_display: &str,
+    initial_color: Color,
+    multiline: bool,
+) {
+    input_line.spawn((
+        TextFieldSelection,
+        TextFieldSelectionFor(field_entity),
+        text_field_selection_node(),
+        BackgroundColor(Color::NONE),
+    ));
+
+    let mut input = input_line.spawn((
+        TextFieldInput,
+        TextFieldInputFor(field_entity),
+        Text::new(initial_display),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(initial_color),
+        RelativeCursorPosition::default(),
+    ));
+    if multiline {
+        input.insert((
+            TextFont {
+                font_size: 16.0,
+                line_height: bevy::text::LineHeight::Px(TEXT_FIELD_LINE_HEIGHT),
+                ..default()
+            },
+            TextLayout::new_with_linebreak(LineBreak::WordBoundary),
+            Node {
+                width: Val::Percent(100.0),
+                ..default()
+            },
+        ));
+    }
+
+    input_line.spawn((
+        TextFieldPreeditUnderline,
+        TextFieldPreeditUnderlineFor(field_entity),
+        Node {
+            position_type: PositionType::Absolute,
+            height: Val::Px(1.0),
+            bottom: Val::Px(0.0),
+            display: Display::None,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+    ));
+
+    input_line.spawn((
+        TextFieldCaret,
+        TextFieldCaretFor(field_entity),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(2.0),
+            top: Val::Px(0.0),
+            bottom: Val::Px(0.0),
+            display: Display::None,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+    ));
+}
+
 // ============================================================================
 // Spawn Traits for ChildSpawnerCommands
 // ============================================================================
@@ -1342,6 +3197,7 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
             .effective_trailing_icon()
             .map(|s| s.to_string());
         let initial_is_label_floating = builder.text_field.is_label_floating();
+        let line_limits = builder.text_field.line_limits();
 
         let supporting_text = builder.text_field.supporting_text.clone();
         let error = builder.text_field.error;
@@ -1472,46 +3328,38 @@ impl SpawnTextFieldChild for ChildSpawnerCommands<'_> {
                         };
 
                         // Input line: placeholder (overlay) + actual input text.
-                        content
-                            .spawn(Node {
-                                position_type: PositionType::Relative,
-                                ..default()
-                            })
-                            .with_children(|input_line| {
-                                // Separate placeholder (shown only when label is floating and value is empty)
-                                // Spawn it even if it's initially hidden so systems can toggle it.
-                                input_line.spawn((
-                                    TextFieldPlaceholder,
-                                    TextFieldPlaceholderFor(field_entity),
-                                    Text::new(placeholder_text.as_str()),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
-                                    TextColor(placeholder_color),
-                                    Node {
-                                        position_type: PositionType::Absolute,
-                                        left: Val::Px(0.0),
-                                        right: Val::Px(0.0),
-                                        top: Val::Px(0.0),
-                                        bottom: Val::Px(0.0),
-                                        display: Display::None,
-                                        ..default()
-                                    },
-                                    Visibility::Hidden,
-                                ));
-
-                                input_line.spawn((
-                                    TextFieldInput,
-                                    TextFieldInputFor(field_entity),
-                                    Text::new(initial_display),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
-                                    TextColor(initial_color),
-                                ));
-                            });
+                        spawn_text_field_input_line(content, line_limits, |input_line| {
+                            // Separate placeholder (shown only when label is floating and value is empty)
+                            // Spawn it even if it's initially hidden so systems can toggle it.
+                            input_line.spawn((
+                                TextFieldPlaceholder,
+                                TextFieldPlaceholderFor(field_entity),
+                                Text::new(placeholder_text.as_str()),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(placeholder_color),
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px(0.0),
+                                    right: Val::Px(0.0),
+                                    top: Val::Px(0.0),
+                                    bottom: Val::Px(0.0),
+                                    display: Display::None,
+                                    ..default()
+                                },
+                                Visibility::Hidden,
+                            ));
+
+                            spawn_text_field_input_text(
+                                input_line,
+                                field_entity,
+                                initial_display,
+                                initial_color,
+                                line_limits.is_some(),
+                            );
+                        });
                     });
 
                 // End icon (trailing icon)
@@ -1601,6 +3449,7 @@ pub fn spawn_text_field_control(
         .effective_trailing_icon()
         .map(|s| s.to_string());
     let initial_is_label_floating = builder.text_field.is_label_floating();
+    let line_limits = builder.text_field.line_limits();
 
     let supporting_text = builder.text_field.supporting_text.clone();
     let error = builder.text_field.error;
@@ -1733,46 +3582,38 @@ pub fn spawn_text_field_control(
                         };
 
                         // Input line: placeholder (overlay) + actual input text.
-                        content
-                            .spawn(Node {
-                                position_type: PositionType::Relative,
-                                ..default()
-                            })
-                            .with_children(|input_line| {
-                                // Separate placeholder (shown only when label is floating and value is empty)
-                                // Spawn it even if it's initially hidden so systems can toggle it.
-                                input_line.spawn((
-                                    TextFieldPlaceholder,
-                                    TextFieldPlaceholderFor(field_entity),
-                                    Text::new(placeholder_text.as_str()),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
-                                    TextColor(placeholder_color),
-                                    Node {
-                                        position_type: PositionType::Absolute,
-                                        left: Val::Px(0.0),
-                                        right: Val::Px(0.0),
-                                        top: Val::Px(0.0),
-                                        bottom: Val::Px(0.0),
-                                        display: Display::None,
-                                        ..default()
-                                    },
-                                    Visibility::Hidden,
-                                ));
-
-                                input_line.spawn((
-                                    TextFieldInput,
-                                    TextFieldInputFor(field_entity),
-                                    Text::new(initial_display),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
-                                    TextColor(initial_color),
-                                ));
-                            });
+                        spawn_text_field_input_line(content, line_limits, |input_line| {
+                            // Separate placeholder (shown only when label is floating and value is empty)
+                            // Spawn it even if it's initially hidden so systems can toggle it.
+                            input_line.spawn((
+                                TextFieldPlaceholder,
+                                TextFieldPlaceholderFor(field_entity),
+                                Text::new(placeholder_text.as_str()),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(placeholder_color),
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px(0.0),
+                                    right: Val::Px(0.0),
+                                    top: Val::Px(0.0),
+                                    bottom: Val::Px(0.0),
+                                    display: Display::None,
+                                    ..default()
+                                },
+                                Visibility::Hidden,
+                            ));
+
+                            spawn_text_field_input_text(
+                                input_line,
+                                field_entity,
+                                initial_display,
+                                initial_color,
+                                line_limits.is_some(),
+                            );
+                        });
                     });
 
                 // End icon (trailing icon)
@@ -1859,6 +3700,7 @@ pub fn spawn_text_field_control_with<M: Component>(
         .effective_trailing_icon()
         .map(|s| s.to_string());
     let initial_is_label_floating = builder.text_field.is_label_floating();
+    let line_limits = builder.text_field.line_limits();
 
     let supporting_text = builder.text_field.supporting_text.clone();
     let error = builder.text_field.error;
@@ -1992,46 +3834,38 @@ pub fn spawn_text_field_control_with<M: Component>(
                         };
 
                         // Input line: placeholder (overlay) + actual input text.
-                        content
-                            .spawn(Node {
-                                position_type: PositionType::Relative,
-                                ..default()
-                            })
-                            .with_children(|input_line| {
-                                // Separate placeholder (shown only when label is floating and value is empty)
-                                // Spawn it even if it's initially hidden so systems can toggle it.
-                                input_line.spawn((
-                                    TextFieldPlaceholder,
-                                    TextFieldPlaceholderFor(field_entity),
-                                    Text::new(placeholder_text.as_str()),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
-                                    TextColor(placeholder_color),
-                                    Node {
-                                        position_type: PositionType::Absolute,
-                                        left: Val::Px(0.0),
-                                        right: Val::Px(0.0),
-                                        top: Val::Px(0.0),
-                                        bottom: Val::Px(0.0),
-                                        display: Display::None,
-                                        ..default()
-                                    },
-                                    Visibility::Hidden,
-                                ));
-
-                                input_line.spawn((
-                                    TextFieldInput,
-                                    TextFieldInputFor(field_entity),
-                                    Text::new(initial_display),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
-                                    TextColor(initial_color),
-                                ));
-                            });
+                        spawn_text_field_input_line(content, line_limits, |input_line| {
+                            // Separate placeholder (shown only when label is floating and value is empty)
+                            // Spawn it even if it's initially hidden so systems can toggle it.
+                            input_line.spawn((
+                                TextFieldPlaceholder,
+                                TextFieldPlaceholderFor(field_entity),
+                                Text::new(placeholder_text.as_str()),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(placeholder_color),
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px(0.0),
+                                    right: Val::Px(0.0),
+                                    top: Val::Px(0.0),
+                                    bottom: Val::Px(0.0),
+                                    display: Display::None,
+                                    ..default()
+                                },
+                                Visibility::Hidden,
+                            ));
+
+                            spawn_text_field_input_text(
+                                input_line,
+                                field_entity,
+                                initial_display,
+                                initial_color,
+                                line_limits.is_some(),
+                            );
+                        });
                     });
 
                 // End icon (trailing icon)
@@ -2124,6 +3958,7 @@ fn text_field_end_icon_click_system(
                     click_events.write(TextFieldChangeEvent {
                         entity: *field_entity,
                         value: field.value.clone(),
+                        formatted: field.formatted_value(),
                     });
                 }
             }
@@ -2215,3 +4050,141 @@ fn text_field_icon_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_ime_left_alone_without_text_field() {
+        let mut world = World::new();
+        world.init_resource::<ActiveTextField>();
+        let field = world.spawn(MaterialTextField::new()).id();
+        let window = world.spawn((Window::default(), PrimaryWindow)).id();
+        let ime_enabled = |world: &World| world.get::<Window>(window).unwrap().ime_enabled;
+
+        // Set by the app while no text field is focused.
+        world.get_mut::<Window>(window).unwrap().ime_enabled = true;
+        world.run_system_once(text_field_ime_window_system).unwrap();
+        assert!(ime_enabled(&world));
+
+        let mut system = IntoSystem::into_system(text_field_ime_window_system);
+        system.initialize(&mut world);
+        world.resource_mut::<ActiveTextField>().0 = Some(field);
+        system.run((), &mut world).unwrap();
+        assert!(ime_enabled(&world));
+
+        world.resource_mut::<ActiveTextField>().0 = None;
+        system.run((), &mut world).unwrap();
+        assert!(!ime_enabled(&world));
+
+        world.get_mut::<Window>(window).unwrap().ime_enabled = true;
+        system.run((), &mut world).unwrap();
+        assert!(ime_enabled(&world));
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        let chars: Vec<char> = "foo, bar_baz  qux".chars().collect();
+        assert_eq!(previous_word_start(&chars, 8), 5);
+        assert_eq!(previous_word_start(&chars, 5), 0);
+        assert_eq!(next_word_end(&chars, 3), 12);
+        assert_eq!(next_word_end(&chars, 12), chars.len());
+    }
+
+    fn xs(stops: &[CaretStop]) -> Vec<f32> {
+        stops.iter().map(|stop| stop.x).collect()
+    }
+
+    fn lines(stops: &[CaretStop]) -> Vec<usize> {
+        stops.iter().map(|stop| stop.line).collect()
+    }
+
+    #[test]
+    fn test_caret_stops_follow_glyph_bounds() {
+        // "ab c": the space has no ink, so its bounds collapse to the pen position.
+        let glyphs = [
+            (0, 0.0, 8.0, 0),
+            (1, 10.0, 18.0, 0),
+            (2, 20.0, 20.0, 0),
+            (3, 24.0, 30.0, 0),
+        ];
+        let stops = caret_stops_from_glyphs("ab c", &glyphs);
+        assert_eq!(xs(&stops), vec![0.0, 9.0, 19.0, 22.0, 30.0]);
+        assert_eq!(nearest_caret_stop(&stops, 17.0, 0), Some(2));
+        assert_eq!(nearest_caret_stop(&stops, 100.0, 0), Some(4));
+    }
+
+    #[test]
+    fn test_caret_stops_multibyte() {
+        let glyphs = [(0, 0.0, 8.0, 0), (3, 8.0, 16.0, 0)];
+        let stops = caret_stops_from_glyphs("•x", &glyphs);
+        assert_eq!(xs(&stops), vec![0.0, 8.0, 16.0]);
+    }
+
+    #[test]
+    fn test_caret_stops_wrapped_lines() {
+        // "ab cd" soft-wrapped after the space, then a hard break before "e".
+        let glyphs = [
+            (0, 0.0, 8.0, 0),
+            (1, 8.0, 16.0, 0),
+            (2, 16.0, 20.0, 0),
+            (3, 0.0, 8.0, 1),
+            (4, 8.0, 16.0, 1),
+            (6, 0.0, 8.0, 2),
+        ];
+        let stops = caret_stops_from_glyphs("ab cd\ne", &glyphs);
+        assert_eq!(lines(&stops), vec![0, 0, 0, 1, 1, 1, 2, 2]);
+        assert_eq!(xs(&stops), vec![0.0, 8.0, 16.0, 0.0, 8.0, 16.0, 0.0, 8.0]);
+
+        assert_eq!(nearest_caret_stop(&stops, 9.0, 1), Some(4));
+        // Past the last line picks from the last line.
+        assert_eq!(nearest_caret_stop(&stops, 100.0, 9), Some(7));
+        assert_eq!(caret_stop_on_adjacent_line(&stops, 1, 8.0, 1), Some(4));
+        assert_eq!(caret_stop_on_adjacent_line(&stops, 4, 8.0, -1), Some(1));
+        assert_eq!(caret_stop_on_adjacent_line(&stops, 1, 8.0, -1), None);
+        assert_eq!(caret_stop_on_adjacent_line(&stops, 7, 8.0, 1), None);
+
+        assert_eq!(
+            selection_rects(&stops, 1..7),
+            vec![(8.0, 16.0, 0), (0.0, 16.0, 1), (0.0, 8.0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_caret_stops_empty_lines() {
+        let glyphs = [(0, 0.0, 8.0, 0), (3, 0.0, 8.0, 2)];
+        let stops = caret_stops_from_glyphs("a\n\nb", &glyphs);
+        assert_eq!(lines(&stops), vec![0, 0, 1, 2, 2]);
+        assert_eq!(xs(&stops), vec![0.0, 8.0, 0.0, 0.0, 8.0]);
+    }
+
+    #[test]
+    fn test_logical_line_motion() {
+        let chars: Vec<char> = "abcd\nef\nghij".chars().collect();
+        assert_eq!(line_above(&chars, 3), None);
+        assert_eq!(line_below(&chars, 3), Some(7));
+        assert_eq!(line_below(&chars, 7), Some(10));
+        assert_eq!(line_above(&chars, 12), Some(7));
+        assert_eq!(line_below(&chars, 12), None);
+    }
+
+    #[test]
+    fn test_preedit_display() {
+        let mut field = MaterialTextField::new().with_value("abcd");
+        field.select(1..3);
+        // "ね" is 3 bytes: the IME cursor sits after the first character.
+        field.set_preedit("ねこ", Some((3, 3)));
+        assert_eq!(field.display_value(), "aねこd");
+        assert_eq!(field.preedit_display_range(), Some(1..3));
+        assert_eq!(field.display_caret(), Some(2));
+
+        field.set_preedit("ねこ", None);
+        assert_eq!(field.display_caret(), None);
+
+        field.set_preedit("", None);
+        assert!(!field.is_composing());
+        assert_eq!(field.display_value(), "abcd");
+    }
+}
@@ -7,10 +7,10 @@
 
 use bevy::picking::Pickable;
 use bevy::prelude::*;
-use bevy::ui::UiGlobalTransform;
 
 use crate::{
     motion::{ease_standard_accelerate, ease_standard_decelerate},
+    popover::{Popover, PopoverAlign, PopoverAnchor, PopoverPlacement, PopoverSide},
     theme::MaterialTheme,
     tokens::{CornerRadius, Duration, Spacing},
 };
@@ -81,6 +81,19 @@ pub enum TooltipPosition {
     Right,
 }
 
+impl TooltipPosition {
+    /// The preferred [`PopoverPlacement`], centered on the anchor
+    pub fn placement(self) -> PopoverPlacement {
+        let side = match self {
+            Self::Top => PopoverSide::Above,
+            Self::Bottom => PopoverSide::Below,
+            Self::Left => PopoverSide::Start,
+            Self::Right => PopoverSide::End,
+        };
+        PopoverPlacement::new(side, PopoverAlign::Center)
+    }
+}
+
 // ============================================================================
 // Components
 // ============================================================================
@@ -616,96 +629,30 @@ fn tooltip_animation_system(
     }
 }
 
-/// System to position tooltips relative to their anchors
+/// System to place tooltips next to their anchors through a [`Popover`], which flips
+/// them to the other side of the anchor and keeps them inside the window
 fn tooltip_position_system(
-    mut tooltips: Query<(&Tooltip, &mut Node, &ComputedNode)>,
-    anchors: Query<(&UiGlobalTransform, &ComputedNode)>,
-    overlay_query: Query<(&UiGlobalTransform, &ComputedNode), With<TooltipOverlay>>,
-    windows: Query<&Window>,
+    mut commands: Commands,
+    mut tooltips: Query<(Entity, &Tooltip, Option<&mut Popover>), Changed<Tooltip>>,
 ) {
-    // Get window scale factor
-    let scale_factor = windows
-        .iter()
-        .next()
-        .map(|w| w.scale_factor())
-        .unwrap_or(1.0);
-
-    // Get overlay's position and size to calculate offset
-    // UiGlobalTransform gives CENTER of element, so we need to adjust
-    let (overlay_center, overlay_size) = overlay_query
-        .iter()
-        .next()
-        .map(|(t, c)| (t.translation, c.size()))
-        .unwrap_or((Vec2::ZERO, Vec2::ZERO));
-
-    // Calculate overlay's top-left corner
-    let overlay_top_left = overlay_center - overlay_size / 2.0;
-
-    for (tooltip, mut node, tooltip_computed) in tooltips.iter_mut() {
-        let Ok((anchor_transform, anchor_computed)) = anchors.get(tooltip.anchor) else {
-            continue;
-        };
-
-        let scale = scale_factor;
-
-        // Both UiGlobalTransform and ComputedNode.size() are in physical pixels
-        let anchor_center_physical = anchor_transform.translation;
-        let anchor_size_physical = anchor_computed.size();
-
-        // Skip if anchor has no size yet (not laid out)
-        if anchor_size_physical.x <= 0.0 || anchor_size_physical.y <= 0.0 {
-            continue;
+    for (entity, tooltip, popover) in tooltips.iter_mut() {
+        let placement = tooltip.position.placement();
+        match popover {
+            Some(mut popover) => {
+                let anchor = PopoverAnchor::Entity(tooltip.anchor);
+                if popover.anchor != anchor || popover.placement != placement {
+                    popover.anchor = anchor;
+                    popover.placement = placement;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(
+                    Popover::new(tooltip.anchor)
+                        .with_placement(placement)
+                        .with_gap(TOOLTIP_OFFSET),
+                );
+            }
         }
-
-        // Calculate anchor's top-left corner in physical pixels
-        let anchor_top_left_physical = anchor_center_physical - anchor_size_physical / 2.0;
-
-        // Tooltip size is also in physical pixels
-        let tooltip_size_physical = tooltip_computed.size();
-        let tooltip_width_physical = if tooltip_size_physical.x > 0.0 {
-            tooltip_size_physical.x
-        } else {
-            TOOLTIP_MAX_WIDTH * scale / 2.0
-        };
-        let tooltip_height_physical = if tooltip_size_physical.y > 0.0 {
-            tooltip_size_physical.y
-        } else {
-            TOOLTIP_HEIGHT_PLAIN * scale
-        };
-
-        // Offset in physical pixels
-        let offset_physical = TOOLTIP_OFFSET * scale;
-
-        // Calculate tooltip position in physical pixels
-        let (screen_top_physical, screen_left_physical) = match tooltip.position {
-            TooltipPosition::Top => (
-                anchor_top_left_physical.y - offset_physical - tooltip_height_physical,
-                anchor_top_left_physical.x
-                    + (anchor_size_physical.x - tooltip_width_physical) / 2.0,
-            ),
-            TooltipPosition::Bottom => (
-                anchor_top_left_physical.y + anchor_size_physical.y + offset_physical,
-                anchor_top_left_physical.x
-                    + (anchor_size_physical.x - tooltip_width_physical) / 2.0,
-            ),
-            TooltipPosition::Left => (
-                anchor_top_left_physical.y
-                    + (anchor_size_physical.y - tooltip_height_physical) / 2.0,
-                anchor_top_left_physical.x - offset_physical - tooltip_width_physical,
-            ),
-            TooltipPosition::Right => (
-                anchor_top_left_physical.y
-                    + (anchor_size_physical.y - tooltip_height_physical) / 2.0,
-                anchor_top_left_physical.x + anchor_size_physical.x + offset_physical,
-            ),
-        };
-
-        // Convert to logical pixels for Node positioning (divide by scale)
-        let top = (screen_top_physical - overlay_top_left.y) / scale;
-        let left = (screen_left_physical - overlay_top_left.x) / scale;
-
-        node.top = Val::Px(top);
-        node.left = Val::Px(left);
     }
 }
 
@@ -733,6 +680,15 @@ mod tests {
         assert!((trigger.delay - 0.2).abs() < 0.001);
     }
 
+    #[test]
+    fn test_tooltip_position_placement() {
+        assert_eq!(
+            TooltipPosition::Top.placement(),
+            PopoverPlacement::new(PopoverSide::Above, PopoverAlign::Center)
+        );
+        assert_eq!(TooltipPosition::Right.placement().side, PopoverSide::End);
+    }
+
     #[test]
     fn test_tooltip_dismiss() {
         // Can't test without ECS, but we can test the state machine
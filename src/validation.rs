@@ -0,0 +1,923 @@
+//! Input validation
+//!
+//! A [`Validator`] checks a text value and returns an error message when the value
+//! is invalid. Validators are attached to text fields with
+//! [`MaterialTextField::validator`](crate::text_field::MaterialTextField::validator);
+//! the failing validator's message is shown as the field's error text.
+//!
+//! Built-in validators:
+//! - [`Required`] - the value must not be blank
+//! - [`MinLength`] / [`MaxLength`] - character count limits
+//! - [`Pattern`] - the whole value must match a regex-like pattern
+//! - [`NumberRange`] - the value must be a number within bounds
+//! - [`Email`] / [`Url`] - the value must look like an email address / URL
+//!
+//! Any `Fn(&str) -> Result<(), String>` closure is a validator too. All built-ins
+//! except [`Required`] accept an empty value, so optional fields stay valid until
+//! something is typed.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let field = MaterialTextField::new()
+//!     .label("Zip code")
+//!     .validator(Required::new())
+//!     .validator(Pattern::new(r"\d{5}(-\d{4})?").unwrap().with_message("Use 12345 or 12345-6789"))
+//!     .validate_on(ValidationTrigger::OnChange);
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A rule that a text value has to satisfy
+pub trait Validator: Send + Sync + 'static {
+    /// Check `value`, returning an error message if it is invalid
+    fn validate(&self, value: &str) -> Result<(), String>;
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+{
+    fn validate(&self, value: &str) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// Run validators in order and return the first error
+pub fn validate_all(validators: &[Arc<dyn Validator>], value: &str) -> Result<(), String> {
+    validators
+        .iter()
+        .try_for_each(|validator| validator.validate(value))
+}
+
+/// When a field's validators run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationTrigger {
+    /// After every edit
+    OnChange,
+    /// When the field loses focus (and on submit)
+    #[default]
+    OnBlur,
+    /// Only when the value is submitted (Enter, or a form submit)
+    OnSubmit,
+}
+
+/// The value must contain something other than whitespace
+#[derive(Debug, Clone)]
+pub struct Required {
+    message: String,
+}
+
+impl Required {
+    /// Create the validator with the default message
+    pub fn new() -> Self {
+        Self {
+            message: "Required".to_string(),
+        }
+    }
+
+    /// Use a custom error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl Default for Required {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for Required {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.trim().is_empty() {
+            Err(self.message.clone())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The value must have at least `min` characters
+#[derive(Debug, Clone)]
+pub struct MinLength {
+    min: usize,
+    message: String,
+}
+
+impl MinLength {
+    /// Create the validator with the default message
+    pub fn new(min: usize) -> Self {
+        Self {
+            min,
+            message: format!("Must be at least {min} characters"),
+        }
+    }
+
+    /// Use a custom error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl Validator for MinLength {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let count = value.chars().count();
+        if count == 0 || count >= self.min {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+/// The value must have at most `max` characters
+#[derive(Debug, Clone)]
+pub struct MaxLength {
+    max: usize,
+    message: String,
+}
+
+impl MaxLength {
+    /// Create the validator with the default message
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            message: format!("Must be at most {max} characters"),
+        }
+    }
+
+    /// Use a custom error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl Validator for MaxLength {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.chars().count() <= self.max {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+/// The value must be a number within the given bounds (inclusive)
+#[derive(Debug, Clone)]
+pub struct NumberRange {
+    min: Option<f64>,
+    max: Option<f64>,
+    message: String,
+}
+
+impl NumberRange {
+    /// Create a validator for `min..=max`
+    pub fn new(min: f64, max: f64) -> Self {
+        Self::with_bounds(Some(min), Some(max))
+    }
+
+    /// Create a validator with only a lower bound
+    pub fn at_least(min: f64) -> Self {
+        Self::with_bounds(Some(min), None)
+    }
+
+    /// Create a validator with only an upper bound
+    pub fn at_most(max: f64) -> Self {
+        Self::with_bounds(None, Some(max))
+    }
+
+    /// Create a validator that only requires a number
+    pub fn any() -> Self {
+        Self::with_bounds(None, None)
+    }
+
+    fn with_bounds(min: Option<f64>, max: Option<f64>) -> Self {
+        let message = match (min, max) {
+            (Some(min), Some(max)) => format!("Must be between {min} and {max}"),
+            (Some(min), None) => format!("Must be at least {min}"),
+            (None, Some(max)) => format!("Must be at most {max}"),
+            (None, None) => "Must be a number".to_string(),
+        };
+        Self { min, max, message }
+    }
+
+    /// Use a custom error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl Validator for NumberRange {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(());
+        }
+
+        let in_range = value.parse::<f64>().is_ok_and(|number| {
+            number.is_finite()
+                && self.min.is_none_or(|min| number >= min)
+                && self.max.is_none_or(|max| number <= max)
+        });
+        if in_range {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+/// The value must look like an email address (`name@example.com`)
+#[derive(Debug, Clone)]
+pub struct Email {
+    message: String,
+}
+
+impl Email {
+    /// Create the validator with the default message
+    pub fn new() -> Self {
+        Self {
+            message: "Enter a valid email address".to_string(),
+        }
+    }
+
+    /// Use a custom error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl Default for Email {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for Email {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() || is_email_shaped(value) {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+/// The value must look like an absolute URL (`https://example.com/path`)
+#[derive(Debug, Clone)]
+pub struct Url {
+    message: String,
+}
+
+impl Url {
+    /// Create the validator with the default message
+    pub fn new() -> Self {
+        Self {
+            message: "Enter a valid URL".to_string(),
+        }
+    }
+
+    /// Use a custom error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl Default for Url {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for Url {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() || is_url_shaped(value) {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+/// `local@domain.tld`: one `@`, no whitespace, and a dotted domain.
+fn is_email_shaped(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !local.contains(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.contains('.')
+        && is_host_shaped(domain)
+}
+
+/// `scheme://host[:port][/path][?query][#fragment]` without whitespace.
+fn is_url_shaped(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+    let scheme_ok = scheme.starts_with(|ch: char| ch.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.'));
+    if !scheme_ok || rest.contains(char::is_whitespace) {
+        return false;
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    // Drop any `user:password@` prefix.
+    let authority = authority.rsplit('@').next().unwrap_or("");
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (authority, None),
+    };
+    port.is_none_or(|port| !port.is_empty() && port.chars().all(|ch| ch.is_ascii_digit()))
+        && is_host_shaped(host)
+}
+
+/// Dot-separated labels of letters, digits and inner hyphens.
+fn is_host_shaped(host: &str) -> bool {
+    !host.is_empty()
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|ch| ch.is_alphanumeric() || ch == '-')
+        })
+}
+
+// ============================================================================
+// Pattern
+// ============================================================================
+
+/// The whole value must match a regex-like pattern.
+///
+/// Supported syntax: literal characters, `.`, character classes (`[a-z]`,
+/// `[^0-9]`), the shorthands `\d`, `\w`, `\s` (and `\D`, `\W`, `\S`), groups
+/// `( )`, alternation `|`, and the quantifiers `?`, `*`, `+`, `{n}`, `{n,}` and
+/// `{n,m}`. Patterns always match the entire value, so `^` / `$` anchors are not
+/// needed (and not supported). Escape other special characters with `\`.
+///
+/// Matching takes time linear in the length of the value, whatever the pattern.
+/// Patterns that compile to more than [`PATTERN_MAX_INSTRUCTIONS`] are rejected,
+/// and values that would take more than [`PATTERN_MAX_STEPS`] to check do not
+/// match.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String,
+    program: Vec<PatternInst>,
+    message: String,
+}
+
+/// Largest compiled [`Pattern`]; counted repetitions like `{n,m}` are expanded.
+pub const PATTERN_MAX_INSTRUCTIONS: usize = 2_000;
+
+/// Most matcher steps one [`Pattern::is_match`] call may take.
+pub const PATTERN_MAX_STEPS: usize = 10_000_000;
+
+/// Error returned by [`Pattern::new`] for malformed patterns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternError {
+    /// Character index where the problem was found
+    pub position: usize,
+    /// What is wrong
+    pub message: String,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl Pattern {
+    /// Compile a pattern
+    pub fn new(pattern: impl Into<String>) -> Result<Self, PatternError> {
+        let source = pattern.into();
+        let chars: Vec<char> = source.chars().collect();
+        let mut parser = PatternParser {
+            chars: &chars,
+            pos: 0,
+        };
+        let root = parser.parse_alternatives()?;
+        if parser.pos < chars.len() {
+            return Err(parser.error("unmatched ')'"));
+        }
+
+        let mut compiler = PatternCompiler {
+            program: Vec::new(),
+            position: chars.len(),
+        };
+        compiler.compile_alternatives(&root)?;
+        compiler.emit(PatternInst::Match)?;
+
+        Ok(Self {
+            source,
+            program: compiler.program,
+            message: "Invalid format".to_string(),
+        })
+    }
+
+    /// Use a custom error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// The pattern source
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the whole of `text` matches the pattern
+    pub fn is_match(&self, text: &str) -> bool {
+        // Thompson-style simulation: every thread of the NFA advances one
+        // character at a time, so nothing is retried and nothing recurses.
+        let mut threads = PatternThreads::new(self.program.len());
+        threads.add(&self.program, 0);
+        for ch in text.chars() {
+            if threads.current.is_empty() || threads.steps > PATTERN_MAX_STEPS {
+                return false;
+            }
+            let current = std::mem::take(&mut threads.current);
+            threads.generation += 1;
+            for &pc in &current {
+                if let PatternInst::Set(set) = &self.program[pc] {
+                    if set.matches(ch) {
+                        threads.add(&self.program, pc + 1);
+                    }
+                }
+            }
+        }
+        threads.steps <= PATTERN_MAX_STEPS
+            && threads
+                .current
+                .iter()
+                .any(|&pc| matches!(self.program[pc], PatternInst::Match))
+    }
+}
+
+impl Validator for Pattern {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() || self.is_match(value) {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum PatternNode {
+    /// One character from a set
+    Set(CharSet),
+    /// A group of alternative sequences
+    Group(Vec<Vec<PatternNode>>),
+    /// `node{min,max}`
+    Repeat {
+        node: Box<PatternNode>,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct CharSet {
+    items: Vec<ClassItem>,
+    negated: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ClassItem {
+    Range(char, char),
+    /// `\d`, `\w`, `\s`, with `true` for the negated forms
+    Digit(bool),
+    Word(bool),
+    Space(bool),
+}
+
+impl CharSet {
+    fn literal(ch: char) -> Self {
+        Self {
+            items: vec![ClassItem::Range(ch, ch)],
+            negated: false,
+        }
+    }
+
+    fn matches(&self, ch: char) -> bool {
+        let hit = self.items.iter().any(|item| match *item {
+            ClassItem::Range(lo, hi) => (lo..=hi).contains(&ch),
+            ClassItem::Digit(negated) => ch.is_ascii_digit() != negated,
+            ClassItem::Word(negated) => (ch.is_alphanumeric() || ch == '_') != negated,
+            ClassItem::Space(negated) => ch.is_whitespace() != negated,
+        });
+        hit != self.negated
+    }
+}
+
+struct PatternParser<'a> {
+    chars: &'a [char],
+    pos: usize,
+}
+
+impl PatternParser<'_> {
+    fn error(&self, message: &str) -> PatternError {
+        PatternError {
+            position: self.pos,
+            message: message.to_string(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += 1;
+        Some(ch)
+    }
+
+    fn parse_alternatives(&mut self) -> Result<Vec<Vec<PatternNode>>, PatternError> {
+        let mut alternatives = vec![self.parse_sequence()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            alternatives.push(self.parse_sequence()?);
+        }
+        Ok(alternatives)
+    }
+
+    fn parse_sequence(&mut self) -> Result<Vec<PatternNode>, PatternError> {
+        let mut sequence = Vec::new();
+        while let Some(ch) = self.peek() {
+            if matches!(ch, '|' | ')') {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            sequence.push(self.parse_quantifier(atom)?);
+        }
+        Ok(sequence)
+    }
+
+    fn parse_atom(&mut self) -> Result<PatternNode, PatternError> {
+        let Some(ch) = self.next() else {
+            return Err(self.error("unexpected end of pattern"));
+        };
+        let set = match ch {
+            '.' => CharSet {
+                items: Vec::new(),
+                negated: true,
+            },
+            '(' => {
+                let group = self.parse_alternatives()?;
+                if self.next() != Some(')') {
+                    return Err(self.error("missing ')'"));
+                }
+                return Ok(PatternNode::Group(group));
+            }
+            '[' => self.parse_class()?,
+            '\\' => {
+                let item = self.parse_escape()?;
+                CharSet {
+                    items: vec![item],
+                    negated: false,
+                }
+            }
+            '?' | '*' | '+' | '{' => return Err(self.error("quantifier without a target")),
+            ch => CharSet::literal(ch),
+        };
+        Ok(PatternNode::Set(set))
+    }
+
+    fn parse_escape(&mut self) -> Result<ClassItem, PatternError> {
+        let Some(ch) = self.next() else {
+            return Err(self.error("trailing '\\'"));
+        };
+        Ok(match ch {
+            'd' => ClassItem::Digit(false),
+            'D' => ClassItem::Digit(true),
+            'w' => ClassItem::Word(false),
+            'W' => ClassItem::Word(true),
+            's' => ClassItem::Space(false),
+            'S' => ClassItem::Space(true),
+            ch => ClassItem::Range(ch, ch),
+        })
+    }
+
+    fn parse_class(&mut self) -> Result<CharSet, PatternError> {
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.pos += 1;
+        }
+
+        let mut items = Vec::new();
+        loop {
+            let item = match self.next() {
+                None => return Err(self.error("missing ']'")),
+                // A leading `]` is a literal.
+                Some(']') if !items.is_empty() => break,
+                Some('\\') => self.parse_escape()?,
+                Some(ch) => ClassItem::Range(ch, ch),
+            };
+
+            // `a-z` ranges (a `-` before `]` is a literal).
+            let is_range = self.peek() == Some('-')
+                && self
+                    .chars
+                    .get(self.pos + 1)
+                    .is_some_and(|&next| next != ']');
+            match item {
+                ClassItem::Range(lo, _) if is_range => {
+                    self.pos += 1;
+                    let hi = match self.next() {
+                        Some('\\') => match self.parse_escape()? {
+                            ClassItem::Range(hi, _) => hi,
+                            _ => return Err(self.error("invalid class range")),
+                        },
+                        Some(hi) => hi,
+                        None => return Err(self.error("missing ']'")),
+                    };
+                    if hi < lo {
+                        return Err(self.error("invalid class range"));
+                    }
+                    items.push(ClassItem::Range(lo, hi));
+                }
+                item => items.push(item),
+            }
+        }
+        Ok(CharSet { items, negated })
+    }
+
+    fn parse_quantifier(&mut self, node: PatternNode) -> Result<PatternNode, PatternError> {
+        let (min, max) = match self.peek() {
+            Some('?') => (0, Some(1)),
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('{') => {
+                self.pos += 1;
+                let min = self
+                    .parse_number()
+                    .ok_or_else(|| self.error("expected a count"))?;
+                let max = if self.peek() == Some(',') {
+                    self.pos += 1;
+                    self.parse_number()
+                } else {
+                    Some(min)
+                };
+                if self.peek() != Some('}') {
+                    return Err(self.error("missing '}'"));
+                }
+                if max.is_some_and(|max| max < min) {
+                    return Err(self.error("invalid repetition range"));
+                }
+                (min, max)
+            }
+            _ => return Ok(node),
+        };
+        self.pos += 1;
+
+        Ok(PatternNode::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+        })
+    }
+
+    fn parse_number(&mut self) -> Option<usize> {
+        let start = self.pos;
+        while self.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+/// One instruction of a compiled [`Pattern`].
+#[derive(Debug, Clone)]
+enum PatternInst {
+    /// Consume one character from the set and continue with the next instruction
+    Set(CharSet),
+    /// Continue with both instructions
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+/// Turns the parsed [`PatternNode`] tree into a flat instruction list.
+struct PatternCompiler {
+    program: Vec<PatternInst>,
+    /// Reported by the "pattern too large" error
+    position: usize,
+}
+
+impl PatternCompiler {
+    fn emit(&mut self, inst: PatternInst) -> Result<usize, PatternError> {
+        if self.program.len() >= PATTERN_MAX_INSTRUCTIONS {
+            return Err(PatternError {
+                position: self.position,
+                message: "pattern too large".to_string(),
+            });
+        }
+        self.program.push(inst);
+        Ok(self.program.len() - 1)
+    }
+
+    fn compile_alternatives(
+        &mut self,
+        alternatives: &[Vec<PatternNode>],
+    ) -> Result<(), PatternError> {
+        let mut jumps = Vec::new();
+        for (index, sequence) in alternatives.iter().enumerate() {
+            if index + 1 == alternatives.len() {
+                self.compile_sequence(sequence)?;
+                break;
+            }
+            let split = self.emit(PatternInst::Split(0, 0))?;
+            self.compile_sequence(sequence)?;
+            jumps.push(self.emit(PatternInst::Jump(0))?);
+            self.program[split] = PatternInst::Split(split + 1, self.program.len());
+        }
+        let end = self.program.len();
+        for jump in jumps {
+            self.program[jump] = PatternInst::Jump(end);
+        }
+        Ok(())
+    }
+
+    fn compile_sequence(&mut self, sequence: &[PatternNode]) -> Result<(), PatternError> {
+        sequence.iter().try_for_each(|node| self.compile_node(node))
+    }
+
+    fn compile_node(&mut self, node: &PatternNode) -> Result<(), PatternError> {
+        match node {
+            PatternNode::Set(set) => self.emit(PatternInst::Set(set.clone())).map(|_| ()),
+            PatternNode::Group(alternatives) => self.compile_alternatives(alternatives),
+            PatternNode::Repeat { node, min, max } => {
+                for _ in 0..*min {
+                    self.compile_node(node)?;
+                }
+                match max {
+                    // Loop: try another repetition first, as the greedy form.
+                    None => {
+                        let split = self.emit(PatternInst::Split(0, 0))?;
+                        self.compile_node(node)?;
+                        self.emit(PatternInst::Jump(split))?;
+                        self.program[split] = PatternInst::Split(split + 1, self.program.len());
+                    }
+                    // Up to `max - min` optional repetitions, each skipping to the end.
+                    Some(max) => {
+                        let mut splits = Vec::new();
+                        for _ in *min..*max {
+                            splits.push(self.emit(PatternInst::Split(0, 0))?);
+                            self.compile_node(node)?;
+                        }
+                        let end = self.program.len();
+                        for split in splits {
+                            self.program[split] = PatternInst::Split(split + 1, end);
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The set of NFA states reached after the characters matched so far.
+struct PatternThreads {
+    /// Instructions waiting for the next character (or the end of the input)
+    current: Vec<usize>,
+    /// Generation in which each instruction was last added
+    seen: Vec<usize>,
+    generation: usize,
+    steps: usize,
+}
+
+impl PatternThreads {
+    fn new(len: usize) -> Self {
+        Self {
+            current: Vec::new(),
+            seen: vec![usize::MAX; len],
+            generation: 0,
+            steps: 0,
+        }
+    }
+
+    /// Add `pc` and everything reachable from it without consuming a character.
+    fn add(&mut self, program: &[PatternInst], pc: usize) {
+        let mut stack = vec![pc];
+        while let Some(pc) = stack.pop() {
+            if self.seen[pc] == self.generation {
+                continue;
+            }
+            self.seen[pc] = self.generation;
+            self.steps += 1;
+            match program[pc] {
+                // Pushed in reverse so the first target is followed first.
+                PatternInst::Split(first, second) => stack.extend([second, first]),
+                PatternInst::Jump(target) => stack.push(target),
+                PatternInst::Set(_) | PatternInst::Match => self.current.push(pc),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_matching() {
+        let zip = Pattern::new(r"\d{5}(-\d{4})?").unwrap();
+        assert!(zip.is_match("12345"));
+        assert!(zip.is_match("12345-6789"));
+        assert!(!zip.is_match("1234"));
+        assert!(!zip.is_match("12345-"));
+
+        let name = Pattern::new("[A-Z][a-z]*( [A-Z][a-z]*)*").unwrap();
+        assert!(name.is_match("Ada Lovelace"));
+        assert!(!name.is_match("ada"));
+
+        let choice = Pattern::new("(red|green)+").unwrap();
+        assert!(choice.is_match("redgreenred"));
+        assert!(!choice.is_match("blue"));
+
+        let class = Pattern::new(r"[^\s-]+-[a-c\]]").unwrap();
+        assert!(class.is_match("abc-]"));
+        assert!(!class.is_match("a c-a"));
+
+        assert!(Pattern::new("(a*)*b").unwrap().is_match("aab"));
+        assert!(Pattern::new("a{2,3}").unwrap().is_match("aaa"));
+        assert!(!Pattern::new("a{2,3}").unwrap().is_match("aaaa"));
+        assert!(Pattern::new("(|a)+").unwrap().is_match(""));
+    }
+
+    #[test]
+    fn test_pattern_long_input() {
+        let digits = Pattern::new(r"\d+").unwrap();
+        let long = "7".repeat(200_000);
+        assert!(digits.is_match(&long));
+        assert!(!digits.is_match(&format!("{long}x")));
+    }
+
+    #[test]
+    fn test_pattern_nested_quantifiers() {
+        let nested = Pattern::new("(a+)+b").unwrap();
+        assert!(nested.is_match("aaab"));
+        assert!(!nested.is_match(&"a".repeat(5_000)));
+
+        let stars = Pattern::new("(a*)*(a|aa)*c").unwrap();
+        assert!(!stars.is_match(&"a".repeat(5_000)));
+    }
+
+    #[test]
+    fn test_pattern_size_limit() {
+        assert!(Pattern::new("a{3000}").is_err());
+        assert!(Pattern::new("(a{100}){100}").is_err());
+        assert!(Pattern::new("[a-z]{1,64}").is_ok());
+    }
+
+    #[test]
+    fn test_pattern_errors() {
+        assert!(Pattern::new("(ab").is_err());
+        assert!(Pattern::new("ab)").is_err());
+        assert!(Pattern::new("[ab").is_err());
+        assert!(Pattern::new("*a").is_err());
+        assert!(Pattern::new("a{3,1}").is_err());
+        assert!(Pattern::new("[z-a]").is_err());
+        assert!(Pattern::new("a\\").is_err());
+    }
+
+    #[test]
+    fn test_email_and_url_shapes() {
+        assert!(is_email_shaped("ada@example.com"));
+        assert!(is_email_shaped("first.last+tag@mail.example.co"));
+        assert!(!is_email_shaped("ada@example"));
+        assert!(!is_email_shaped("ada@@example.com"));
+        assert!(!is_email_shaped("a da@example.com"));
+        assert!(!is_email_shaped("@example.com"));
+        assert!(!is_email_shaped("ada@-example.com"));
+
+        assert!(is_url_shaped("https://example.com"));
+        assert!(is_url_shaped("http://localhost:8080/path?q=1#top"));
+        assert!(is_url_shaped("ftp://user:pw@files.example.org/"));
+        assert!(!is_url_shaped("example.com"));
+        assert!(!is_url_shaped("https://"));
+        assert!(!is_url_shaped("https://exa mple.com"));
+        assert!(!is_url_shaped("https://example.com:port"));
+    }
+}
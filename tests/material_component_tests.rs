@@ -15,15 +15,28 @@ use bevy_material_ui::badge::{BadgeSize, MaterialBadge};
 use bevy_material_ui::button::{ButtonVariant, IconGravity, MaterialButton};
 use bevy_material_ui::checkbox::{CheckboxState, MaterialCheckbox};
 use bevy_material_ui::chip::{ChipElevation, ChipVariant, MaterialChip};
-use bevy_material_ui::dialog::{DialogType, MaterialDialog};
+use bevy_material_ui::datetime_picker::{
+    Date, DateRange, DateSelectionMode, MaterialDateTimePicker,
+};
+use bevy_material_ui::dialog::{
+    DialogResponse, DialogType, MaterialDialog, QuickDialogBuilder, QuickDialogKind,
+};
 use bevy_material_ui::fab::{FabColor, FabSize, MaterialFab};
+use bevy_material_ui::input_format::{InputMask, NumberFormat, NumberSeparators};
 use bevy_material_ui::progress::{MaterialCircularProgress, MaterialLinearProgress, ProgressMode};
 use bevy_material_ui::radio::MaterialRadio;
+use bevy_material_ui::search::{
+    MaterialSearchBar, SearchViewItem, SearchViewItemKind, SEARCH_QUERY_DEBOUNCE,
+};
+use bevy_material_ui::select::{MaterialSelect, SelectOption};
 use bevy_material_ui::slider::{MaterialSlider, SliderVariant, TickVisibility};
 use bevy_material_ui::snackbar::{ShowSnackbar, SnackbarPosition};
 use bevy_material_ui::switch::MaterialSwitch;
-use bevy_material_ui::text_field::{EndIconMode, InputType, MaterialTextField, TextFieldVariant};
+use bevy_material_ui::text_field::{
+    CaretMotion, EndIconMode, InputType, MaterialTextField, TextFieldVariant,
+};
 use bevy_material_ui::tooltip::{TooltipPosition, TooltipTrigger, TooltipVariant};
+use bevy_material_ui::validation::{MinLength, NumberRange, Required, Validator};
 
 // ============================================================================
 // Button Tests (modeled after MaterialButtonTest.java)
@@ -607,6 +620,327 @@ mod text_field_tests {
         let field = MaterialTextField::new().box_stroke_width(2.0);
         assert_eq!(field.box_stroke_width, 2.0);
     }
+
+    #[test]
+    fn test_caret_starts_at_end_of_value() {
+        let field = MaterialTextField::new().with_value("hello");
+        assert_eq!(field.caret_position(), 5);
+        assert_eq!(field.selection(), None);
+    }
+
+    #[test]
+    fn test_insert_at_caret() {
+        let mut field = MaterialTextField::new().with_value("helo");
+        field.set_caret(3, false);
+        assert!(field.insert_text("l"));
+        assert_eq!(field.value, "hello");
+        assert_eq!(field.caret_position(), 4);
+    }
+
+    #[test]
+    fn test_selection_replace_and_delete() {
+        let mut field = MaterialTextField::new().with_value("hello world");
+        field.move_caret(CaretMotion::WordLeft, true);
+        assert_eq!(field.selected_text(), "world");
+
+        assert!(field.insert_text("there"));
+        assert_eq!(field.value, "hello there");
+
+        field.select_all();
+        assert!(field.delete_backward(false));
+        assert!(field.value.is_empty());
+        assert!(!field.has_content);
+    }
+
+    #[test]
+    fn test_caret_motion_collapses_selection() {
+        let mut field = MaterialTextField::new().with_value("abcdef");
+        field.select(1..4);
+        field.move_caret(CaretMotion::Left, false);
+        assert_eq!(field.caret_position(), 1);
+        assert_eq!(field.selection(), None);
+
+        field.move_caret(CaretMotion::End, true);
+        assert_eq!(field.selection(), Some(1..6));
+    }
+
+    #[test]
+    fn test_word_deletion() {
+        let mut field = MaterialTextField::new().with_value("one two three");
+        assert!(field.delete_backward(true));
+        assert_eq!(field.value, "one two ");
+
+        field.set_caret(0, false);
+        assert!(field.delete_forward(true));
+        assert_eq!(field.value, " two ");
+    }
+
+    #[test]
+    fn test_insert_respects_max_length_and_input_type() {
+        let mut field = MaterialTextField::new()
+            .input_type(InputType::Number)
+            .max_length(4);
+        assert!(field.insert_text("-12a345"));
+        assert_eq!(field.value, "-123");
+
+        // A second sign is rejected anywhere.
+        field.set_caret(0, false);
+        assert!(!field.insert_text("+"));
+
+        let mut single_line = MaterialTextField::new();
+        single_line.insert_text("a\r\nb");
+        assert_eq!(single_line.value, "a b");
+    }
+
+    #[test]
+    fn test_caret_clamped_after_external_value_change() {
+        let mut field = MaterialTextField::new().with_value("long value");
+        field.value = "ab".to_string();
+        assert_eq!(field.caret_position(), 2);
+        assert!(field.insert_text("c"));
+        assert_eq!(field.value, "abc");
+    }
+
+    #[test]
+    fn test_undo_redo_coalesces_typing() {
+        let mut field = MaterialTextField::new();
+        assert!(!field.history.can_undo());
+        for ch in ["h", "i"] {
+            field.insert_text(ch);
+        }
+        field.insert_text(" there");
+        assert_eq!(field.value, "hi there");
+
+        assert!(field.undo());
+        assert_eq!(field.value, "hi");
+        assert_eq!(field.caret_position(), 2);
+        assert!(field.undo());
+        assert_eq!(field.value, "");
+        assert!(!field.undo());
+
+        assert!(field.redo());
+        assert!(field.redo());
+        assert_eq!(field.value, "hi there");
+        assert!(!field.redo());
+    }
+
+    #[test]
+    fn test_caret_move_breaks_coalescing_and_edit_clears_redo() {
+        let mut field = MaterialTextField::new().with_value("ab");
+        assert!(!field.history.can_undo());
+        field.delete_backward(false);
+        field.delete_backward(false);
+        assert_eq!(field.history.undo_len(), 1);
+
+        field.insert_text("x");
+        field.move_caret(CaretMotion::Home, false);
+        field.insert_text("y");
+        assert_eq!(field.value, "yx");
+        assert_eq!(field.history.undo_len(), 3);
+
+        assert!(field.undo());
+        assert!(field.history.can_redo());
+        field.insert_text("z");
+        assert!(!field.history.can_redo());
+    }
+
+    #[test]
+    fn test_undo_external_value_change() {
+        let mut field = MaterialTextField::new().with_value("draft");
+        field.insert_text("!");
+        // e.g. a TextFieldChangeEvent handler normalizing the value
+        field.value = "DRAFT!".to_string();
+
+        assert!(field.undo());
+        assert_eq!(field.value, "draft!");
+        assert!(field.undo());
+        assert_eq!(field.value, "draft");
+        assert!(field.redo());
+        assert!(field.redo());
+        assert_eq!(field.value, "DRAFT!");
+    }
+
+    #[test]
+    fn test_history_depth() {
+        let mut field = MaterialTextField::new().history_depth(2);
+        field.set_value("a");
+        field.set_value("b");
+        field.set_value("c");
+        assert_eq!(field.history.undo_len(), 2);
+        assert!(field.undo());
+        assert!(field.undo());
+        assert_eq!(field.value, "a");
+        assert!(!field.undo());
+
+        let mut disabled = MaterialTextField::new().history_depth(0);
+        disabled.insert_text("a");
+        assert!(!disabled.undo());
+    }
+
+    #[test]
+    fn test_validate_sets_and_clears_error_text() {
+        let mut field = MaterialTextField::new()
+            .validator(Required::new())
+            .validator(MinLength::new(3).with_message("Too short"));
+        assert!(!field.validate());
+        assert_eq!(field.error_text.as_deref(), Some("Required"));
+
+        field.set_value("ab");
+        assert!(!field.validate());
+        assert!(field.error);
+        assert_eq!(field.error_text.as_deref(), Some("Too short"));
+
+        field.set_value("abc");
+        assert!(field.validate());
+        assert!(!field.error);
+        assert_eq!(field.error_text, None);
+    }
+
+    #[test]
+    fn test_validation_keeps_app_errors() {
+        let mut field = MaterialTextField::new()
+            .with_value("taken")
+            .validator(|value: &str| {
+                if value.contains(' ') {
+                    Err("No spaces".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+        field.error = true;
+        field.error_text = Some("Username taken".to_string());
+        assert!(field.validate());
+        assert_eq!(field.error_text.as_deref(), Some("Username taken"));
+    }
+
+    #[test]
+    fn test_email_and_url_input_types_are_validated() {
+        let mut email = MaterialTextField::new()
+            .input_type(InputType::Email)
+            .with_value("not-an-email");
+        assert!(email.check_validity().is_err());
+        email.set_value("ada@example.com");
+        assert!(email.check_validity().is_ok());
+
+        let url = MaterialTextField::new()
+            .input_type(InputType::Url)
+            .with_value("example.com");
+        assert!(url.check_validity().is_err());
+
+        // Empty optional fields are valid.
+        assert!(MaterialTextField::new()
+            .input_type(InputType::Email)
+            .check_validity()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_number_range_validator() {
+        let range = NumberRange::new(1.0, 10.0);
+        assert!(range.validate("5").is_ok());
+        assert!(range.validate("10").is_ok());
+        assert!(range.validate("").is_ok());
+        assert!(range.validate("11").is_err());
+        assert!(range.validate("abc").is_err());
+    }
+
+    #[test]
+    fn test_masked_field_stores_raw_value() {
+        let mut field = MaterialTextField::new().format(InputMask::phone());
+        assert!(field.insert_text("(555) 123-4567"));
+        assert_eq!(field.value, "5551234567");
+        assert_eq!(field.formatted_value(), "(555) 123-4567");
+        assert!(!field.insert_text("8"));
+
+        let date = MaterialTextField::new()
+            .with_value("12/31/1999")
+            .format(InputMask::date());
+        assert_eq!(date.value, "12311999");
+        assert_eq!(date.formatted_value(), "12/31/1999");
+    }
+
+    #[test]
+    fn test_number_format_and_clamping() {
+        let mut field = MaterialTextField::new().format(
+            NumberFormat::currency(NumberSeparators::COMMA_DECIMAL)
+                .min(0.0)
+                .max(5000.0),
+        );
+        field.insert_text("12345,678");
+        assert_eq!(field.value, "12345.67");
+        assert_eq!(field.formatted_value(), "12.345,67");
+
+        assert!(field.clamp_value());
+        assert_eq!(field.value, "5000.00");
+        assert!(!field.clamp_value());
+
+        // Negative numbers are rejected when the minimum is not negative.
+        field.set_value("");
+        assert!(!field.insert_text("-"));
+    }
+
+    #[test]
+    fn test_plain_number_field_accepts_one_decimal_point() {
+        let mut field = MaterialTextField::new().input_type(InputType::Number);
+        field.insert_text("-3.1.4");
+        assert_eq!(field.value, "-3.14");
+    }
+
+    #[test]
+    fn test_multiline_line_limits() {
+        assert_eq!(MaterialTextField::new().max_lines(4).line_limits(), None);
+
+        let field = MaterialTextField::new().multiline(3, 6);
+        assert_eq!(field.input_type, InputType::Multiline);
+        assert_eq!(field.line_limits(), Some((3, Some(6))));
+
+        // The maximum never drops below the minimum; no maximum grows without limit.
+        let field = MaterialTextField::new().multiline(4, 2);
+        assert_eq!(field.line_limits(), Some((4, Some(4))));
+        let field = MaterialTextField::new()
+            .input_type(InputType::Multiline)
+            .min_lines(0);
+        assert_eq!(field.line_limits(), Some((1, None)));
+    }
+
+    #[test]
+    fn test_multiline_caret_up_down() {
+        let mut field = MaterialTextField::new()
+            .multiline(1, 5)
+            .with_value("first line\nab\nthird");
+        field.set_caret(8, false);
+
+        field.move_caret(CaretMotion::Down, false);
+        assert_eq!(field.caret_position(), 13);
+        field.move_caret(CaretMotion::Down, true);
+        assert_eq!(field.caret_position(), 16);
+        assert_eq!(field.selected_text(), "\nth");
+
+        field.move_caret(CaretMotion::Up, false);
+        assert_eq!(field.caret_position(), 13);
+        assert_eq!(field.selection(), None);
+        field.move_caret(CaretMotion::Up, false);
+        field.move_caret(CaretMotion::Up, false);
+        assert_eq!(field.caret_position(), 0);
+    }
+
+    #[test]
+    fn test_ime_commit_is_filtered() {
+        let mut field = MaterialTextField::new().max_length(3);
+        field.set_preedit("かな", Some((6, 6)));
+        assert!(field.is_composing());
+        assert_eq!(field.value, "");
+
+        assert!(field.commit_preedit("仮名です"));
+        assert!(!field.is_composing());
+        assert_eq!(field.value, "仮名で");
+        assert_eq!(field.caret_position(), 3);
+
+        let mut number = MaterialTextField::new().input_type(InputType::Number);
+        assert!(!number.commit_preedit("いち"));
+        assert!(number.commit_preedit("12"));
+        assert_eq!(number.value, "12");
+    }
 }
 
 // ============================================================================
@@ -951,6 +1285,119 @@ mod dialog_tests {
         let dialog = MaterialDialog::new().no_escape_dismiss();
         assert!(!dialog.dismiss_on_escape);
     }
+
+    #[test]
+    fn test_quick_dialog_builders() {
+        let alert = QuickDialogBuilder::alert("Saved", "Your changes were saved");
+        assert_eq!(alert.kind(), QuickDialogKind::Alert);
+        assert!(!alert.has_cancel());
+
+        let confirm = QuickDialogBuilder::confirm("Delete?", "This cannot be undone");
+        assert_eq!(confirm.kind(), QuickDialogKind::Confirm);
+        assert!(confirm.has_cancel());
+
+        let prompt = QuickDialogBuilder::prompt("Rename", "Name");
+        assert_eq!(prompt.kind(), QuickDialogKind::Prompt);
+        assert!(prompt.has_cancel());
+    }
+
+    #[test]
+    fn test_dialog_response() {
+        assert!(DialogResponse::Confirmed.is_confirmed());
+        assert!(!DialogResponse::Cancelled.is_confirmed());
+
+        let text = DialogResponse::Text("Ada".to_string());
+        assert!(text.is_confirmed());
+        assert_eq!(text.text(), Some("Ada"));
+        assert_eq!(DialogResponse::Confirmed.text(), None);
+    }
+}
+
+// ============================================================================
+// DateTime Picker Tests (modeled after RangeDateSelectorTest.java)
+// ============================================================================
+
+mod datetime_picker_tests {
+    use super::*;
+
+    fn range_picker() -> MaterialDateTimePicker {
+        let mut picker = MaterialDateTimePicker::default();
+        picker.selection_mode = DateSelectionMode::Range;
+        picker
+    }
+
+    #[test]
+    fn test_single_selection() {
+        let mut picker = MaterialDateTimePicker::default();
+        assert!(!picker.is_selection_complete());
+        assert!(picker.select_date(Date::new(2025, 3, 4)));
+        assert_eq!(picker.selected_date, Some(Date::new(2025, 3, 4)));
+        assert!(picker.is_selection_complete());
+        assert!(!picker.select_date(Date::new(2025, 2, 30)));
+    }
+
+    #[test]
+    fn test_range_selection_taps() {
+        let mut picker = range_picker();
+        picker.select_date(Date::new(2025, 3, 10));
+        assert_eq!(picker.range_start, Some(Date::new(2025, 3, 10)));
+        assert_eq!(picker.date_range(), None);
+
+        picker.select_date(Date::new(2025, 4, 2));
+        assert_eq!(
+            picker.date_range(),
+            Some(DateRange::new(
+                Date::new(2025, 3, 10),
+                Date::new(2025, 4, 2)
+            ))
+        );
+        assert!(picker.is_selection_complete());
+        assert_eq!(picker.selected_date, None);
+
+        // A third tap starts a new range.
+        picker.select_date(Date::new(2025, 3, 20));
+        assert_eq!(picker.range_start, Some(Date::new(2025, 3, 20)));
+        assert_eq!(picker.range_end, None);
+
+        // Tapping before the start moves the start instead of ending the range.
+        picker.select_date(Date::new(2025, 3, 15));
+        assert_eq!(picker.range_start, Some(Date::new(2025, 3, 15)));
+        assert_eq!(picker.range_end, None);
+
+        // Start and end may be the same day.
+        picker.select_date(Date::new(2025, 3, 15));
+        assert_eq!(
+            picker.date_range(),
+            Some(DateRange::new(
+                Date::new(2025, 3, 15),
+                Date::new(2025, 3, 15)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_range_respects_min_max() {
+        let mut picker = range_picker();
+        picker.min_date = Some(Date::new(2025, 1, 5));
+        picker.max_date = Some(Date::new(2025, 1, 20));
+        assert!(!picker.select_date(Date::new(2025, 1, 4)));
+        assert_eq!(picker.range_start, None);
+        assert!(picker.select_date(Date::new(2025, 1, 5)));
+        assert!(!picker.select_date(Date::new(2025, 1, 21)));
+        assert_eq!(picker.range_end, None);
+        assert!(picker.select_date(Date::new(2025, 1, 20)));
+        assert!(picker.is_selection_complete());
+    }
+
+    #[test]
+    fn test_date_range_ordering() {
+        let range = DateRange::new(Date::new(2025, 5, 1), Date::new(2025, 4, 28));
+        assert_eq!(range.start, Date::new(2025, 4, 28));
+        assert_eq!(range.end, Date::new(2025, 5, 1));
+        assert!(range.contains(Date::new(2025, 4, 30)));
+        assert!(range.contains(Date::new(2025, 5, 1)));
+        assert!(!range.contains(Date::new(2025, 5, 2)));
+    }
 }
 
 // ============================================================================
@@ -1020,6 +1467,182 @@ mod progress_tests {
     }
 }
 
+// ============================================================================
+// Search Tests
+// ============================================================================
+
+mod search_tests {
+    use super::*;
+
+    /// Test search bar defaults
+    #[test]
+    fn test_search_bar_defaults() {
+        let bar = MaterialSearchBar::new("Search");
+        assert_eq!(bar.hint, "Search");
+        assert!(bar.text.is_empty());
+        assert!(bar.show_action);
+        assert!(!bar.expanded);
+        assert!(bar.history.is_empty());
+        assert_eq!(bar.debounce, SEARCH_QUERY_DEBOUNCE);
+    }
+
+    /// Test recent queries are deduplicated, newest first and capped
+    #[test]
+    fn test_search_history() {
+        let mut bar = MaterialSearchBar::new("Search");
+        bar.max_history = 3;
+        bar.push_history("a");
+        bar.push_history("b");
+        bar.push_history("  ");
+        bar.push_history("a");
+        bar.push_history("c");
+        bar.push_history("d");
+        assert_eq!(bar.history, vec!["d", "c", "a"]);
+
+        bar.clear_history();
+        assert!(bar.history.is_empty());
+    }
+
+    /// Test the view lists history while empty and suggestions while typing
+    #[test]
+    fn test_search_view_items() {
+        let mut bar = MaterialSearchBar::new("Search")
+            .with_history(vec!["recent".into()])
+            .with_suggestions(vec!["one".into(), "two".into()]);
+        assert_eq!(bar.view_items(), vec![SearchViewItem::recent("recent")]);
+
+        bar.text = "o".into();
+        let items = bar.view_items();
+        assert_eq!(items.len(), 2);
+        assert!(items
+            .iter()
+            .all(|item| item.kind == SearchViewItemKind::Suggestion));
+    }
+
+    /// Test candidates are fuzzy-filtered and ranked by the query
+    #[test]
+    fn test_search_candidates_ranked() {
+        let mut bar = MaterialSearchBar::new("Search").with_candidates(vec![
+            "Profile settings".into(),
+            "File".into(),
+            "Open".into(),
+        ]);
+        bar.text = "fil".into();
+        let items = bar.view_items();
+        let queries: Vec<&str> = items.iter().map(|item| item.query.as_str()).collect();
+        assert_eq!(queries, vec!["File", "Profile settings"]);
+        assert_eq!(items[0].matches, vec![0..3]);
+    }
+}
+
+// ============================================================================
+// Select Tests
+// ============================================================================
+
+mod select_tests {
+    use super::*;
+
+    /// Test the filter narrows and ranks options
+    #[test]
+    fn test_select_filtered_options() {
+        let mut select = MaterialSelect::new(vec![
+            SelectOption::new("Grape"),
+            SelectOption::new("Apple"),
+            SelectOption::new("Banana"),
+        ])
+        .filterable(true);
+        assert_eq!(select.filtered_options().len(), 3);
+
+        select.filter = "AP".into();
+        let indices: Vec<usize> = select.filtered_options().iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, vec![1, 0]);
+    }
+
+    /// Test arrow navigation skips disabled options and wraps
+    #[test]
+    fn test_select_highlight_navigation() {
+        let mut select = MaterialSelect::new(vec![
+            SelectOption::new("One"),
+            SelectOption::new("Two").disabled(),
+            SelectOption::new("Three"),
+        ]);
+        select.highlight_next();
+        assert_eq!(select.highlighted_index, Some(0));
+        select.highlight_next();
+        assert_eq!(select.highlighted_index, Some(2));
+        select.highlight_next();
+        assert_eq!(select.highlighted_index, Some(0));
+        select.highlight_previous();
+        assert_eq!(select.highlighted_index, Some(2));
+
+        select.open = true;
+        assert_eq!(select.commit_highlighted(), Some(2));
+        assert_eq!(select.selected_index, Some(2));
+        assert!(!select.open);
+    }
+
+    /// Test type-ahead prefixes and repeated letters
+    #[test]
+    fn test_select_type_ahead() {
+        let mut select = MaterialSelect::new(vec![
+            SelectOption::new("Banana"),
+            SelectOption::new("Blueberry"),
+            SelectOption::new("Cherry"),
+            SelectOption::new("Blackberry"),
+        ]);
+        assert!(select.type_ahead("b"));
+        assert_eq!(select.highlighted_index, Some(0));
+        assert!(select.type_ahead("bb"));
+        assert_eq!(select.highlighted_index, Some(1));
+        assert!(select.type_ahead("bbb"));
+        assert_eq!(select.highlighted_index, Some(3));
+
+        assert!(select.type_ahead("BLU"));
+        assert_eq!(select.highlighted_index, Some(1));
+        assert!(!select.type_ahead("z"));
+        assert_eq!(select.highlighted_index, Some(1));
+    }
+
+    /// Test a multi-select toggles options and summarizes the selection
+    #[test]
+    fn test_select_multiple() {
+        let mut select = MaterialSelect::new(vec![
+            SelectOption::new("Red"),
+            SelectOption::new("Green").disabled(),
+            SelectOption::new("Blue").value("blue"),
+        ])
+        .multiple(true);
+        assert_eq!(select.display_text(), "");
+
+        select.open = true;
+        assert!(select.select_option(0));
+        assert!(!select.select_option(1));
+        assert!(select.select_option(2));
+        assert!(select.open);
+        assert!(select.is_selected(0) && select.is_selected(2));
+        assert_eq!(select.selected_values(), vec!["Red", "blue"]);
+        assert_eq!(select.display_text(), "2 selected");
+
+        assert!(select.select_option(0));
+        assert_eq!(select.display_text(), "Blue");
+        assert_eq!(select.selected_index, None);
+    }
+
+    /// Test an editable select shows its custom value until an option is picked
+    #[test]
+    fn test_select_custom_value() {
+        let mut select = MaterialSelect::new(vec![SelectOption::new("Paris")])
+            .editable(true)
+            .allow_custom(true);
+        select.custom_value = Some("Rome".into());
+        assert_eq!(select.display_text(), "Rome");
+
+        assert!(select.select_option(0));
+        assert_eq!(select.custom_value, None);
+        assert_eq!(select.display_text(), "Paris");
+    }
+}
+
 // ============================================================================
 // Integration Tests - Type Export Verification
 // ============================================================================